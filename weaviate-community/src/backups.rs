@@ -1,25 +1,41 @@
 use reqwest::Url;
 use std::error::Error;
+use std::future::{pending, Future};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::collections::backups::{
     BackupBackends, BackupCreateRequest, BackupResponse, BackupRestoreRequest, BackupStatus,
     BackupStatusResponse,
 };
-use crate::collections::error::BackupError;
+use crate::collections::error::{BackupError, WeaviateError};
+use crate::http::HttpClient;
+
+/// The interval between status polls when waiting for a backup or restore to complete.
+const BACKUP_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 /// All backup related endpoints and functionality described in
 /// [Weaviate meta API documentation](https://weaviate.io/developers/weaviate/api/rest/backups)
 #[derive(Debug)]
 pub struct Backups {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    restore_precheck: bool,
 }
 
 impl Backups {
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/backups/")?;
-        Ok(Backups { endpoint, client })
+        Ok(Backups {
+            endpoint,
+            client,
+            restore_precheck: false,
+        })
+    }
+
+    /// Set whether a restore first checks the backup exists.
+    pub(super) fn set_restore_precheck(&mut self, restore_precheck: bool) {
+        self.restore_precheck = restore_precheck;
     }
 
     /// Create a new backup
@@ -36,7 +52,8 @@ impl Backups {
     ///     let my_request = BackupCreateRequest {
     ///         id: "doc-test-backup".into(),
     ///         include: None,
-    ///         exclude: None
+    ///         exclude: None,
+    ///         config: None,
     ///     };
     ///     let res = client.backups.create(
     ///         &BackupBackends::FILESYSTEM,
@@ -52,17 +69,165 @@ impl Backups {
         backend: &BackupBackends,
         backup_request: &BackupCreateRequest,
         wait_for_completion: bool,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.create_backup(
+            backend,
+            backup_request,
+            wait_for_completion,
+            None,
+            |_| {},
+            pending(),
+        )
+        .await
+    }
+
+    /// Create a new backup, allowing the create request up to `timeout` to complete.
+    ///
+    /// This overrides the timeout set with `WeaviateClientBuilder::with_timeout` for the create
+    /// request only. When waiting for completion, each status poll keeps the client timeout, and
+    /// the wait itself is unbounded; use `create_cancellable` to bound it.
+    ///
+    /// # Parameters
+    /// - backend: the backend to store the backup in
+    /// - backup_request: the backup to create
+    /// - wait_for_completion: whether to wait for the backup to complete
+    /// - timeout: the maximum duration of the create request
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::{BackupBackends, BackupCreateRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080")
+    ///         .with_timeout(Duration::from_secs(10))
+    ///         .build()?;
+    ///     let my_request = BackupCreateRequest::builder("doc-test-backup").build();
+    ///     let res = client.backups.create_with_timeout(
+    ///         &BackupBackends::S3,
+    ///         &my_request,
+    ///         false,
+    ///         Duration::from_secs(120),
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_with_timeout(
+        &self,
+        backend: &BackupBackends,
+        backup_request: &BackupCreateRequest,
+        wait_for_completion: bool,
+        timeout: Duration,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.create_backup(
+            backend,
+            backup_request,
+            wait_for_completion,
+            Some(timeout),
+            |_| {},
+            pending(),
+        )
+        .await
+    }
+
+    /// Create a new backup and wait for it to complete, calling `progress` with the latest status
+    /// after each status poll.
+    ///
+    /// # Parameters
+    /// - backend: the backend to store the backup in
+    /// - backup_request: the backup to create
+    /// - progress: the callback to invoke with each polled status
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::{BackupBackends, BackupCreateRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let my_request = BackupCreateRequest::builder("doc-test-backup").build();
+    ///     let res = client.backups.create_with_progress(
+    ///         &BackupBackends::FILESYSTEM,
+    ///         &my_request,
+    ///         |status| println!("backup is {:?}", status.status),
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_with_progress<F: Fn(&BackupStatusResponse)>(
+        &self,
+        backend: &BackupBackends,
+        backup_request: &BackupCreateRequest,
+        progress: F,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.create_backup(backend, backup_request, true, None, progress, pending())
+            .await
+    }
+
+    /// Create a new backup and wait for it to complete, unless `cancel` resolves first.
+    ///
+    /// When `cancel` resolves, the wait is abandoned and a `WeaviateError::Cancelled` is
+    /// returned. The backup itself carries on running in Weaviate.
+    ///
+    /// # Parameters
+    /// - backend: the backend to store the backup in
+    /// - backup_request: the backup to create
+    /// - cancel: a future which aborts the wait when it resolves, such as a shutdown signal
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::{BackupBackends, BackupCreateRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let my_request = BackupCreateRequest::builder("doc-test-backup").build();
+    ///     let res = client.backups.create_cancellable(
+    ///         &BackupBackends::FILESYSTEM,
+    ///         &my_request,
+    ///         tokio::time::sleep(Duration::from_secs(600)),
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_cancellable<C: Future<Output = ()>>(
+        &self,
+        backend: &BackupBackends,
+        backup_request: &BackupCreateRequest,
+        cancel: C,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.create_backup(backend, backup_request, true, None, |_| {}, cancel)
+            .await
+    }
+
+    async fn create_backup<F: Fn(&BackupStatusResponse), C: Future<Output = ()>>(
+        &self,
+        backend: &BackupBackends,
+        backup_request: &BackupCreateRequest,
+        wait_for_completion: bool,
+        timeout: Option<Duration>,
+        progress: F,
+        cancel: C,
     ) -> Result<BackupResponse, Box<dyn Error>> {
         let endpoint = self.endpoint.join(backend.value())?;
         let payload = serde_json::to_value(&backup_request)?;
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let mut request = self.client.post(endpoint).json(&payload);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        let res = self.client.send(request).await?;
 
         match res.status() {
             reqwest::StatusCode::OK => {
                 let mut res: BackupResponse = res.json().await?;
                 if wait_for_completion {
                     let complete = self
-                        .wait_for_completion(&backend, &backup_request.id, false)
+                        .wait_for_completion(backend, &backup_request.id, false, progress, cancel)
                         .await?;
                     res.status = complete;
                 }
@@ -107,7 +272,7 @@ impl Backups {
             endpoint.push_str("/restore");
         }
         let endpoint = self.endpoint.join(&endpoint)?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: BackupStatusResponse = res.json().await?;
@@ -120,8 +285,53 @@ impl Backups {
         }
     }
 
+    /// Check whether a backup exists in a backend.
+    ///
+    /// # Parameters
+    /// - backend: the backend the backup is stored in
+    /// - backup_id: the id of the backup
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::BackupBackends;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let exists = client
+    ///         .backups
+    ///         .exists(&BackupBackends::FILESYSTEM, "doc-test-backup")
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn exists(
+        &self,
+        backend: &BackupBackends,
+        backup_id: &str,
+    ) -> Result<bool, Box<dyn Error>> {
+        let mut endpoint: String = backend.value().into();
+        endpoint.push('/');
+        endpoint.push_str(backup_id);
+        let endpoint = self.endpoint.join(&endpoint)?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
+        match res.status() {
+            reqwest::StatusCode::OK => Ok(true),
+            reqwest::StatusCode::NOT_FOUND => Ok(false),
+            _ => Err(Box::new(BackupError(format!(
+                "status code {} received.",
+                res.status()
+            )))),
+        }
+    }
+
     /// Restore a backup
     ///
+    /// When the restore precheck is enabled with `WeaviateClientBuilder::with_restore_precheck`,
+    /// the backup is checked to exist first, and a `WeaviateError::NotFound` is returned if it
+    /// does not.
+    ///
     /// # Examples
     /// Restore a backup from the filesystem, waiting for completion
     /// ```no_run
@@ -133,7 +343,8 @@ impl Backups {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
     ///     let my_request = BackupRestoreRequest {
     ///         include: None,
-    ///         exclude: None
+    ///         exclude: None,
+    ///         config: None,
     ///     };
     ///     let res = client.backups.restore(
     ///         &BackupBackends::FILESYSTEM,
@@ -152,19 +363,137 @@ impl Backups {
         backup_request: &BackupRestoreRequest,
         wait_for_completion: bool,
     ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.restore_backup(
+            backend,
+            backup_id,
+            backup_request,
+            wait_for_completion,
+            |_| {},
+            pending(),
+        )
+        .await
+    }
+
+    /// Restore a backup and wait for it to complete, calling `progress` with the latest status
+    /// after each status poll.
+    ///
+    /// # Parameters
+    /// - backend: the backend the backup is stored in
+    /// - backup_id: the id of the backup to restore
+    /// - backup_request: the restore request
+    /// - progress: the callback to invoke with each polled status
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::{BackupBackends, BackupRestoreRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let my_request = BackupRestoreRequest::builder().build();
+    ///     let res = client.backups.restore_with_progress(
+    ///         &BackupBackends::FILESYSTEM,
+    ///         "doc-test-backup",
+    ///         &my_request,
+    ///         |status| println!("restore is {:?}", status.status),
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn restore_with_progress<F: Fn(&BackupStatusResponse)>(
+        &self,
+        backend: &BackupBackends,
+        backup_id: &str,
+        backup_request: &BackupRestoreRequest,
+        progress: F,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.restore_backup(
+            backend,
+            backup_id,
+            backup_request,
+            true,
+            progress,
+            pending(),
+        )
+        .await
+    }
+
+    /// Restore a backup and wait for it to complete, unless `cancel` resolves first.
+    ///
+    /// When `cancel` resolves, the wait is abandoned and a `WeaviateError::Cancelled` is
+    /// returned. The restore itself carries on running in Weaviate.
+    ///
+    /// # Parameters
+    /// - backend: the backend the backup is stored in
+    /// - backup_id: the id of the backup to restore
+    /// - backup_request: the restore request
+    /// - cancel: a future which aborts the wait when it resolves, such as a shutdown signal
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::backups::{BackupBackends, BackupRestoreRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let my_request = BackupRestoreRequest::builder().build();
+    ///     let (shutdown, on_shutdown) = tokio::sync::oneshot::channel::<()>();
+    ///     let res = client.backups.restore_cancellable(
+    ///         &BackupBackends::FILESYSTEM,
+    ///         "doc-test-backup",
+    ///         &my_request,
+    ///         async { let _ = on_shutdown.await; },
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn restore_cancellable<C: Future<Output = ()>>(
+        &self,
+        backend: &BackupBackends,
+        backup_id: &str,
+        backup_request: &BackupRestoreRequest,
+        cancel: C,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        self.restore_backup(backend, backup_id, backup_request, true, |_| {}, cancel)
+            .await
+    }
+
+    async fn restore_backup<F: Fn(&BackupStatusResponse), C: Future<Output = ()>>(
+        &self,
+        backend: &BackupBackends,
+        backup_id: &str,
+        backup_request: &BackupRestoreRequest,
+        wait_for_completion: bool,
+        progress: F,
+        cancel: C,
+    ) -> Result<BackupResponse, Box<dyn Error>> {
+        if self.restore_precheck && !self.exists(backend, backup_id).await? {
+            return Err(Box::new(WeaviateError::NotFound(format!(
+                "backup {} does not exist in the {} backend",
+                backup_id,
+                backend.value()
+            ))));
+        }
         let mut endpoint: String = backend.value().into();
         endpoint.push_str("/");
         endpoint.push_str(&backup_id.to_string());
         endpoint.push_str("/restore");
         let endpoint = self.endpoint.join(&endpoint)?;
         let payload = serde_json::to_value(&backup_request)?;
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
 
         match res.status() {
             reqwest::StatusCode::OK => {
                 let mut res: BackupResponse = res.json().await?;
                 if wait_for_completion {
-                    let complete = self.wait_for_completion(&backend, &backup_id, true).await?;
+                    let complete = self
+                        .wait_for_completion(backend, backup_id, true, progress, cancel)
+                        .await?;
                     res.status = complete;
                 }
                 Ok(res)
@@ -176,32 +505,54 @@ impl Backups {
         }
     }
 
-    /// Wait for a backup to complete before returning
-    async fn wait_for_completion(
+    /// Wait for a backup to complete before returning, calling `progress` after each poll.
+    ///
+    /// Returns a `WeaviateError::Cancelled` if `cancel` resolves before the backup completes.
+    async fn wait_for_completion<F: Fn(&BackupStatusResponse), C: Future<Output = ()>>(
         &self,
         backend: &BackupBackends,
         backup_id: &str,
         restore: bool,
+        progress: F,
+        cancel: C,
     ) -> Result<BackupStatus, Box<dyn Error>> {
+        let cancelled = || {
+            Box::new(WeaviateError::Cancelled(format!(
+                "stopped waiting for backup {} to complete",
+                backup_id
+            )))
+        };
+        tokio::pin!(cancel);
         loop {
-            let res = self.get_backup_status(backend, backup_id, restore).await;
+            let res = tokio::select! {
+                res = self.get_backup_status(backend, backup_id, restore) => res,
+                _ = &mut cancel => return Err(cancelled()),
+            };
             let status = res?;
+            progress(&status);
             if status.status == BackupStatus::SUCCESS {
                 return Ok(BackupStatus::SUCCESS);
             } else if status.status == BackupStatus::FAILED {
                 return Err(Box::new(BackupError(format!("backup status FAILED",))));
             }
+            tokio::select! {
+                _ = tokio::time::sleep(BACKUP_POLL_INTERVAL) => {}
+                _ = &mut cancel => return Err(cancelled()),
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use crate::{
         collections::backups::{
-            BackupBackends, BackupCreateRequest, BackupResponse, BackupRestoreRequest,
-            BackupStatus, BackupStatusResponse,
+            BackupBackends, BackupConfig, BackupCreateRequest, BackupResponse,
+            BackupRestoreRequest, BackupStatus, BackupStatusResponse,
         },
+        collections::error::WeaviateError,
         WeaviateClient,
     };
 
@@ -213,6 +564,16 @@ mod tests {
         (mock_server, client)
     }
 
+    async fn get_precheck_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_restore_precheck(true)
+            .build()
+            .unwrap();
+        (mock_server, client)
+    }
+
     fn test_create_backup_request() -> BackupCreateRequest {
         BackupCreateRequest::builder("abcd").build()
     }
@@ -314,6 +675,96 @@ mod tests {
         assert_eq!(req.id, res.unwrap().id);
     }
 
+    #[tokio::test]
+    async fn test_create_backup_with_timeout() {
+        let req = test_create_backup_request();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/backups/filesystem")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_chunked_body(|w| {
+                std::thread::sleep(Duration::from_millis(500));
+                w.write_all(b"{}")
+            })
+            .create();
+        let res = client
+            .backups
+            .create_with_timeout(
+                &BackupBackends::FILESYSTEM,
+                &req,
+                false,
+                Duration::from_millis(100),
+            )
+            .await;
+        mock.assert();
+        let err = res.unwrap_err();
+        assert!(err.downcast_ref::<reqwest::Error>().unwrap().is_timeout());
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_config() {
+        let config = BackupConfig::builder()
+            .with_bucket("my-bucket")
+            .with_path("nightly")
+            .with_cpu_percentage(40)
+            .with_chunk_size(128)
+            .build();
+        let req = BackupCreateRequest::builder("abcd")
+            .with_config(config)
+            .build();
+        let out = test_backup_response(BackupStatus::STARTED);
+        let out_str = serde_json::to_string(&out).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/backups/s3")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "id": "abcd",
+                "config": {
+                    "Bucket": "my-bucket",
+                    "Path": "nightly",
+                    "CPUPercentage": 40,
+                    "ChunkSize": 128
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&out_str)
+            .create();
+        let res = client
+            .backups
+            .create(&BackupBackends::S3, &req, false)
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_backup_config_serde() {
+        let req = BackupRestoreRequest::builder()
+            .with_config(BackupConfig::builder().with_bucket("my-bucket").build())
+            .build();
+        assert_eq!(
+            serde_json::json!({
+                "include": null,
+                "exclude": null,
+                "config": {"Bucket": "my-bucket"}
+            }),
+            serde_json::to_value(&req).unwrap()
+        );
+        let req = test_restore_backup_request();
+        assert!(serde_json::to_value(&req).unwrap().get("config").is_none());
+
+        let config: BackupConfig = serde_json::from_value(serde_json::json!({
+            "Path": "nightly",
+            "CPUPercentage": 80
+        }))
+        .unwrap();
+        assert_eq!(Some("nightly".into()), config.path);
+        assert_eq!(Some(80), config.cpu_percentage);
+        assert!(config.bucket.is_none());
+    }
+
     #[tokio::test]
     async fn test_create_backup_err() {
         let req = test_create_backup_request();
@@ -352,6 +803,70 @@ mod tests {
         assert_eq!(BackupStatus::SUCCESS, res.unwrap().status);
     }
 
+    #[tokio::test]
+    async fn test_create_backup_with_progress() {
+        let req = test_create_backup_request();
+        let out = test_backup_response(BackupStatus::STARTED);
+        let out_str = serde_json::to_string(&out).unwrap();
+        let out_two = test_backup_status(BackupStatus::SUCCESS);
+        let out_two_str = serde_json::to_string(&out_two).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 200, &out_str).await;
+        let mock2 = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &out_two_str,
+        )
+        .await;
+        let polls = std::sync::Mutex::new(Vec::new());
+        let res = client
+            .backups
+            .create_with_progress(&BackupBackends::FILESYSTEM, &req, |status| {
+                polls.lock().unwrap().push(status.status.clone())
+            })
+            .await;
+        mock.assert();
+        mock2.assert();
+        assert_eq!(BackupStatus::SUCCESS, res.unwrap().status);
+        assert_eq!(vec![BackupStatus::SUCCESS], *polls.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_cancelled() {
+        let req = test_create_backup_request();
+        let out = test_backup_response(BackupStatus::STARTED);
+        let out_str = serde_json::to_string(&out).unwrap();
+        let out_two = test_backup_status(BackupStatus::TRANSFERRING);
+        let out_two_str = serde_json::to_string(&out_two).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 200, &out_str).await;
+        let mock2 = mock_server
+            .mock("GET", "/v1/backups/filesystem/abcd")
+            .with_status(200)
+            .with_body(&out_two_str)
+            .expect_at_least(1)
+            .create();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+            let _ = tx.send(());
+        });
+        let res = client
+            .backups
+            .create_cancellable(&BackupBackends::FILESYSTEM, &req, async {
+                let _ = rx.await;
+            })
+            .await;
+        mock.assert();
+        mock2.assert();
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::Cancelled(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_create_backup_wait_err() {
         let req = test_create_backup_request();
@@ -404,6 +919,83 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_exists() {
+        let status_str = serde_json::to_string(&test_backup_status(BackupStatus::SUCCESS)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock_present = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &status_str,
+        )
+        .await;
+        let mock_absent = mock_get(&mut mock_server, "/v1/backups/filesystem/efgh", 404, "").await;
+        let present = client
+            .backups
+            .exists(&BackupBackends::FILESYSTEM, "abcd")
+            .await;
+        let absent = client
+            .backups
+            .exists(&BackupBackends::FILESYSTEM, "efgh")
+            .await;
+        mock_present.assert();
+        mock_absent.assert();
+        assert!(present.unwrap());
+        assert!(!absent.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_precheck_present() {
+        let req = test_restore_backup_request();
+        let status_str = serde_json::to_string(&test_backup_status(BackupStatus::SUCCESS)).unwrap();
+        let out_str = serde_json::to_string(&test_backup_response(BackupStatus::STARTED)).unwrap();
+        let (mut mock_server, client) = get_precheck_test_harness().await;
+        let mock_status = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &status_str,
+        )
+        .await;
+        let mock_restore = mock_post(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd/restore",
+            200,
+            &out_str,
+        )
+        .await;
+        let res = client
+            .backups
+            .restore(&BackupBackends::FILESYSTEM, "abcd", &req, false)
+            .await;
+        mock_status.assert();
+        mock_restore.assert();
+        assert_eq!(BackupStatus::STARTED, res.unwrap().status);
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_precheck_absent() {
+        let req = test_restore_backup_request();
+        let (mut mock_server, client) = get_precheck_test_harness().await;
+        let mock_status = mock_get(&mut mock_server, "/v1/backups/filesystem/abcd", 404, "").await;
+        let mock_restore = mock_server
+            .mock("POST", "/v1/backups/filesystem/abcd/restore")
+            .expect(0)
+            .create();
+        let res = client
+            .backups
+            .restore(&BackupBackends::FILESYSTEM, "abcd", &req, false)
+            .await;
+        mock_status.assert();
+        mock_restore.assert();
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::NotFound(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_restore_backup_wait_ok() {
         let req = test_restore_backup_request();
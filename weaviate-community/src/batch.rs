@@ -1,25 +1,74 @@
-use reqwest::Url;
+use flate2::{write::GzEncoder, Compression};
+use reqwest::header::{CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::{RequestBuilder, Url};
+use std::collections::HashSet;
 use std::error::Error;
+use std::io::Write;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
 
 use crate::collections::{
-    batch::{BatchAddObjects, BatchAddReferencesResponse, BatchDeleteRequest, BatchDeleteResponse},
-    error::BatchError,
-    objects::{ConsistencyLevel, MultiObjects, References},
+    batch::{
+        BatchAddObjects, BatchAddReferencesResponse, BatchDeleteRequest, BatchDeleteResponse,
+        GeneralStatus, ImportReport, MalformedLine,
+    },
+    error::{BatchError, WeaviateError},
+    objects::{beacon, ConsistencyLevel, MultiObjects, Object, References, DEFAULT_BEACON_HOST},
 };
+use crate::http::HttpClient;
+use crate::objects::check_reference_targets;
 
 /// All batch related endpoints and functionality described in
 /// [Weaviate meta API documentation](https://weaviate.io/developers/weaviate/api/rest/batch)
 #[derive(Debug)]
 pub struct Batch {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    beacon_host: String,
+    compression: bool,
 }
 
+/// Request bodies smaller than this are never compressed, as the overhead outweighs the benefit.
+const COMPRESSION_THRESHOLD_BYTES: usize = 1024;
+
 impl Batch {
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/batch/")?;
-        Ok(Batch { endpoint, client })
+        Ok(Batch {
+            endpoint,
+            client,
+            beacon_host: DEFAULT_BEACON_HOST.into(),
+            compression: false,
+        })
+    }
+
+    pub(super) fn set_compression(&mut self, compression: bool) {
+        self.compression = compression;
+    }
+
+    /// Set the host used within the beacons of references.
+    pub(super) fn set_beacon_host(&mut self, beacon_host: &str) {
+        self.beacon_host = beacon_host.into();
+    }
+
+    /// Attach the JSON payload to the request, gzip compressing it when compression is enabled
+    /// and the payload is larger than the compression threshold.
+    fn with_body(
+        &self,
+        request: RequestBuilder,
+        payload: &serde_json::Value,
+    ) -> Result<RequestBuilder, Box<dyn Error>> {
+        let body = serde_json::to_vec(payload)?;
+        if !self.compression || body.len() < COMPRESSION_THRESHOLD_BYTES {
+            return Ok(request.header(CONTENT_TYPE, "application/json").body(body));
+        }
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&body)?;
+        Ok(request
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_ENCODING, "gzip")
+            .body(encoder.finish()?))
     }
 
     /// Batch add objects.
@@ -68,20 +117,80 @@ impl Batch {
         objects: MultiObjects,
         consistency_level: Option<ConsistencyLevel>,
         tenant: Option<&str>,
+    ) -> Result<BatchAddObjects, Box<dyn Error>> {
+        self.add_objects(objects, consistency_level, tenant, None)
+            .await
+    }
+
+    /// Batch add objects, allowing the request up to `timeout` to complete.
+    ///
+    /// This overrides the timeout set with `WeaviateClientBuilder::with_timeout` for this request
+    /// only, as a large batch can take far longer to be vectorized and stored than other calls.
+    ///
+    /// # Parameters
+    /// - objects: the objects to add
+    /// - consistency_level: the consistency level to use
+    /// - tenant: the name of the tenant the objects belong to
+    /// - timeout: the maximum duration of the request
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::{Object, MultiObjects};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080")
+    ///         .with_timeout(Duration::from_secs(10))
+    ///         .build()?;
+    ///     let objects: Vec<Object> = (0..1000)
+    ///         .map(|i| Object::builder("Article", serde_json::json!({"index": i})).build())
+    ///         .collect();
+    ///     let res = client
+    ///         .batch
+    ///         .objects_batch_add_with_timeout(
+    ///             MultiObjects::new(objects),
+    ///             None,
+    ///             None,
+    ///             Duration::from_secs(300),
+    ///         )
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn objects_batch_add_with_timeout(
+        &self,
+        objects: MultiObjects,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant: Option<&str>,
+        timeout: Duration,
+    ) -> Result<BatchAddObjects, Box<dyn Error>> {
+        self.add_objects(objects, consistency_level, tenant, Some(timeout))
+            .await
+    }
+
+    async fn add_objects(
+        &self,
+        objects: MultiObjects,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant: Option<&str>,
+        timeout: Option<Duration>,
     ) -> Result<BatchAddObjects, Box<dyn Error>> {
         let mut endpoint = self.endpoint.join("objects")?;
-        if let Some(x) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", x.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
 
         if let Some(t) = tenant {
             endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
         let payload = serde_json::to_value(&objects)?;
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let mut request = self.client.post(endpoint);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        let res = self.client.send(self.with_body(request, &payload)?).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: BatchAddObjects = res.json().await?;
@@ -94,11 +203,107 @@ impl Batch {
         }
     }
 
+    /// Batch import objects from a newline-delimited JSON (JSONL) reader.
+    ///
+    /// Each line should hold an object in the format written by `Objects::export`. The class of
+    /// every object is set to `class_name`. The objects are grouped into batches of
+    /// `batch_size` and added with `objects_batch_add`.
+    ///
+    /// Lines that can't be parsed are collected into the `malformed` list of the report rather
+    /// than aborting the import. Blank lines are skipped.
+    ///
+    /// # Parameters
+    /// - reader: the JSONL input
+    /// - class_name: the name of the class to import the objects into
+    /// - batch_size: the number of objects to send per batch request
+    /// - consistency_level: the consistency level to use
+    ///
+    /// # Example
+    /// ```no_run
+    /// use tokio::io::BufReader;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let file = tokio::fs::File::open("articles.jsonl").await?;
+    ///     let report = client
+    ///         .batch
+    ///         .import_jsonl(BufReader::new(file), "Article", 100, None)
+    ///         .await?;
+    ///     println!("imported {}, malformed {}", report.imported, report.malformed.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn import_jsonl<R: AsyncBufRead + Unpin>(
+        &self,
+        reader: R,
+        class_name: &str,
+        batch_size: usize,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<ImportReport, Box<dyn Error>> {
+        if batch_size == 0 {
+            return Err(Box::new(WeaviateError::InvalidRequest(
+                "`batch_size` must be greater than 0".into(),
+            )));
+        }
+        let mut report = ImportReport::default();
+        let mut pending: Vec<Object> = Vec::with_capacity(batch_size);
+        let mut lines = reader.lines();
+        let mut line_number = 0;
+        while let Some(line) = lines.next_line().await? {
+            line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_jsonl_object(&line, class_name) {
+                Ok(object) => pending.push(object),
+                Err(e) => report.malformed.push(MalformedLine {
+                    line: line_number,
+                    error: e.to_string(),
+                }),
+            }
+            if pending.len() == batch_size {
+                let objects = MultiObjects::new(std::mem::take(&mut pending));
+                self.import_batch(objects, consistency_level, &mut report)
+                    .await?;
+            }
+        }
+        if !pending.is_empty() {
+            self.import_batch(MultiObjects::new(pending), consistency_level, &mut report)
+                .await?;
+        }
+        Ok(report)
+    }
+
+    /// Add a batch of imported objects, and tally the results into the report.
+    async fn import_batch(
+        &self,
+        objects: MultiObjects,
+        consistency_level: Option<ConsistencyLevel>,
+        report: &mut ImportReport,
+    ) -> Result<(), Box<dyn Error>> {
+        let res = self
+            .objects_batch_add(objects, consistency_level, None)
+            .await?;
+        for object in res.0 {
+            match object.result.status {
+                GeneralStatus::FAILED => report.failed += 1,
+                _ => report.imported += 1,
+            }
+        }
+        Ok(())
+    }
+
     /// Batch delete objects.
     ///
+    /// For classes with multi-tenancy enabled, the tenant must be provided so that the delete is
+    /// scoped to the tenant's shard.
+    ///
     /// # Parameters
     /// - request_body: the config to use for deletion
     /// - consistency_level: the consistency level to use
+    /// - tenant: the name of the tenant to delete the objects from
     ///
     /// # Example
     /// ```rust
@@ -110,16 +315,7 @@ impl Batch {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let req = BatchDeleteRequest::builder(
-    ///         MatchConfig::new(
-    ///             "Article",
-    ///             serde_json::json!({
-    ///                 "operator": "Like",
-    ///                 "path": ["id"],
-    ///                 "valueText": "*4*",
-    ///             })
-    ///         )
-    ///     ).build();
+    ///     let req = BatchDeleteRequest::builder(MatchConfig::by_id_like("Article", "*4*")).build();
     ///
     ///     let res = client.batch.objects_batch_delete(
     ///         req,
@@ -137,18 +333,18 @@ impl Batch {
         tenant: Option<&str>,
     ) -> Result<BatchDeleteResponse, Box<dyn Error>> {
         let mut endpoint = self.endpoint.join("objects")?;
-        if let Some(x) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", x.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
 
         if let Some(t) = tenant {
             endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
         let payload = serde_json::to_value(&request_body)?;
-        let res = self.client.delete(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.with_body(self.client.delete(endpoint), &payload)?)
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: BatchDeleteResponse = res.json().await?;
@@ -166,6 +362,9 @@ impl Batch {
     /// Note that the consistency_level and tenant_name in the `Reference` items contained within
     /// the `References` input bare no effect on this method and will be ignored.
     ///
+    /// A reference with additional targets is added as one beacon per target, once its property
+    /// has been checked to be configured for every target class.
+    ///
     /// # Parameters
     /// - references: the references to add
     /// - consistency_level: the consistency level to use
@@ -217,36 +416,52 @@ impl Batch {
         tenant: Option<&str>,
     ) -> Result<BatchAddReferencesResponse, Box<dyn Error>> {
         let mut converted: Vec<serde_json::Value> = Vec::new();
+        let mut checked = HashSet::new();
         for reference in references.0 {
-            let new_ref = serde_json::json!({
-                "from": format!(
-                    "weaviate://localhost/{}/{}/{}",
-                    reference.from_class_name,
-                    reference.from_uuid,
-                    reference.from_property_name
-                ),
-                "to": format!(
-                    "weaviate://localhost/{}/{}",
-                    reference.to_class_name,
-                    reference.to_uuid
+            let targets: Vec<String> = reference
+                .targets()
+                .iter()
+                .map(|(class_name, _)| class_name.to_string())
+                .collect();
+            let key = (
+                reference.from_class_name.clone(),
+                reference.from_property_name.clone(),
+                targets,
+            );
+            if !reference.additional_targets.is_empty() && !checked.contains(&key) {
+                check_reference_targets(&self.client, &self.endpoint, &reference).await?;
+                checked.insert(key);
+            }
+            let from = format!(
+                "{}/{}",
+                beacon(
+                    &self.beacon_host,
+                    &reference.from_class_name,
+                    &reference.from_uuid
                 ),
-            });
-            converted.push(new_ref);
+                reference.from_property_name
+            );
+            for (to_class_name, to_uuid) in reference.targets() {
+                converted.push(serde_json::json!({
+                    "from": from,
+                    "to": beacon(&self.beacon_host, to_class_name, to_uuid),
+                }));
+            }
         }
         let payload = serde_json::json!(converted);
 
         let mut endpoint = self.endpoint.join("references")?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
 
         if let Some(t) = tenant {
             endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.with_body(self.client.post(endpoint), &payload)?)
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: BatchAddReferencesResponse = res.json().await?;
@@ -260,18 +475,29 @@ impl Batch {
     }
 }
 
+/// Parse a single JSONL line into an object of the given class.
+fn parse_jsonl_object(line: &str, class_name: &str) -> Result<Object, serde_json::Error> {
+    let mut value: serde_json::Value = serde_json::from_str(line)?;
+    if let Some(map) = value.as_object_mut() {
+        map.insert("class".into(), class_name.into());
+    }
+    serde_json::from_value(value)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
     use uuid::Uuid;
 
     use crate::{
-        collections::objects::{MultiObjects, Object},
+        collections::objects::{ConsistencyLevel, MultiObjects, Object},
         collections::{
             batch::{
                 BatchAddObject, BatchDeleteRequest, BatchDeleteResponse, BatchDeleteResult,
                 GeneralStatus, MatchConfig, ResultStatus,
             },
             objects::{Reference, References},
+            query::{FilterOperator, WhereFilter},
         },
         WeaviateClient,
     };
@@ -335,6 +561,54 @@ mod tests {
         BatchDeleteRequest::builder(MatchConfig::new("Test", map)).build()
     }
 
+    #[test]
+    fn test_match_config_from_filter() {
+        let map = serde_json::json!({
+            "operator": "NotEqual",
+            "path": ["name"],
+            "valueText": "aaa"
+        });
+        let filter = WhereFilter::builder(vec!["name"], FilterOperator::NOTEQUAL)
+            .with_value_text("aaa")
+            .build();
+        let match_config = MatchConfig::from_filter("Test", filter);
+        assert_eq!(
+            serde_json::to_value(MatchConfig::new("Test", map)).unwrap(),
+            serde_json::to_value(match_config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_match_config_by_id_like() {
+        let map = serde_json::json!({
+            "operator": "Like",
+            "path": ["id"],
+            "valueText": "*4*"
+        });
+        assert_eq!(
+            serde_json::to_value(MatchConfig::new("Test", map)).unwrap(),
+            serde_json::to_value(MatchConfig::by_id_like("Test", "*4*")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_match_config_by_ids() {
+        let id = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+        let map = serde_json::json!({
+            "operator": "ContainsAny",
+            "path": ["id"],
+            "valueTextArray": ["ee22d1b8-3b95-4e94-96d5-9a2b60fbd303"]
+        });
+        let request = BatchDeleteRequest::builder(MatchConfig::by_ids("Test", &[id])).build();
+        assert_eq!(
+            serde_json::to_value(
+                BatchDeleteRequest::builder(MatchConfig::new("Test", map)).build()
+            )
+            .unwrap(),
+            serde_json::to_value(request).unwrap()
+        );
+    }
+
     fn test_delete_response() -> BatchDeleteResponse {
         let map = serde_json::json!({
             "operator": "NotEqual",
@@ -420,6 +694,66 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_objects_batch_add_consistency_level() {
+        let objects = test_create_objects();
+        let res_str = test_batch_add_object_response();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .match_query(mockito::Matcher::Exact(
+                "consistency_level=QUORUM&tenant=TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client
+            .batch
+            .objects_batch_add(objects, Some(ConsistencyLevel::QUORUM), Some("TENANT_A"))
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_objects_batch_add_with_timeout() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let res_str = test_batch_add_object_response();
+        let mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_chunked_body(move |w| {
+                std::thread::sleep(Duration::from_millis(500));
+                w.write_all(res_str.as_bytes())
+            })
+            .expect_at_least(1)
+            .create();
+        let res = client
+            .batch
+            .objects_batch_add(test_create_objects(), None, None)
+            .await;
+        let err = res.unwrap_err();
+        assert!(err.downcast_ref::<reqwest::Error>().unwrap().is_timeout());
+        let res = client
+            .batch
+            .objects_batch_add_with_timeout(
+                test_create_objects(),
+                None,
+                None,
+                Duration::from_secs(5),
+            )
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_objects_batch_add_err() {
         let objects = test_create_objects();
@@ -430,6 +764,94 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_import_jsonl() {
+        let input = [
+            r#"{"properties": {"name": "a"}}"#,
+            r#"{"properties": {"name": "b""#,
+            r#"{"class": "Other", "properties": {"name": "c"}}"#,
+        ]
+        .join("\n");
+        let res_str = serde_json::json!([
+            {"class": "Test", "properties": {"name": "a"}, "result": {"status": "SUCCESS"}},
+            {"class": "Test", "properties": {"name": "c"}, "result": {"status": "FAILED"}},
+        ])
+        .to_string();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "objects": [
+                    {"class": "Test", "properties": {"name": "a"}},
+                    {"class": "Test", "properties": {"name": "c"}},
+                ]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client
+            .batch
+            .import_jsonl(input.as_bytes(), "Test", 10, None)
+            .await;
+        mock.assert();
+        let report = res.unwrap();
+        assert_eq!(1, report.imported);
+        assert_eq!(1, report.failed);
+        assert_eq!(1, report.malformed.len());
+        assert_eq!(2, report.malformed[0].line);
+    }
+
+    async fn get_compressed_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_compression(true)
+            .build()
+            .unwrap();
+        (mock_server, client)
+    }
+
+    #[tokio::test]
+    async fn test_objects_batch_add_compression_small_body() {
+        let objects = test_create_objects();
+        let res_str = test_batch_add_object_response();
+        let (mut mock_server, client) = get_compressed_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .match_header("content-encoding", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client.batch.objects_batch_add(objects, None, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_objects_batch_add_compression_large_body() {
+        let mut objects = test_create_objects();
+        for _ in 0..50 {
+            objects
+                .objects
+                .push(test_create_objects().objects.remove(0));
+        }
+        let res_str = test_batch_add_object_response();
+        let (mut mock_server, client) = get_compressed_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .match_header("content-encoding", "gzip")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client.batch.objects_batch_add(objects, None, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_objects_batch_delete_ok() {
         let req = test_delete_objects();
@@ -442,6 +864,30 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_objects_batch_delete_tenant() {
+        let req = test_delete_objects();
+        let out = test_delete_response();
+        let res_str = serde_json::to_string(&out).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", "/v1/batch/objects")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "tenant".into(),
+                "TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client
+            .batch
+            .objects_batch_delete(req, None, Some("TENANT_A"))
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_objects_batch_delete_err() {
         let req = test_delete_objects();
@@ -463,6 +909,76 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_references_batch_add_multi_target() {
+        let uuid = Uuid::parse_str("36ddd591-2dee-4e7e-a3cc-eb86d30a4303").unwrap();
+        let uuid2 = Uuid::parse_str("6bb06a43-e7f0-393e-9ecf-3c0f4e129064").unwrap();
+        let uuid3 = Uuid::parse_str("b72912b9-e5d7-304e-a654-66dc63c55b32").unwrap();
+        let refs = References::new(vec![Reference::builder(
+            "Test", &uuid, "testProp", "Other", &uuid2,
+        )
+        .with_additional_target("Another", &uuid3)
+        .build()]);
+        let res_str = test_add_references_response();
+        let (mut mock_server, client) = get_test_harness().await;
+        let schema = serde_json::json!({
+            "class": "Test",
+            "properties": [{"name": "testProp", "dataType": ["Other", "Another"]}]
+        });
+        let schema_mock = mock_server
+            .mock("GET", "/v1/schema/Test")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(schema.to_string())
+            .create();
+        let mock = mock_server
+            .mock("POST", "/v1/batch/references")
+            .match_body(mockito::Matcher::Json(serde_json::json!([
+                {
+                    "from": "weaviate://localhost/Test/36ddd591-2dee-4e7e-a3cc-eb86d30a4303/testProp",
+                    "to": "weaviate://localhost/Other/6bb06a43-e7f0-393e-9ecf-3c0f4e129064",
+                },
+                {
+                    "from": "weaviate://localhost/Test/36ddd591-2dee-4e7e-a3cc-eb86d30a4303/testProp",
+                    "to": "weaviate://localhost/Another/b72912b9-e5d7-304e-a654-66dc63c55b32",
+                },
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client.batch.references_batch_add(refs, None, None).await;
+        schema_mock.assert();
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_references_batch_add_beacons() {
+        let refs = test_references();
+        let res_str = test_add_references_response();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/references")
+            .match_body(mockito::Matcher::Json(serde_json::json!([
+                {
+                    "from": "weaviate://localhost/Test/36ddd591-2dee-4e7e-a3cc-eb86d30a4303/testProp",
+                    "to": "weaviate://localhost/Other/6bb06a43-e7f0-393e-9ecf-3c0f4e129064",
+                },
+                {
+                    "from": "weaviate://localhost/Test/36ddd591-2dee-4e7e-a3cc-eb86d30a4303/testProp",
+                    "to": "weaviate://localhost/Other/b72912b9-e5d7-304e-a654-66dc63c55b32",
+                },
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client.batch.references_batch_add(refs, None, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_references_batch_add_err() {
         let refs = test_references();
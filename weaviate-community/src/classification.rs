@@ -5,21 +5,22 @@ use uuid::Uuid;
 
 use crate::collections::{
     classification::{ClassificationRequest, ClassificationResponse},
-    error::ClassificationError,
+    error::{ClassificationError, WeaviateError},
 };
+use crate::http::HttpClient;
 
 /// All classification related endpoints and functionality described in
 /// [Weaviate meta API documentation](https://weaviate.io/developers/weaviate/api/rest/classification)
 #[derive(Debug)]
 pub struct Classification {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
 }
 
 impl Classification {
     /// Create a new instance of the Classification endpoint struct. Should only be done by the 
     /// parent client.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/classifications/")?;
         Ok(Classification { endpoint, client })
     }
@@ -65,16 +66,14 @@ impl Classification {
     ) -> Result<ClassificationResponse, Box<dyn Error>> {
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&request)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&request))
             .await?;
         match res.status() {
             reqwest::StatusCode::CREATED => {
                 let res: ClassificationResponse = res.json().await?;
                 Ok(res)
             }
-            _ => Err(self.get_err_msg("schedule classification", res).await)
+            _ => Err(self.get_err_msg("schedule classification", res).await),
         }
     }
 
@@ -96,7 +95,7 @@ impl Classification {
     /// ```
     pub async fn get(&self, id: Uuid) -> Result<ClassificationResponse, Box<dyn Error>> {
         let endpoint = self.endpoint.join(&id.to_string())?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: ClassificationResponse = res.json().await?;
@@ -106,6 +105,78 @@ impl Classification {
         }
     }
 
+    /// List the past and running classifications.
+    ///
+    /// Weaviate does not currently expose an endpoint to list classifications, only to `get` one
+    /// by id, so against current servers this returns a `WeaviateError::Unsupported`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///
+    ///     let res = client.classification.list().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list(&self) -> Result<Vec<ClassificationResponse>, Box<dyn Error>> {
+        let res = self
+            .client
+            .send(self.client.get(self.endpoint.clone()))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Vec<ClassificationResponse> = res.json().await?;
+                Ok(res)
+            }
+            reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::METHOD_NOT_ALLOWED => {
+                Err(Box::new(WeaviateError::Unsupported(
+                    "Weaviate has no endpoint to list classifications".into(),
+                )))
+            }
+            _ => Err(self.get_err_msg("list classifications", res).await),
+        }
+    }
+
+    /// Cancel a running classification.
+    ///
+    /// Weaviate does not currently expose an endpoint to cancel classifications, so against
+    /// current servers this returns a `WeaviateError::Unsupported`. If there is no classification
+    /// with the given id, a `WeaviateError::NotFound` is returned instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let uuid = Uuid::parse_str("00037775-1432-35e5-bc59-443baaef7d80")?;
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///
+    ///     let res = client.classification.cancel(uuid).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn cancel(&self, id: Uuid) -> Result<bool, Box<dyn Error>> {
+        let endpoint = self.endpoint.join(&id.to_string())?;
+        let res = self.client.send(self.client.delete(endpoint)).await?;
+        match res.status() {
+            reqwest::StatusCode::OK | reqwest::StatusCode::NO_CONTENT => Ok(true),
+            reqwest::StatusCode::NOT_FOUND => Err(Box::new(WeaviateError::NotFound(format!(
+                "classification {} does not exist",
+                id
+            )))),
+            reqwest::StatusCode::METHOD_NOT_ALLOWED => Err(Box::new(WeaviateError::Unsupported(
+                "Weaviate has no endpoint to cancel classifications".into(),
+            ))),
+            _ => Err(self.get_err_msg("cancel classification", res).await),
+        }
+    }
+
     /// Get the error message for the endpoint
     ///
     /// Made to reduce the boilerplate error message building
@@ -137,11 +208,12 @@ impl Classification {
 
 #[cfg(test)]
 mod tests {
-    use uuid::Uuid;
     use crate::{
+        collections::classification::{ClassificationRequest, ClassificationType},
+        collections::error::WeaviateError,
         WeaviateClient,
-        collections::classification::{ClassificationRequest, ClassificationType}
     };
+    use uuid::Uuid;
 
     async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
         let mock_server = mockito::Server::new_async().await;
@@ -221,4 +293,106 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    fn test_classification_list_response(uuid: &Uuid) -> String {
+        serde_json::json!([{
+            "id": uuid,
+            "class": "Test",
+            "classifyProperties": ["hasPopularity"],
+            "basedOnProperties": ["testProp"],
+            "status": "running",
+            "meta": {
+                "started": "2023-01-01T00:00:00.000Z",
+                "completed": "0001-01-01T00:00:00.000Z",
+                "count": 10,
+                "countSucceeded": 5,
+                "countFailed": 0
+            },
+            "type": "knn",
+            "settings": {"k": 3},
+            "filters": {}
+        }])
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_classification_list_ok() {
+        let uuid = Uuid::new_v4();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/classifications/",
+            200,
+            &test_classification_list_response(&uuid),
+        )
+        .await;
+        let res = client.classification.list().await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(1, res.len());
+        assert_eq!(uuid, res[0].id);
+    }
+
+    #[tokio::test]
+    async fn test_classification_list_unsupported() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/classifications/", 405, "").await;
+        let res = client.classification.list().await;
+        mock.assert();
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::Unsupported(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_classification_cancel_ok() {
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/classifications/");
+        url.push_str(&uuid.to_string());
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", url.as_str())
+            .with_status(204)
+            .create();
+        let res = client.classification.cancel(uuid).await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_classification_cancel_unsupported() {
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/classifications/");
+        url.push_str(&uuid.to_string());
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", url.as_str())
+            .with_status(405)
+            .create();
+        let res = client.classification.cancel(uuid).await;
+        mock.assert();
+        match res.unwrap_err().downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::Unsupported(msg)) => assert!(msg.contains("cancel")),
+            _ => panic!("expected an Unsupported error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_classification_cancel_not_found() {
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/classifications/");
+        url.push_str(&uuid.to_string());
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", url.as_str())
+            .with_status(404)
+            .create();
+        let res = client.classification.cancel(uuid).await;
+        mock.assert();
+        match res.unwrap_err().downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::NotFound(msg)) => assert!(msg.contains(&uuid.to_string())),
+            _ => panic!("expected a NotFound error"),
+        }
+    }
 }
@@ -23,6 +23,52 @@ impl AuthApiKey {
     }
 }
 
+/// The authentication scheme used by a `WeaviateClient` to authenticate with Weaviate.
+///
+/// This is applied to the `Authorization` header of every request, separately from any module
+/// api keys set with `WeaviateClientBuilder::with_api_key`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Auth {
+    /// A Weaviate API key.
+    ApiKey(String),
+    /// An OIDC bearer (access) token.
+    Bearer(String),
+    /// Anonymous access.
+    #[default]
+    None,
+}
+
+impl Auth {
+    /// Retrieve the `reqwest::header::HeaderValue` for an Authorization header, if any.
+    ///
+    /// Tokens that are not valid header values are rejected by `WeaviateClientBuilder::build`,
+    /// so this only returns `None` for `Auth::None` on a built client.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::auth::Auth;
+    ///
+    /// let value = Auth::Bearer("token".into()).get_header_value().unwrap();
+    /// assert_eq!("Bearer token", value.to_str().unwrap());
+    /// assert!(Auth::None.get_header_value().is_none());
+    /// ```
+    pub fn get_header_value(&self) -> Option<HeaderValue> {
+        let token = match self {
+            Auth::ApiKey(token) | Auth::Bearer(token) => token,
+            Auth::None => return None,
+        };
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", token)).ok()?;
+        value.set_sensitive(true);
+        Some(value)
+    }
+}
+
+impl From<AuthApiKey> for Auth {
+    fn from(auth: AuthApiKey) -> Self {
+        Auth::ApiKey(auth.api_key)
+    }
+}
+
 /// The `AuthApiKey` can be used to attach a bearer token to a `WeaviateClient`.
 #[derive(Debug)]
 pub struct ApiKey {
@@ -42,6 +42,9 @@ pub struct BackupCreateRequest {
     pub id: String,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupCreateRequest {
@@ -68,6 +71,7 @@ pub struct BackupCreateRequestBuilder {
     pub id: String,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupCreateRequestBuilder {
@@ -89,6 +93,7 @@ impl BackupCreateRequestBuilder {
             id: id.into(),
             include: None,
             exclude: None,
+            config: None,
         }
     }
 
@@ -126,6 +131,23 @@ impl BackupCreateRequestBuilder {
         self
     }
 
+    /// Set the backend configuration of the BackupCreateRequest, such as the bucket and path.
+    ///
+    /// # Parameters
+    /// - config: the backend configuration to use for the backup
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::{BackupConfig, BackupCreateRequestBuilder};
+    ///
+    /// let config = BackupConfig::builder().with_bucket("my-bucket").build();
+    /// let builder = BackupCreateRequestBuilder::new("my-backup").with_config(config);
+    /// ```
+    pub fn with_config(mut self, config: BackupConfig) -> BackupCreateRequestBuilder {
+        self.config = Some(config);
+        self
+    }
+
     /// Build the BackupCreateRequest from the BackupCreateRequestBuilder
     ///
     /// # Example
@@ -147,6 +169,7 @@ impl BackupCreateRequestBuilder {
             id: self.id,
             include: self.include,
             exclude: self.exclude,
+            config: self.config,
         }
     }
 }
@@ -157,6 +180,9 @@ impl BackupCreateRequestBuilder {
 pub struct BackupRestoreRequest {
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupRestoreRequest {
@@ -180,6 +206,7 @@ impl BackupRestoreRequest {
 pub struct BackupRestoreRequestBuilder {
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupRestoreRequestBuilder {
@@ -197,6 +224,7 @@ impl BackupRestoreRequestBuilder {
         BackupRestoreRequestBuilder {
             include: None,
             exclude: None,
+            config: None,
         }
     }
 
@@ -234,6 +262,23 @@ impl BackupRestoreRequestBuilder {
         self
     }
 
+    /// Set the backend configuration of the BackupRestoreRequest, such as the bucket and path.
+    ///
+    /// # Parameters
+    /// - config: the backend configuration to restore the backup from
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::{BackupConfig, BackupRestoreRequestBuilder};
+    ///
+    /// let config = BackupConfig::builder().with_path("backups/nightly").build();
+    /// let builder = BackupRestoreRequestBuilder::new().with_config(config);
+    /// ```
+    pub fn with_config(mut self, config: BackupConfig) -> BackupRestoreRequestBuilder {
+        self.config = Some(config);
+        self
+    }
+
     /// Build the BackupRestoreRequest from the BackupRestoreRequestBuilder
     ///
     /// # Example
@@ -254,6 +299,153 @@ impl BackupRestoreRequestBuilder {
         BackupRestoreRequest {
             include: self.include,
             exclude: self.exclude,
+            config: self.config,
+        }
+    }
+}
+
+/// BackupConfig struct defining the backend configuration of a backup or restore, overriding the
+/// configuration of the Weaviate server.
+///
+/// `chunk_size` is only used when creating a backup.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct BackupConfig {
+    #[serde(rename = "Bucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bucket: Option<String>,
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(rename = "CPUPercentage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpu_percentage: Option<u32>,
+    #[serde(rename = "ChunkSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub chunk_size: Option<u32>,
+}
+
+impl BackupConfig {
+    /// Create a new builder for the BackupConfig object.
+    ///
+    /// This is the same as `BackupConfigBuilder::new()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfig;
+    ///
+    /// let builder = BackupConfig::builder();
+    /// ```
+    pub fn builder() -> BackupConfigBuilder {
+        BackupConfigBuilder::default()
+    }
+}
+
+/// BackupConfigBuilder for building new BackupConfigs
+#[derive(Default)]
+pub struct BackupConfigBuilder {
+    pub bucket: Option<String>,
+    pub path: Option<String>,
+    pub cpu_percentage: Option<u32>,
+    pub chunk_size: Option<u32>,
+}
+
+impl BackupConfigBuilder {
+    /// Create a new builder for the BackupConfig object.
+    ///
+    /// This is the same as `BackupConfig::builder()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let builder = BackupConfigBuilder::new();
+    /// ```
+    pub fn new() -> BackupConfigBuilder {
+        BackupConfigBuilder::default()
+    }
+
+    /// Set the bucket of the backend to use.
+    ///
+    /// # Parameters
+    /// - bucket: the name of the S3, GCS, or Azure bucket
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let builder = BackupConfigBuilder::new().with_bucket("my-bucket");
+    /// ```
+    pub fn with_bucket(mut self, bucket: &str) -> BackupConfigBuilder {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    /// Set the path within the bucket to use.
+    ///
+    /// # Parameters
+    /// - path: the path within the bucket
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let builder = BackupConfigBuilder::new().with_path("backups/nightly");
+    /// ```
+    pub fn with_path(mut self, path: &str) -> BackupConfigBuilder {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the percentage of CPU the backup or restore may use.
+    ///
+    /// # Parameters
+    /// - cpu_percentage: the percentage of CPU to use, between 1 and 80
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let builder = BackupConfigBuilder::new().with_cpu_percentage(40);
+    /// ```
+    pub fn with_cpu_percentage(mut self, cpu_percentage: u32) -> BackupConfigBuilder {
+        self.cpu_percentage = Some(cpu_percentage);
+        self
+    }
+
+    /// Set the chunk size of the backup, in MB.
+    ///
+    /// # Parameters
+    /// - chunk_size: the chunk size in MB, between 2 and 512
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let builder = BackupConfigBuilder::new().with_chunk_size(128);
+    /// ```
+    pub fn with_chunk_size(mut self, chunk_size: u32) -> BackupConfigBuilder {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Build the BackupConfig from the BackupConfigBuilder
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::backups::BackupConfigBuilder;
+    ///
+    /// let config = BackupConfigBuilder::new().with_bucket("my-bucket").build();
+    /// ```
+    pub fn build(self) -> BackupConfig {
+        BackupConfig {
+            bucket: self.bucket,
+            path: self.path,
+            cpu_percentage: self.cpu_percentage,
+            chunk_size: self.chunk_size,
         }
     }
 }
@@ -261,7 +453,7 @@ impl BackupRestoreRequestBuilder {
 /// Strict definitions of the different backup status' available for backups.
 ///
 /// Weaviate supports STARTED, SUCCESS, FAILED, TRANSFERRING, and TRANSFERRED.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum BackupStatus {
     STARTED,
     SUCCESS,
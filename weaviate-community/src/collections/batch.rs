@@ -1,4 +1,5 @@
 use crate::collections::objects::Object;
+use crate::collections::query::{FilterOperator, WhereFilter};
 /// All batch associated type components
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -203,6 +204,69 @@ impl MatchConfig {
             match_where,
         }
     }
+
+    /// Create a new MatchConfig from a typed `WhereFilter`.
+    ///
+    /// # Parameters
+    /// - class: the name of the class to delete objects from
+    /// - filter: the filter the objects to delete must match
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::batch::MatchConfig;
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    ///
+    /// let filter = WhereFilter::builder(vec!["name"], FilterOperator::NOTEQUAL)
+    ///     .with_value_text("aaa")
+    ///     .build();
+    /// let match_config = MatchConfig::from_filter("Article", filter);
+    /// ```
+    pub fn from_filter(class: &str, filter: WhereFilter) -> MatchConfig {
+        MatchConfig {
+            class: class.into(),
+            match_where: serde_json::json!(filter),
+        }
+    }
+
+    /// Create a new MatchConfig matching the objects whose id is like the pattern.
+    ///
+    /// # Parameters
+    /// - class: the name of the class to delete objects from
+    /// - pattern: the `Like` pattern, where `*` matches any characters and `?` matches one
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::batch::MatchConfig;
+    ///
+    /// let match_config = MatchConfig::by_id_like("Article", "*4*");
+    /// ```
+    pub fn by_id_like(class: &str, pattern: &str) -> MatchConfig {
+        let filter = WhereFilter::builder(vec!["id"], FilterOperator::LIKE)
+            .with_value_text(pattern)
+            .build();
+        MatchConfig::from_filter(class, filter)
+    }
+
+    /// Create a new MatchConfig matching the objects with any of the ids.
+    ///
+    /// # Parameters
+    /// - class: the name of the class to delete objects from
+    /// - ids: the ids of the objects to match
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::batch::MatchConfig;
+    ///
+    /// let match_config = MatchConfig::by_ids("Article", &[Uuid::new_v4(), Uuid::new_v4()]);
+    /// ```
+    pub fn by_ids(class: &str, ids: &[Uuid]) -> MatchConfig {
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        let filter = WhereFilter::builder(vec!["id"], FilterOperator::CONTAINSANY)
+            .with_value_text_array(ids.iter().map(|id| id.as_str()).collect())
+            .build();
+        MatchConfig::from_filter(class, filter)
+    }
 }
 
 /// Strict definitions of the different verbosity levels available.
@@ -263,7 +327,7 @@ pub struct DeleteObject {
 /// Strict definitions of the different status levels available for batch requests.
 ///
 /// Weaviate supports SUCCESS, FAILED, and DRYRUN.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum GeneralStatus {
     SUCCESS,
     FAILED,
@@ -304,7 +368,7 @@ pub struct ErrorMessage {
 ///
 /// You shouldn't need to create this yourself.
 #[derive(Serialize, Deserialize, Debug)]
-pub struct BatchAddObjects(Vec<BatchAddObject>);
+pub struct BatchAddObjects(pub Vec<BatchAddObject>);
 
 /// This is basically the same as the collections::objects variant of an Object,
 /// however there is an extra field which Weaviate polls with a ResultStatus.
@@ -354,6 +418,30 @@ impl BatchAddObject {
     }
 }
 
+/// The aggregated report of a `Batch::import_jsonl` run.
+///
+/// There should be no need to make this manually.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// The number of objects Weaviate reported as successfully added.
+    pub imported: u64,
+    /// The number of objects Weaviate reported as failed.
+    pub failed: u64,
+    /// The lines that could not be parsed as an object, and so were never sent.
+    pub malformed: Vec<MalformedLine>,
+}
+
+/// A line of a JSONL import that could not be parsed as an object.
+///
+/// There should be no need to make this manually.
+#[derive(Debug)]
+pub struct MalformedLine {
+    /// The 1-based line number in the input.
+    pub line: usize,
+    /// Why the line could not be parsed.
+    pub error: String,
+}
+
 /// Wrapper for the response of the batch add response payload items for each beacon.
 ///
 /// There should be no need to make this manually.
@@ -1,18 +1,42 @@
 /// All custom errors
+use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result},
 };
 
-/// Custom QueryError, used when there was a mismatch in expected query parameters for endpoints.
+/// Custom QueryError, used when there was a mismatch in expected query parameters for endpoints,
+/// or when an endpoint responds with an unexpected status code.
 #[derive(Debug)]
-pub struct QueryError(pub String);
+pub struct QueryError {
+    /// The status code of the response, if the error came from one.
+    pub status: Option<reqwest::StatusCode>,
+    pub message: String,
+}
+
+impl QueryError {
+    /// Create a new QueryError that did not come from a response.
+    pub fn new(message: impl Into<String>) -> QueryError {
+        QueryError {
+            status: None,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new QueryError for a response with the given status code.
+    pub fn with_status(status: reqwest::StatusCode, message: impl Into<String>) -> QueryError {
+        QueryError {
+            status: Some(status),
+            message: message.into(),
+        }
+    }
+}
 
 impl Error for QueryError {}
 
 impl Display for QueryError {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(f, "Invalid query parameters passed: {}", self.0)
+        write!(f, "Invalid query parameters passed: {}", self.message)
     }
 }
 
@@ -64,18 +88,64 @@ impl Display for BackupError {
     }
 }
 
-/// Custom GraphQLError, used when there was a mismatch in expected query parameters for endpoints.
-#[derive(Debug)]
-pub struct GraphQLError(pub String);
+/// Custom GraphQLError, used when a GraphQL query could not be executed.
+///
+/// Weaviate describes each failure of a query in the `errors` of the response, in this format.
+/// Failures that happen before the query reaches Weaviate only have a `message`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GraphQLError {
+    pub message: String,
+    /// The path of the field in the response that failed, as field names and list indices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub path: Option<Vec<serde_json::Value>>,
+    /// The positions in the query that caused the failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub locations: Option<Vec<Location>>,
+}
+
+impl GraphQLError {
+    /// Create a new GraphQLError with only a message.
+    pub fn new(message: impl Into<String>) -> GraphQLError {
+        GraphQLError {
+            message: message.into(),
+            path: None,
+            locations: None,
+        }
+    }
+}
 
 impl Error for GraphQLError {}
 
 impl Display for GraphQLError {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        write!(f, "Error executing GraphQL query: {}", self.0)
+        write!(f, "Error executing GraphQL query: {}", self.message)?;
+        if let Some(path) = &self.path {
+            let path: Vec<String> = path
+                .iter()
+                .map(|p| {
+                    p.as_str()
+                        .map(String::from)
+                        .unwrap_or_else(|| p.to_string())
+                })
+                .collect();
+            write!(f, " (path: {})", path.join("."))?;
+        }
+        if let Some(location) = self.locations.as_ref().and_then(|l| l.first()) {
+            write!(f, " (line {}, column {})", location.line, location.column)?;
+        }
+        Ok(())
     }
 }
 
+/// A position within a GraphQL query.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
 /// Custom NodesError, used when there was an incorrect status code for the nodes endpoint.
 #[derive(Debug)]
 pub struct NodesError(pub String);
@@ -113,3 +183,82 @@ impl Display for ModuleError {
         write!(f, "ModuleErEror: {}", self.0)
     }
 }
+
+/// Custom WeaviateError, used for distinct failure conditions reported by Weaviate that callers
+/// may want to handle explicitly, rather than a generic endpoint error.
+///
+/// As all endpoints return a `Box<dyn Error>`, this can be recovered using `downcast_ref`.
+///
+/// # Example
+/// ```
+/// use weaviate_community::collections::error::WeaviateError;
+///
+/// let err: Box<dyn std::error::Error> = Box::new(WeaviateError::AlreadyExists {
+///     class: "Article".into(),
+/// });
+/// match err.downcast_ref::<WeaviateError>() {
+///     Some(WeaviateError::AlreadyExists { class }) => assert_eq!("Article", class),
+///     _ => panic!("unexpected error"),
+/// }
+/// ```
+#[derive(Debug)]
+pub enum WeaviateError {
+    /// The resource being created already exists.
+    AlreadyExists {
+        /// The name of the class that already exists, or of the class of the existing object.
+        class: String,
+    },
+    /// The requested resource does not exist.
+    NotFound(String),
+    /// The request could not be built or sent, as it is invalid.
+    InvalidRequest(String),
+    /// The request attempts to change a field that cannot be changed once it has been set.
+    ImmutableField {
+        /// The path of the immutable field, such as `vectorIndexConfig.distance`.
+        field: String,
+    },
+    /// The operation was cancelled by the caller before it completed.
+    Cancelled(String),
+    /// The server rejected the object, with a message for each field that failed validation.
+    ValidationError {
+        /// The validation messages returned by the server.
+        messages: Vec<String>,
+    },
+    /// The GraphQL query failed, with the errors returned by the server.
+    GraphQL(Vec<GraphQLError>),
+    /// The resource was changed by another writer since it was last read.
+    Conflict(String),
+    /// The operation is not supported by the Weaviate server.
+    Unsupported(String),
+}
+
+impl Error for WeaviateError {}
+
+impl Display for WeaviateError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            WeaviateError::AlreadyExists { class } => {
+                write!(f, "AlreadyExists: conflict in class `{}`", class)
+            }
+            WeaviateError::NotFound(msg) => write!(f, "NotFound: {}", msg),
+            WeaviateError::InvalidRequest(msg) => write!(f, "InvalidRequest: {}", msg),
+            WeaviateError::ImmutableField { field } => {
+                write!(
+                    f,
+                    "ImmutableField: `{}` cannot be changed after creation",
+                    field
+                )
+            }
+            WeaviateError::Cancelled(msg) => write!(f, "Cancelled: {}", msg),
+            WeaviateError::ValidationError { messages } => {
+                write!(f, "ValidationError: {}", messages.join("; "))
+            }
+            WeaviateError::GraphQL(errors) => {
+                let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+                write!(f, "GraphQL: {}", messages.join("; "))
+            }
+            WeaviateError::Conflict(msg) => write!(f, "Conflict: {}", msg),
+            WeaviateError::Unsupported(msg) => write!(f, "Unsupported: {}", msg),
+        }
+    }
+}
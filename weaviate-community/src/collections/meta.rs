@@ -1,6 +1,12 @@
 /// All meta associated type components
 use serde::{Deserialize, Serialize};
 
+/// The name prefixes of the modules that vectorize objects.
+const VECTORIZER_PREFIXES: &[&str] = &["text2vec-", "multi2vec-", "img2vec-", "ref2vec-"];
+
+/// The name prefixes of the modules that generate results from objects.
+const GENERATOR_PREFIXES: &[&str] = &["generative-"];
+
 /// The Metadata struct used to contain all of the results returned from the get_meta endpoint.
 ///
 /// There should never be a need for this to be created manually.
@@ -10,3 +16,48 @@ pub struct Metadata {
     pub modules: serde_json::Value,
     pub version: String,
 }
+
+impl Metadata {
+    /// Check whether a module is enabled on the Weaviate instance.
+    ///
+    /// # Parameters
+    /// - name: the name of the module, such as `generative-openai`
+    pub fn is_module_enabled(&self, name: &str) -> bool {
+        self.module_config(name).is_some()
+    }
+
+    /// Get the configuration reported for a module, if it is enabled.
+    ///
+    /// # Parameters
+    /// - name: the name of the module, such as `text2vec-cohere`
+    pub fn module_config(&self, name: &str) -> Option<&serde_json::Value> {
+        self.modules.get(name)
+    }
+
+    /// Get the names of the enabled vectorizer modules, such as `text2vec-openai`.
+    ///
+    /// Any of these can be used as the `vectorizer` of a class. The configuration each reports,
+    /// such as its default model, is available through `module_config`.
+    pub fn vectorizers(&self) -> Vec<String> {
+        self.modules_with_prefix(VECTORIZER_PREFIXES)
+    }
+
+    /// Get the names of the enabled generative modules, such as `generative-openai`.
+    pub fn generators(&self) -> Vec<String> {
+        self.modules_with_prefix(GENERATOR_PREFIXES)
+    }
+
+    /// Get the names of the enabled modules starting with any of the prefixes.
+    fn modules_with_prefix(&self, prefixes: &[&str]) -> Vec<String> {
+        self.modules
+            .as_object()
+            .map(|modules| {
+                modules
+                    .keys()
+                    .filter(|name| prefixes.iter().any(|prefix| name.starts_with(prefix)))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
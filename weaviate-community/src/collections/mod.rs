@@ -9,4 +9,5 @@ pub mod objects;
 pub mod oidc;
 pub mod query;
 pub mod schema;
+pub mod summary;
 pub mod modules;
@@ -102,3 +102,16 @@ impl ContextionaryExtension {
         ContextionaryExtension { concept: concept.into(), definition: definition.into(), weight }
     }
 }
+
+/// The expected response format when received from /v1/modules/text-spellcheck/spellcheck
+/// successfully.
+///
+/// This shouldn't be something you create yourself, as it is returned by the appropriate
+/// endpoint when deserialized.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellcheckResult {
+    pub original: String,
+    pub did_change: bool,
+    pub corrected: String,
+}
@@ -10,6 +10,16 @@ pub struct MultiNodes {
     pub nodes: Vec<Node>,
 }
 
+impl MultiNodes {
+    /// Count the nodes reporting a `HEALTHY` status.
+    pub fn healthy_count(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|node| node.status == Some(NodeStatus::HEALTHY))
+            .count()
+    }
+}
+
 /// The expected response format when received from /v1/nodes successfully.
 ///
 /// This shouldn't be something you create yourself, as it is returned by the appropriate
@@ -102,7 +112,7 @@ pub struct NodeStats {
 ///
 /// This shouldn't be something you create yourself, as it is returned by the appropriate
 /// endpoint when deserialized.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum NodeStatus {
     HEALTHY,
     UNHEALTHY,
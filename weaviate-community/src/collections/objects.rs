@@ -2,6 +2,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::collections::error::QueryError;
+
 /// Wrapper for multiple objects.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MultiObjects {
@@ -26,9 +28,51 @@ impl MultiObjects {
     }
 }
 
-/// Object struct used for creating a new Object.
+/// The response of listing data objects, including the paging metadata returned by Weaviate.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct ListObjectsResponse {
+    #[serde(default)]
+    pub objects: Vec<Object>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub total_results: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub deprecations: Option<Vec<serde_json::Value>>,
+}
+
+/// The default host used within beacons.
+///
+/// The host of a beacon does not address a node: Weaviate always resolves beacons within the
+/// cluster that receives them, and writes `localhost` into the beacons it returns. `localhost` is
+/// therefore used regardless of the address the client connects to, unless overridden with
+/// `WeaviateClientBuilder::with_beacon_host`.
+pub(crate) const DEFAULT_BEACON_HOST: &str = "localhost";
+
+/// Build the beacon of an object, in the format `weaviate://{host}/{class_name}/{id}`.
+///
+/// The class name is always included, as Weaviate requires it to resolve the object.
+pub(crate) fn beacon(host: &str, class_name: &str, id: &Uuid) -> String {
+    format!("weaviate://{}/{}/{}", host, class_name, id)
+}
+
+/// Parse a beacon in the format `weaviate://{host}/{class_name}/{id}` into the class name and id.
+///
+/// Older beacons may not contain the class name, in which case `None` is returned in its place.
+pub(crate) fn parse_beacon(beacon: &str) -> Option<(Option<String>, Uuid)> {
+    let path = beacon.strip_prefix("weaviate://")?;
+    let mut parts = path.split('/').skip(1);
+    match (parts.next(), parts.next()) {
+        (Some(class_name), Some(id)) => Some((Some(class_name.into()), Uuid::parse_str(id).ok()?)),
+        (Some(id), None) => Some((None, Uuid::parse_str(id).ok()?)),
+        _ => None,
+    }
+}
+
+/// Object struct used for creating a new Object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Object {
     pub class: String,
     pub properties: serde_json::Value,
@@ -41,10 +85,12 @@ pub struct Object {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub tenant: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Read-only. Set by Weaviate, and never sent on create or update.
+    #[serde(skip_serializing)]
     #[serde(default)]
     pub creation_time_unix: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Read-only. Set by Weaviate, and never sent on create or update.
+    #[serde(skip_serializing)]
     #[serde(default)]
     pub last_update_time_unix: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,6 +124,7 @@ impl Object {
 /// The builder for an Object
 ///
 /// Note that you should not adjust the creation_time_unix or the last_update_time_unix values.
+/// They are read-only, and are never sent to Weaviate.
 pub struct ObjectBuilder {
     pub class: String,
     pub properties: serde_json::Value,
@@ -216,6 +263,151 @@ impl ObjectBuilder {
     }
 }
 
+/// The outcome of `Objects::create_if_absent`.
+#[derive(Debug, Clone)]
+pub enum CreateOutcome {
+    /// The object did not exist, and was created.
+    Created(Object),
+    /// An object with the same id already existed, and was left unchanged.
+    Existing(Object),
+}
+
+impl CreateOutcome {
+    /// Whether the object was created by the call.
+    pub fn created(&self) -> bool {
+        matches!(self, CreateOutcome::Created(_))
+    }
+
+    /// Get the created or existing object.
+    pub fn into_object(self) -> Object {
+        match self {
+            CreateOutcome::Created(object) | CreateOutcome::Existing(object) => object,
+        }
+    }
+}
+
+/// The value of a `geoCoordinates` property.
+///
+/// Can be used directly inside of the `properties` of an `Object`.
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::collections::objects::{GeoCoordinates, Object};
+///
+/// let properties = serde_json::json!({
+///     "name": "Amsterdam",
+///     "location": GeoCoordinates::new(52.366667, 4.9),
+/// });
+/// let object = Object::builder("City", properties).build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct GeoCoordinates {
+    pub latitude: f32,
+    pub longitude: f32,
+}
+
+impl GeoCoordinates {
+    /// Create a new GeoCoordinates value.
+    ///
+    /// # Parameters
+    /// - latitude: the latitude of the location
+    /// - longitude: the longitude of the location
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::GeoCoordinates;
+    ///
+    /// let location = GeoCoordinates::new(52.366667, 4.9);
+    /// ```
+    pub fn new(latitude: f32, longitude: f32) -> GeoCoordinates {
+        GeoCoordinates {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+/// The value of a `phoneNumber` property.
+///
+/// Only the `input` and `default_country` are sent to Weaviate. The remaining fields are
+/// read-only, and are populated by Weaviate when the object is retrieved.
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::collections::objects::{Object, PhoneNumber};
+///
+/// let properties = serde_json::json!({
+///     "name": "Jodi Kantor",
+///     "phone": PhoneNumber::new("020 1234567").with_default_country("nl"),
+/// });
+/// let object = Object::builder("Person", properties).build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneNumber {
+    pub input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub default_country: Option<String>,
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub international_formatted: Option<String>,
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub country_code: Option<u64>,
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub national: Option<u64>,
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub national_formatted: Option<String>,
+    #[serde(skip_serializing)]
+    #[serde(default)]
+    pub valid: Option<bool>,
+}
+
+impl PhoneNumber {
+    /// Create a new PhoneNumber value.
+    ///
+    /// # Parameters
+    /// - input: the phone number, as entered
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::PhoneNumber;
+    ///
+    /// let phone = PhoneNumber::new("+31 20 1234567");
+    /// ```
+    pub fn new(input: &str) -> PhoneNumber {
+        PhoneNumber {
+            input: input.into(),
+            default_country: None,
+            international_formatted: None,
+            country_code: None,
+            national: None,
+            national_formatted: None,
+            valid: None,
+        }
+    }
+
+    /// Set the ISO 3166-1 alpha-2 country code used to parse numbers without an international
+    /// prefix.
+    ///
+    /// # Parameters
+    /// - default_country: the country code to use
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::PhoneNumber;
+    ///
+    /// let phone = PhoneNumber::new("020 1234567").with_default_country("nl");
+    /// ```
+    pub fn with_default_country(mut self, default_country: &str) -> PhoneNumber {
+        self.default_country = Some(default_country.into());
+        self
+    }
+}
+
 /// Strict definitions for ordering queries.
 ///
 /// The options available are ASC and DESC.
@@ -250,7 +442,7 @@ impl OrderBy {
 /// - QUORUM / QUORUM => balanced write and read latency
 /// - ONE / ALL => fast write and slow read (optimized for write)
 /// - ALL / ONE => slow write and fast read (optimized for read)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum ConsistencyLevel {
     ONE,
     QUORUM,
@@ -273,9 +465,31 @@ impl ConsistencyLevel {
             ConsistencyLevel::ALL => "ALL",
         }
     }
+
+    /// Get the number of replicas that must respond for this ConsistencyLevel to be met, for
+    /// a class with the given replication factor.
+    ///
+    /// # Parameters
+    /// - replication_factor: the replication factor of the class
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::ConsistencyLevel;
+    ///
+    /// assert_eq!(1, ConsistencyLevel::ONE.required_replicas(3));
+    /// assert_eq!(2, ConsistencyLevel::QUORUM.required_replicas(3));
+    /// assert_eq!(3, ConsistencyLevel::ALL.required_replicas(3));
+    /// ```
+    pub fn required_replicas(&self, replication_factor: u64) -> u64 {
+        match self {
+            ConsistencyLevel::ONE => 1,
+            ConsistencyLevel::QUORUM => replication_factor / 2 + 1,
+            ConsistencyLevel::ALL => replication_factor,
+        }
+    }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ObjectListParameters {
     pub class_name: Option<String>,
     pub limit: Option<u64>,
@@ -284,6 +498,8 @@ pub struct ObjectListParameters {
     pub include: Option<String>,
     pub sort: Option<Vec<String>>,
     pub order: Option<Vec<String>>,
+    pub tenant: Option<String>,
+    pub node_name: Option<String>,
 }
 
 impl ObjectListParameters {
@@ -312,6 +528,40 @@ impl ObjectListParameters {
     pub fn builder() -> ObjectListParametersBuilder {
         ObjectListParametersBuilder::default()
     }
+
+    /// Check the parameters can be used together.
+    ///
+    /// Paging with the `after` cursor requires `class_name` to be set, and can't be combined
+    /// with `offset` or `sort`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::ObjectListParameters;
+    ///
+    /// let params = ObjectListParameters::builder()
+    ///     .with_after("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+    ///     .build();
+    /// assert!(params.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), QueryError> {
+        if self.after.is_none() {
+            return Ok(());
+        }
+        if self.class_name.is_none() {
+            return Err(QueryError::new(
+                "'class_name' must be Some when 'after' is Some",
+            ));
+        }
+        if self.offset.is_some() {
+            return Err(QueryError::new(
+                "'offset' must be None when 'after' is Some",
+            ));
+        }
+        if self.sort.is_some() {
+            return Err(QueryError::new("'sort' must be None when 'after' is Some"));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -323,6 +573,8 @@ pub struct ObjectListParametersBuilder {
     pub include: Option<String>,
     pub sort: Option<Vec<String>>,
     pub order: Option<Vec<String>>,
+    pub tenant: Option<String>,
+    pub node_name: Option<String>,
 }
 
 impl ObjectListParametersBuilder {
@@ -473,6 +725,41 @@ impl ObjectListParametersBuilder {
         self
     }
 
+    /// Add a value to the optional `tenant` value to the parameters.
+    ///
+    /// # Parameters
+    /// - tenant: the name of the tenant to list the objects of
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::ObjectListParametersBuilder;
+    ///
+    /// let builder = ObjectListParametersBuilder::new().with_tenant("TENANT_A");
+    /// ```
+    pub fn with_tenant(mut self, tenant: &str) -> ObjectListParametersBuilder {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Add a value to the optional `node_name` value to the parameters.
+    ///
+    /// The objects are then read from that node only, rather than from the replicas chosen by
+    /// the consistency level, which is useful for finding replicas that have drifted apart.
+    ///
+    /// # Parameters
+    /// - node_name: the name of the node to list the objects from
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::ObjectListParametersBuilder;
+    ///
+    /// let builder = ObjectListParametersBuilder::new().with_node_name("weaviate-0");
+    /// ```
+    pub fn with_node_name(mut self, node_name: &str) -> ObjectListParametersBuilder {
+        self.node_name = Some(node_name.into());
+        self
+    }
+
     /// Build the ObjectListParameters from the ObjectListParametersBuilder
     ///
     /// # Example
@@ -498,6 +785,8 @@ impl ObjectListParametersBuilder {
             include: self.include,
             sort: self.sort,
             order: self.order,
+            tenant: self.tenant,
+            node_name: self.node_name,
         }
     }
 }
@@ -534,12 +823,18 @@ impl References {
 }
 
 /// The Reference type for adding a reference between classes
+///
+/// A reference can point to objects in more than one class when the property is configured
+/// with multiple target classes. The extra (class, uuid) pairs are held in `additional_targets`,
+/// and a beacon is generated for each target.
+#[derive(Debug)]
 pub struct Reference {
     pub from_class_name: String,
     pub from_uuid: Uuid,
     pub from_property_name: String,
     pub to_class_name: String,
     pub to_uuid: Uuid,
+    pub additional_targets: Vec<(String, Uuid)>,
     pub consistency_level: Option<ConsistencyLevel>,
     pub tenant_name: Option<String>,
 }
@@ -576,11 +871,43 @@ impl Reference {
             from_property_name: from_property_name.into(),
             to_class_name: to_class_name.into(),
             to_uuid: to_uuid.clone(),
+            additional_targets: Vec::new(),
             consistency_level: None,
             tenant_name: None,
         }
     }
 
+    /// Get every (class, uuid) pair the reference points to, starting with `to_class_name` and
+    /// `to_uuid`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::objects::Reference;
+    ///
+    /// let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    /// let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    /// let uuid3 = Uuid::parse_str("b72912b9-e5d7-304e-a654-66dc63c55b32").unwrap();
+    /// let reference = Reference::builder(
+    ///     "Author",
+    ///     &uuid1,
+    ///     "wrote",
+    ///     "Article",
+    ///     &uuid2,
+    /// ).with_additional_target("Book", &uuid3).build();
+    ///
+    /// assert_eq!(2, reference.targets().len());
+    /// ```
+    pub fn targets(&self) -> Vec<(&str, &Uuid)> {
+        std::iter::once((self.to_class_name.as_str(), &self.to_uuid))
+            .chain(
+                self.additional_targets
+                    .iter()
+                    .map(|(c, id)| (c.as_str(), id)),
+            )
+            .collect()
+    }
+
     /// Create a new builder for the Reference.
     ///
     /// This is the same as `ReferenceBuilder::new()`.
@@ -625,6 +952,7 @@ pub struct ReferenceBuilder {
     pub from_property_name: String,
     pub to_class_name: String,
     pub to_uuid: Uuid,
+    pub additional_targets: Vec<(String, Uuid)>,
     pub consistency_level: Option<ConsistencyLevel>,
     pub tenant_name: Option<String>,
 }
@@ -663,6 +991,7 @@ impl ReferenceBuilder {
             from_property_name: from_property_name.into(),
             to_class_name: to_class_name.into(),
             to_uuid: to_uuid.clone(),
+            additional_targets: Vec::new(),
             consistency_level: None,
             tenant_name: None,
         }
@@ -697,6 +1026,39 @@ impl ReferenceBuilder {
         self
     }
 
+    /// Add another target to the reference, for properties configured with multiple target
+    /// classes.
+    ///
+    /// # Parameters
+    /// - to_class_name: the name of the class to beacon to
+    /// - to_uuid: the uuid of the object to beacon to
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::objects::ReferenceBuilder;
+    ///
+    /// let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    /// let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    /// let uuid3 = Uuid::parse_str("b72912b9-e5d7-304e-a654-66dc63c55b32").unwrap();
+    /// let reference = ReferenceBuilder::new(
+    ///     "Author",
+    ///     &uuid1,
+    ///     "wrote",
+    ///     "Article",
+    ///     &uuid2,
+    /// ).with_additional_target("Book", &uuid3).build();
+    /// ```
+    pub fn with_additional_target(
+        mut self,
+        to_class_name: &str,
+        to_uuid: &Uuid,
+    ) -> ReferenceBuilder {
+        self.additional_targets
+            .push((to_class_name.into(), *to_uuid));
+        self
+    }
+
     /// Add a value to the optional `tenant_name` value to the reference.
     ///
     /// # Parameters
@@ -764,6 +1126,7 @@ impl ReferenceBuilder {
             from_property_name: self.from_property_name,
             to_class_name: self.to_class_name,
             to_uuid: self.to_uuid,
+            additional_targets: self.additional_targets,
             consistency_level: self.consistency_level,
             tenant_name: self.tenant_name,
         }
@@ -12,3 +12,56 @@ pub struct OidcResponse {
     #[serde(rename = "clientId")]
     pub client_id: String,
 }
+
+/// The `OidcResponse` along with the scopes Weaviate configures, used to build an `OidcConfig`.
+#[derive(Deserialize, Debug)]
+pub(crate) struct OidcScopedResponse {
+    #[serde(flatten)]
+    pub response: OidcResponse,
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// The subset of the OpenID provider metadata, served by the token issuer at the `href` of the
+/// `OidcResponse`, that is needed to retrieve a token.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub token_endpoint: String,
+    pub authorization_endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub scopes_supported: Option<Vec<String>>,
+}
+
+/// The OIDC configuration of a Weaviate instance, combining the client details configured in
+/// Weaviate with the metadata of the token issuer.
+///
+/// This shouldn't be something you create yourself, as it is returned by `Oidc::get_config`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub token_endpoint: String,
+    pub authorization_endpoint: String,
+    pub client_id: String,
+    pub scopes: Vec<String>,
+}
+
+impl OidcConfig {
+    /// Combine the Weaviate OIDC response with the metadata of the token issuer.
+    ///
+    /// The scopes configured in Weaviate are used, falling back to the scopes supported by the
+    /// issuer when Weaviate doesn't configure any.
+    pub(crate) fn new(response: OidcScopedResponse, metadata: OidcProviderMetadata) -> OidcConfig {
+        OidcConfig {
+            issuer: metadata.issuer,
+            token_endpoint: metadata.token_endpoint,
+            authorization_endpoint: metadata.authorization_endpoint,
+            client_id: response.response.client_id,
+            scopes: response
+                .scopes
+                .or(metadata.scopes_supported)
+                .unwrap_or_default(),
+        }
+    }
+}
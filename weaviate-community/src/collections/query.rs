@@ -13,11 +13,149 @@
 ///
 /// There are also some places I need to return an error from which I am yet to do.
 ///
-/// I've also not had a chance to test a lot of the functionality, so lots will be broken like the
-/// near_text or near_image as I have not implemented the `encoding` functionality yet.
-use serde::{Deserialize, Serialize};
+/// I've also not had a chance to test a lot of the functionality, so some of it may be broken.
+/// Media for the near<Media> filters can be base64 encoded using `NearMediaBuilder::from_bytes`.
+use crate::collections::error::{GraphQLError, QueryError, WeaviateError};
+use crate::collections::objects::GeoCoordinates;
+use crate::collections::schema::Class;
+use base64::Engine;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::error::Error;
 use uuid::Uuid;
 
+/// The default `QUERY_MAXIMUM_RESULTS` of Weaviate, the deepest `offset + limit` a paginated
+/// query can reach. Deeper pages should be collected with the `after` cursor instead.
+pub const QUERY_MAXIMUM_RESULTS: u64 = 10_000;
+
+/// Check that `offset + limit` doesn't page deeper than `query_maximum_results`.
+pub(crate) fn check_page_depth(
+    offset: Option<u64>,
+    limit: Option<u64>,
+    query_maximum_results: u64,
+) -> Result<(), String> {
+    let depth = offset.unwrap_or(0) + limit.unwrap_or(0);
+    if depth > query_maximum_results {
+        return Err(format!(
+            "offset + limit ({}) exceeds QUERY_MAXIMUM_RESULTS ({}); use the `after` cursor to \
+             page through deep results",
+            depth, query_maximum_results
+        ));
+    }
+    Ok(())
+}
+
+/// The response of a GraphQL request.
+///
+/// Weaviate will return a 200 status code for GraphQL queries that fail to execute, with the
+/// reason described in `errors`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GraphQLResponse {
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub errors: Option<Vec<GraphQLError>>,
+}
+
+impl GraphQLResponse {
+    /// Parse the results of a Get query for a class into a list of `SearchHit`.
+    ///
+    /// The `certainty`, `distance`, `id`, `answer`, `tokens`, `featureProjection`, and `rerank`
+    /// coordinates are taken from the `_additional` properties when requested in the query, and the
+    /// remaining properties are deserialized into `T`.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class that was queried
+    ///
+    /// # Example
+    /// ```
+    /// use serde::Deserialize;
+    /// use weaviate_community::collections::query::GraphQLResponse;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Question {
+    ///     question: String,
+    /// }
+    ///
+    /// let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+    ///     "data": {
+    ///         "Get": {
+    ///             "JeopardyQuestion": [
+    ///                 {"question": "test", "_additional": {"distance": 0.1}}
+    ///             ]
+    ///         }
+    ///     }
+    /// })).unwrap();
+    /// let hits = res.get_hits::<Question>("JeopardyQuestion").unwrap();
+    /// assert_eq!("test", hits[0].object.question);
+    /// assert_eq!(Some(0.1), hits[0].distance);
+    /// ```
+    pub fn get_hits<T: DeserializeOwned>(
+        &self,
+        class_name: &str,
+    ) -> Result<Vec<SearchHit<T>>, Box<dyn Error>> {
+        let items = match self
+            .data
+            .as_ref()
+            .and_then(|data| data["Get"][class_name].as_array())
+        {
+            Some(items) => items,
+            None => {
+                return Err(Box::new(GraphQLError::new(format!(
+                    "no Get results received for {}. Errors: {:?}",
+                    class_name, self.errors
+                ))))
+            }
+        };
+
+        let mut hits = Vec::with_capacity(items.len());
+        for item in items {
+            let mut item = item.clone();
+            let additional = item
+                .as_object_mut()
+                .and_then(|map| map.remove("_additional"))
+                .unwrap_or_default();
+            hits.push(SearchHit {
+                object: serde_json::from_value(item)?,
+                certainty: additional["certainty"].as_f64().map(|v| v as f32),
+                distance: additional["distance"].as_f64().map(|v| v as f32),
+                id: additional["id"]
+                    .as_str()
+                    .and_then(|id| Uuid::parse_str(id).ok()),
+                answer: serde_json::from_value(additional["answer"].clone()).ok(),
+                tokens: serde_json::from_value(additional["tokens"].clone()).ok(),
+                feature_projection: additional["featureProjection"]["vector"].as_array().map(
+                    |vector| {
+                        vector
+                            .iter()
+                            .filter_map(|v| v.as_f64().map(|v| v as f32))
+                            .collect()
+                    },
+                ),
+                rerank_score: additional["rerank"][0]["score"].as_f64().map(|v| v as f32),
+            });
+        }
+        Ok(hits)
+    }
+}
+
+/// A single result of a Get query, along with its `_additional` search metadata.
+#[derive(Debug)]
+pub struct SearchHit<T> {
+    pub object: T,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub id: Option<Uuid>,
+    /// The extracted answer, when `answer` was requested.
+    pub answer: Option<Answer>,
+    /// The extracted named entities, when `tokens` was requested.
+    pub tokens: Option<Vec<Token>>,
+    /// The projected coordinates, when `featureProjection` was requested.
+    pub feature_projection: Option<Vec<f32>>,
+    /// The score given by the reranker module, when `rerank` was requested.
+    pub rerank_score: Option<f32>,
+}
+
 /// RawQuery struct to hold a custom `raw` query.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RawQuery {
@@ -117,6 +255,9 @@ impl AggregateBuilder {
     /// Should only be set in conjunction when used in conjunction with a `near` filter (for
     /// example, `with_near_text()`
     ///
+    /// This caps the objects that are aggregated, not the number of groups returned. To cap the
+    /// groups, use `with_group_limit`.
+    ///
     /// # Example
     /// ```
     /// use weaviate_community::collections::query::AggregateBuilder;
@@ -158,6 +299,63 @@ impl AggregateBuilder {
         self
     }
 
+    /// Appends the typed aggregations of each property to the aggregate query body.
+    ///
+    /// Unlike `with_fields`, this adds to any fields that have already been set.
+    ///
+    /// # Errors
+    /// Returns a `QueryError` if a field has no aggregations selected, as Weaviate rejects an
+    /// empty selection such as `wordCount {  }`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AggregateBuilder, AggregateField};
+    ///
+    /// let query_builder = AggregateBuilder::new("Article")
+    ///     .with_aggregate_fields(vec![
+    ///         AggregateField::property("wordCount").count().mean().maximum(),
+    ///         AggregateField::property("title").top_occurrences(Some(3)),
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert!(AggregateBuilder::new("Article")
+    ///     .with_aggregate_fields(vec![AggregateField::property("wordCount")])
+    ///     .is_err());
+    /// ```
+    pub fn with_aggregate_fields(
+        mut self,
+        fields: Vec<AggregateField>,
+    ) -> Result<AggregateBuilder, QueryError> {
+        if let Some(field) = fields.iter().find(|field| field.aggregations.is_empty()) {
+            return Err(QueryError::new(format!(
+                "no aggregations selected for property `{}`",
+                field.property
+            )));
+        }
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .extend(fields.iter().map(|field| field.to_string()));
+        Ok(self)
+    }
+
+    /// Group the aggregation by the property at `path`, and add `groupedBy { value path }` to the
+    /// body so each group reports the value it was grouped by.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AggregateBuilder;
+    ///
+    /// let query_builder = AggregateBuilder::new("Article")
+    ///     .with_group_by(vec!["inPublication"])
+    ///     .with_meta_count();
+    /// ```
+    pub fn with_group_by(mut self, path: Vec<&str>) -> AggregateBuilder {
+        let mut group_by = String::new();
+        write_graphql_value(&serde_json::json!(path), &mut group_by);
+        self.group_by = Some(group_by);
+        self
+    }
+
     /// Set the `where` filter in the aggregate query.
     ///
     /// # Example -> todo
@@ -170,7 +368,8 @@ impl AggregateBuilder {
 
     /// Set the `group_by` filter in the aggregate query.
     ///
-    /// This may also require `groupedBy {...}` to be specified in with_fields().
+    /// As with `with_group_by`, `groupedBy { value path }` is added to the body, unless it is
+    /// already selected in `with_fields`.
     ///
     /// # Example
     /// ```
@@ -191,40 +390,59 @@ impl AggregateBuilder {
     /// Note that the `autocorrect` field is only available with the `text-spellcheck` Weaviate
     /// module.
     ///
+    /// Accepts either a typed `NearText` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::{AggregateBuilder, NearText};
+    ///
+    /// let query_builder = AggregateBuilder::new("Article")
+    ///     .with_near_text(NearText::builder(vec!["New Yorker"]).with_distance(0.3).build());
     /// ```
-    pub fn with_near_text(mut self, near_text: &str) -> AggregateBuilder {
+    pub fn with_near_text(mut self, near_text: impl std::fmt::Display) -> AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_text.into());
+        self.near = Some(format!("nearText: {}", near_text));
         self
     }
 
     /// Set the `nearVector` filter in the aggregate query.
     ///
+    /// Accepts either a typed `NearVector` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::{AggregateBuilder, NearVector};
+    ///
+    /// let query_builder = AggregateBuilder::new("Article")
+    ///     .with_near_vector(NearVector::builder(vec![0.1, 0.2]).with_certainty(0.7).build());
     /// ```
-    pub fn with_near_vector(mut self, near_vector: &str) -> AggregateBuilder {
+    pub fn with_near_vector(mut self, near_vector: impl std::fmt::Display) -> AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_vector.into());
+        self.near = Some(format!("nearVector: {}", near_vector));
         self
     }
 
     /// Set the `nearObject` filter in the aggregate query.
     ///
+    /// Accepts either a typed `NearObject` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::{AggregateBuilder, NearObject};
+    ///
+    /// let near_object = NearObject::builder().with_id(Uuid::new_v4()).build().unwrap();
+    /// let query_builder = AggregateBuilder::new("Article").with_near_object(near_object);
     /// ```
-    pub fn with_near_object(mut self, near_object: &str) -> AggregateBuilder {
+    pub fn with_near_object(mut self, near_object: impl std::fmt::Display) -> AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_object.into());
+        self.near = Some(format!("nearObject: {}", near_object));
         self
     }
 
@@ -237,7 +455,7 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_image.into());
+        self.near = Some(format!("nearImage: {}", near_image));
         self
     }
 
@@ -250,7 +468,7 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_audio.into());
+        self.near = Some(format!("nearAudio: {}", near_audio));
         self
     }
 
@@ -263,7 +481,7 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_video.into());
+        self.near = Some(format!("nearVideo: {}", near_video));
         self
     }
 
@@ -276,7 +494,7 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_depth.into());
+        self.near = Some(format!("nearDepth: {}", near_depth));
         self
     }
 
@@ -289,7 +507,7 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_thermal.into());
+        self.near = Some(format!("nearThermal: {}", near_thermal));
         self
     }
 
@@ -302,14 +520,40 @@ impl AggregateBuilder {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_imu.into());
+        self.near = Some(format!("nearIMU: {}", near_imu));
         self
     }
 
+    /// Set the `near<Media>` filter matching the media type of the `NearMedia`, for example
+    /// `nearImage` for `MediaType::IMAGE`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AggregateBuilder, MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::builder(MediaType::IMAGE, "iVBORw0KGgoAAAANS").build();
+    /// let query_builder = AggregateBuilder::new("Dog").with_near_media(near_image);
+    /// ```
+    pub fn with_near_media(self, near_media: NearMedia) -> AggregateBuilder {
+        let media_type = near_media.media_type;
+        let near_media = near_media.to_string();
+        match media_type {
+            MediaType::IMAGE => self.with_near_image(&near_media),
+            MediaType::AUDIO => self.with_near_audio(&near_media),
+            MediaType::VIDEO => self.with_near_video(&near_media),
+            MediaType::DEPTH => self.with_near_depth(&near_media),
+            MediaType::THERMAL => self.with_near_thermal(&near_media),
+            MediaType::IMU => self.with_near_imu(&near_media),
+        }
+    }
+
     /// Set the `tenant` filter in the aggregate query.
     ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::AggregateBuilder;
+    ///
+    /// let query_builder = AggregateBuilder::new("Article").with_tenant("tenantA");
     /// ```
     pub fn with_tenant(mut self, tenant: &str) -> AggregateBuilder {
         self.tenant = Some(tenant.into());
@@ -318,7 +562,8 @@ impl AggregateBuilder {
 
     /// Set the `limit` filter in the aggregate query.
     ///
-    /// Limits the number of results that are returned.
+    /// Limits the number of results that are returned. In an Aggregate query each result is a
+    /// group, so this is the same as `with_group_limit`, which should be preferred for clarity.
     ///
     /// # Example
     /// ```
@@ -332,6 +577,30 @@ impl AggregateBuilder {
         self
     }
 
+    /// Set the maximum number of groups returned by a grouped aggregation.
+    ///
+    /// This is sent as the `limit` filter, and is distinct from `with_object_limit`:
+    /// - `objectLimit` caps how many objects from a `near` search are aggregated
+    /// - `limit` caps how many groups the aggregated objects are returned in
+    ///
+    /// Without `with_group_by`, the aggregation returns a single group and this has no effect.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AggregateBuilder, NearText};
+    ///
+    /// // aggregate the 100 closest articles, returning counts for the first 5 publications
+    /// let query_builder = AggregateBuilder::new("Article")
+    ///     .with_near_text(NearText::builder(vec!["fashion"]).build())
+    ///     .with_object_limit(100)
+    ///     .with_group_by(vec!["inPublication"])
+    ///     .with_group_limit(5)
+    ///     .with_meta_count();
+    /// ```
+    pub fn with_group_limit(self, limit: u32) -> AggregateBuilder {
+        self.with_limit(limit)
+    }
+
     /// Build the `AggregateQuery` to use within within a GraphQL Aggregate request.
     ///
     /// # Example
@@ -381,14 +650,14 @@ impl AggregateBuilder {
             if let Some(group_by) = &self.group_by {
                 query.push_str(format!("      groupBy: {}\n", group_by).as_str());
             }
-            if let Some(near) = &self.where_clause {
-                query.push_str(format!("      near: {}\n", near).as_str());
+            if let Some(near) = &self.near {
+                query.push_str(format!("      {}\n", near).as_str());
             }
             if let Some(object_limit) = &self.object_limit {
                 query.push_str(format!("      objectLimit: {}\n", object_limit).as_str());
             }
             if let Some(tenant) = &self.tenant {
-                query.push_str(format!("      tenant: {}\n", tenant).as_str());
+                query.push_str(format!("      tenant: {}\n", graphql_string(tenant)).as_str());
             }
             if let Some(limit) = &self.limit {
                 query.push_str(format!("      limit: {}\n", limit).as_str());
@@ -401,6 +670,9 @@ impl AggregateBuilder {
         if let Some(_) = &self.meta_count {
             query.push_str("      meta{count}\n");
         }
+        if self.group_by.is_some() && !self.selects_grouped_by() {
+            query.push_str("      groupedBy { value path }\n");
+        }
 
         if let Some(fields) = &self.fields {
             query.push_str(format!("      {}\n", fields.join(" ")).as_str());
@@ -411,6 +683,15 @@ impl AggregateBuilder {
         AggregateQuery { query }
     }
 
+    /// Check if `groupedBy` is already selected in the raw fields.
+    fn selects_grouped_by(&self) -> bool {
+        self.fields.as_ref().is_some_and(|fields| {
+            fields
+                .iter()
+                .any(|field| field.trim_start().starts_with("groupedBy"))
+        })
+    }
+
     /// Check if the query contains a filter.
     fn contains_filter(&self) -> bool {
         match self.where_clause.is_some()
@@ -426,6 +707,185 @@ impl AggregateBuilder {
     }
 }
 
+/// The aggregations to compute for a single property in an Aggregate query.
+///
+/// The aggregations available depend on the data type of the property:
+/// - `int` and `number`: `count`, `minimum`, `maximum`, `mean`, `median`, `mode`, `sum`
+/// - `text`: `count`, `top_occurrences`
+/// - `boolean`: `count`, `total_true`, `total_false`, `percentage_true`, `percentage_false`
+/// - `date`: `count`, `minimum`, `maximum`, `median`, `mode`
+///
+/// # Example
+/// ```
+/// use weaviate_community::collections::query::AggregateField;
+///
+/// let field = AggregateField::property("wordCount").count().mean().maximum();
+/// assert_eq!("wordCount { count mean maximum }", field.to_string());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AggregateField {
+    pub property: String,
+    pub aggregations: Vec<String>,
+}
+
+impl AggregateField {
+    /// Start selecting the aggregations of a property.
+    ///
+    /// # Parameters
+    /// - property: the name of the property to aggregate
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AggregateField;
+    ///
+    /// let field = AggregateField::property("wordCount").sum();
+    /// ```
+    pub fn property(property: &str) -> AggregateField {
+        AggregateField {
+            property: property.into(),
+            aggregations: Vec::new(),
+        }
+    }
+
+    /// Add an aggregation to the selection.
+    fn with(mut self, aggregation: &str) -> AggregateField {
+        self.aggregations.push(aggregation.into());
+        self
+    }
+
+    /// Count the objects with a value for the property.
+    pub fn count(self) -> AggregateField {
+        self.with("count")
+    }
+
+    /// The smallest value of a numeric or date property.
+    pub fn minimum(self) -> AggregateField {
+        self.with("minimum")
+    }
+
+    /// The largest value of a numeric or date property.
+    pub fn maximum(self) -> AggregateField {
+        self.with("maximum")
+    }
+
+    /// The mean of a numeric property.
+    pub fn mean(self) -> AggregateField {
+        self.with("mean")
+    }
+
+    /// The median of a numeric or date property.
+    pub fn median(self) -> AggregateField {
+        self.with("median")
+    }
+
+    /// The most common value of a numeric or date property.
+    pub fn mode(self) -> AggregateField {
+        self.with("mode")
+    }
+
+    /// The sum of a numeric property.
+    pub fn sum(self) -> AggregateField {
+        self.with("sum")
+    }
+
+    /// The data type of the property.
+    pub fn data_type(self) -> AggregateField {
+        self.with("type")
+    }
+
+    /// The most common values of a text property, along with how often they occur.
+    ///
+    /// # Parameters
+    /// - limit: the maximum number of values to return, or `None` for the Weaviate default
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AggregateField;
+    ///
+    /// let field = AggregateField::property("title").top_occurrences(Some(3));
+    /// assert_eq!("title { topOccurrences(limit: 3) { value occurs } }", field.to_string());
+    /// ```
+    pub fn top_occurrences(self, limit: Option<u32>) -> AggregateField {
+        match limit {
+            Some(limit) => self.with(&format!(
+                "topOccurrences(limit: {}) {{ value occurs }}",
+                limit
+            )),
+            None => self.with("topOccurrences { value occurs }"),
+        }
+    }
+
+    /// The number of objects where a boolean property is `true`.
+    pub fn total_true(self) -> AggregateField {
+        self.with("totalTrue")
+    }
+
+    /// The number of objects where a boolean property is `false`.
+    pub fn total_false(self) -> AggregateField {
+        self.with("totalFalse")
+    }
+
+    /// The share of objects where a boolean property is `true`.
+    pub fn percentage_true(self) -> AggregateField {
+        self.with("percentageTrue")
+    }
+
+    /// The share of objects where a boolean property is `false`.
+    pub fn percentage_false(self) -> AggregateField {
+        self.with("percentageFalse")
+    }
+}
+
+impl std::fmt::Display for AggregateField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {{ {} }}", self.property, self.aggregations.join(" "))
+    }
+}
+
+/// The result of an Aggregate query, taken from `data.Aggregate.{class}` in the GraphQL response.
+///
+/// When the query has no `groupBy` filter there will be a single group.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AggregateResponse {
+    pub groups: Vec<serde_json::Value>,
+}
+
+impl AggregateResponse {
+    /// Get the `meta { count }` value of the first group, if it was requested.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AggregateResponse;
+    ///
+    /// let res = AggregateResponse {
+    ///     groups: vec![serde_json::json!({"meta": {"count": 10}})]
+    /// };
+    /// assert_eq!(Some(10), res.meta_count());
+    /// ```
+    pub fn meta_count(&self) -> Option<u64> {
+        self.groups.first()?["meta"]["count"].as_u64()
+    }
+
+    /// Extract the Aggregate results for the first class in a GraphQL response.
+    pub(crate) fn from_graphql(res: &GraphQLResponse) -> Result<Self, Box<dyn Error>> {
+        let groups = res
+            .data
+            .as_ref()
+            .and_then(|data| data["Aggregate"].as_object())
+            .and_then(|classes| classes.values().next())
+            .and_then(|groups| groups.as_array());
+        match groups {
+            Some(groups) => Ok(AggregateResponse {
+                groups: groups.clone(),
+            }),
+            None => Err(Box::new(GraphQLError::new(format!(
+                "no Aggregate results received. Errors: {:?}",
+                res.errors
+            )))),
+        }
+    }
+}
+
 /// ExploreQuery struct to hold an Explore query.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ExploreQuery {
@@ -511,11 +971,17 @@ impl ExploreBuilder {
     /// One of either `with_near_text` or `with_near_vector` must be set in the query at point of
     /// build.
     ///
+    /// Accepts either a typed `NearText` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::ExploreBuilder;
+    ///
+    /// let query_builder = ExploreBuilder::new()
+    ///     .with_near_text("{concepts: [\"New Yorker\"]}");
     /// ```
-    pub fn with_near_text(mut self, near_text: &str) -> ExploreBuilder {
-        self.near_text = Some(near_text.into());
+    pub fn with_near_text(mut self, near_text: impl std::fmt::Display) -> ExploreBuilder {
+        self.near_text = Some(near_text.to_string());
         self
     }
 
@@ -524,35 +990,68 @@ impl ExploreBuilder {
     /// One of either `with_near_text` or `with_near_vector` must be set in the query at point of
     /// build.
     ///
+    /// Accepts either a typed `NearVector` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::ExploreBuilder;
+    ///
+    /// let query_builder = ExploreBuilder::new()
+    ///     .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}");
     /// ```
-    pub fn with_near_vector(mut self, near_vector: &str) -> ExploreBuilder {
-        self.near_vector = Some(near_vector.into());
+    pub fn with_near_vector(mut self, near_vector: impl std::fmt::Display) -> ExploreBuilder {
+        self.near_vector = Some(near_vector.to_string());
         self
     }
 
     /// Build the `ExploreQuery` to use within within a GraphQL Explore request.
     ///
-    /// # Examples -> todo: need to add a nearVector or nearText
-    /// ```no_run
+    /// Returns a `WeaviateError::InvalidRequest` if neither `with_near_text` or
+    /// `with_near_vector` have been set, as Explore requires one near filter.
+    ///
+    /// # Examples
+    /// ```
     /// use weaviate_community::collections::query::ExploreBuilder;
     ///
-    /// let query = ExploreBuilder::new().build();
+    /// let query = ExploreBuilder::new()
+    ///     .with_limit(1)
+    ///     .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
+    ///     .with_fields(vec!["className"])
+    ///     .build()
+    ///     .unwrap();
     /// ```
     ///
-    /// ```no_run
+    /// ```
     /// use weaviate_community::collections::query::ExploreQuery;
     ///
-    /// let query = ExploreQuery::builder().build();
+    /// let query = ExploreQuery::builder()
+    ///     .with_limit(1)
+    ///     .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
+    ///     .with_fields(vec!["className"])
+    ///     .build()
+    ///     .unwrap();
     /// ```
     ///
     /// Both examples will create the following ExploreQuery:
     /// ```text
+    /// ExploreQuery {
+    ///   query: "{
+    ///     Explore
+    ///     (
+    ///       limit: 1
+    ///       nearVector: {vector: [-0.36840257,0.13973749,-0.28994447]}
+    ///     )
+    ///     {
+    ///       className
+    ///     }
+    ///   }"
+    /// }
     /// ```
-    pub fn build(&self) -> ExploreQuery {
+    pub fn build(&self) -> Result<ExploreQuery, WeaviateError> {
         if self.near_text.is_none() && self.near_vector.is_none() {
-            // raise an error, one is required. TBD if other near fields can be used
+            return Err(WeaviateError::InvalidRequest(
+                "Explore requires one near filter, either `near_text` or `near_vector`".into(),
+            ));
         }
 
         // Path
@@ -580,7 +1079,7 @@ impl ExploreBuilder {
         query.push_str("  }\n");
         query.push_str("}");
 
-        ExploreQuery { query }
+        Ok(ExploreQuery { query })
     }
 }
 
@@ -632,6 +1131,9 @@ pub struct GetBuilder {
     pub tenant: Option<String>,
     pub autocut: Option<u32>,
     pub ask: Option<String>,
+    pub target_vectors: Option<Vec<String>>,
+    pub rerank: Option<String>,
+    pub query_maximum_results: Option<u64>,
 }
 
 impl GetBuilder {
@@ -673,6 +1175,9 @@ impl GetBuilder {
             bm25: None,
             ask: None,
             group_by: None,
+            target_vectors: None,
+            rerank: None,
+            query_maximum_results: None,
         }
     }
 
@@ -710,6 +1215,27 @@ impl GetBuilder {
         self
     }
 
+    /// Set the `QUERY_MAXIMUM_RESULTS` configured on the Weaviate instance.
+    ///
+    /// Building a query where `offset + limit` exceeds this returns an error, as Weaviate would
+    /// reject it. Defaults to `QUERY_MAXIMUM_RESULTS`, the Weaviate default. Builders created
+    /// with `Query::get_builder`, or run through `WeaviateClient::for_tenant`, default to the
+    /// maximum set with `WeaviateClientBuilder::with_query_maximum_results` instead.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::GetBuilder;
+    ///
+    /// let query_builder = GetBuilder::new("JeopardyQuestion", vec!["question"])
+    ///     .with_query_maximum_results(20_000)
+    ///     .with_offset(15_000)
+    ///     .with_limit(100);
+    /// ```
+    pub fn with_query_maximum_results(mut self, query_maximum_results: u64) -> GetBuilder {
+        self.query_maximum_results = Some(query_maximum_results);
+        self
+    }
+
     /// Specify the `_additional` properties to retrieve in the query result.
     ///
     /// Note that the additional properties are properties that cannot be specified in the regular
@@ -732,6 +1258,25 @@ impl GetBuilder {
         self
     }
 
+    /// Specify the `_additional` properties to retrieve in the query result using the
+    /// `Additional` builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{Additional, GetBuilder, ProjectionAlgorithm};
+    ///
+    /// let additional = Additional::builder()
+    ///     .with_id()
+    ///     .with_feature_projection(2, ProjectionAlgorithm::TSNE)
+    ///     .build();
+    /// let query_builder = GetBuilder::new("JeopardyQuestion", vec!["answer"])
+    ///     .with_additional_properties(additional);
+    /// ```
+    pub fn with_additional_properties(mut self, additional: Additional) -> GetBuilder {
+        self.additional = Some(additional.fields);
+        self
+    }
+
     /// Specify the `tenant` in the get query filter.
     ///
     /// For classes that have multi-tenancy enabled, the tenant parameter must be specified in each
@@ -776,8 +1321,18 @@ impl GetBuilder {
     ///
     /// More information on `after` can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-operators#cursor-with-after)
     ///
+    /// The id is emitted as a quoted string, such as
+    /// `after: "ee22d1b8-3b95-4e94-96d5-9a2b60fbd303"`.
+    ///
     /// # Example
     /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::GetBuilder;
+    ///
+    /// let after = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    /// let query_builder = GetBuilder::new("Article", vec!["title"])
+    ///     .with_after(after)
+    ///     .with_limit(100);
     /// ```
     pub fn with_after(mut self, after: Uuid) -> GetBuilder {
         self.after = Some(after);
@@ -792,6 +1347,17 @@ impl GetBuilder {
     /// sort operator will override that order.
     ///
     /// More on sorting in Weaviate can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-operators#sorting)
+    ///
+    /// The sort is emitted as given, so any strings within it, such as the `order`, must already
+    /// be valid GraphQL.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::GetBuilder;
+    ///
+    /// let query_builder = GetBuilder::new("JeopardyQuestion", vec!["question", "points"])
+    ///     .with_sort("{path: [\"points\"], order: desc}");
+    /// ```
     pub fn with_sort(mut self, sort: &str) -> GetBuilder {
         self.sort = Some(sort.into());
         self
@@ -799,10 +1365,25 @@ impl GetBuilder {
 
     /// Specify conditionals to add to the `where` search filter in the get query.
     ///
+    /// The `where` filter narrows the results of any one search operator: a `near<Media>`
+    /// filter, `bm25`, `hybrid`, or `ask`. The search operators can't be combined with each
+    /// other, and `where` can't be combined with `after`; `build` returns a
+    /// `WeaviateError::InvalidRequest` for either.
+    ///
     /// More information on conditionals can be found [here](https://weaviate.io/developers/weaviate/api/graphql/filters)
     ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::{FilterOperator, GetBuilder, WhereFilter};
+    ///
+    /// let filter = WhereFilter::builder(vec!["points"], FilterOperator::GREATERTHAN)
+    ///     .with_value_int(200)
+    ///     .build();
+    /// let query = GetBuilder::new("JeopardyQuestion", vec!["question", "answer"])
+    ///     .with_hybrid("{query: \"food\"}")
+    ///     .with_where(&filter.to_string())
+    ///     .build()
+    ///     .unwrap();
     /// ```
     pub fn with_where(mut self, where_clause: &str) -> GetBuilder {
         self.where_clause = Some(where_clause.into());
@@ -811,31 +1392,69 @@ impl GetBuilder {
 
     /// Set the `nearText` filter in the get query.
     ///
+    /// Accepts either a typed `NearText` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::{GetBuilder, NearText};
+    ///
+    /// let query_builder = GetBuilder::new("Article", vec!["title"])
+    ///     .with_near_text(NearText::builder(vec!["New Yorker"]).with_distance(0.3).build());
     /// ```
-    pub fn with_near_text(mut self, near_text: &str) -> GetBuilder {
-        self.near_text = Some(near_text.into());
+    pub fn with_near_text(mut self, near_text: impl std::fmt::Display) -> GetBuilder {
+        self.near_text = Some(near_text.to_string());
         self
     }
 
     /// Set the `nearVector` filter in the get query.
     ///
+    /// Accepts either a typed `NearVector` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::{GetBuilder, NearVector};
+    ///
+    /// let query_builder = GetBuilder::new("Article", vec!["title"])
+    ///     .with_near_vector(NearVector::builder(vec![0.1, 0.2]).with_certainty(0.7).build());
+    /// ```
+    pub fn with_near_vector(mut self, near_vector: impl std::fmt::Display) -> GetBuilder {
+        self.near_vector = Some(near_vector.to_string());
+        self
+    }
+
+    /// Set the named vectors to search against, for classes configured with multiple named
+    /// vectors.
+    ///
+    /// The `targetVectors` argument is added to the `near<Media>` filter set in the query, so a
+    /// `near<Media>` filter must also be set, and it must not set its own `targetVectors`.
+    ///
+    /// # Example
     /// ```
-    pub fn with_near_vector(mut self, near_vector: &str) -> GetBuilder {
-        self.near_vector = Some(near_vector.into());
+    /// use weaviate_community::collections::query::{GetBuilder, NearText};
+    ///
+    /// let query_builder = GetBuilder::new("Article", vec!["title"])
+    ///     .with_near_text(NearText::builder(vec!["New Yorker"]).build())
+    ///     .with_target_vectors(vec!["title"]);
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> GetBuilder {
+        self.target_vectors = Some(target_vectors.iter().map(|t| t.to_string()).collect());
         self
     }
 
     /// Set the `nearObject` filter in the get query.
     ///
+    /// Accepts either a typed `NearObject` or the raw GraphQL argument.
+    ///
     /// # Example
     /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::{GetBuilder, NearObject};
+    ///
+    /// let near_object = NearObject::builder().with_id(Uuid::new_v4()).build().unwrap();
+    /// let query_builder = GetBuilder::new("Article", vec!["title"]).with_near_object(near_object);
     /// ```
-    pub fn with_near_object(mut self, near_object: &str) -> GetBuilder {
-        self.near_object = Some(near_object.into());
+    pub fn with_near_object(mut self, near_object: impl std::fmt::Display) -> GetBuilder {
+        self.near_object = Some(near_object.to_string());
         self
     }
 
@@ -899,11 +1518,37 @@ impl GetBuilder {
         self
     }
 
+    /// Set the `near<Media>` filter matching the media type of the `NearMedia`, for example
+    /// `nearImage` for `MediaType::IMAGE`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{GetBuilder, MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::builder(MediaType::IMAGE, "iVBORw0KGgoAAAANS").build();
+    /// let query_builder = GetBuilder::new("Dog", vec!["breed"]).with_near_media(near_image);
+    /// ```
+    pub fn with_near_media(self, near_media: NearMedia) -> GetBuilder {
+        let media_type = near_media.media_type;
+        let near_media = near_media.to_string();
+        match media_type {
+            MediaType::IMAGE => self.with_near_image(&near_media),
+            MediaType::AUDIO => self.with_near_audio(&near_media),
+            MediaType::VIDEO => self.with_near_video(&near_media),
+            MediaType::DEPTH => self.with_near_depth(&near_media),
+            MediaType::THERMAL => self.with_near_thermal(&near_media),
+            MediaType::IMU => self.with_near_imu(&near_media),
+        }
+    }
+
     /// Specify the `hybrid` search filter in the get query.
     ///
     /// The `hybrid` operator produces results based on a weighted combination of results from a
     /// keyword (bm25) search and a vector (near<media>) search.
     ///
+    /// It can be combined with a `where` filter, but not with `bm25`, `ask`, or a `near<Media>`
+    /// filter.
+    ///
     /// # Example
     /// ```
     /// use weaviate_community::collections::query::GetBuilder;
@@ -941,6 +1586,9 @@ impl GetBuilder {
     ///
     /// To use BM25 search, you must provide a search string as a minimum.
     ///
+    /// It can be combined with a `where` filter, but not with `hybrid`, `ask`, or a `near<Media>`
+    /// filter.
+    ///
     /// More on Keyword (BM25) search can be found [here](https://weaviate.io/developers/weaviate/search/bm25)
     ///
     /// # Example
@@ -986,20 +1634,64 @@ impl GetBuilder {
     ///
     /// # Example
     /// ```
+    /// use weaviate_community::collections::query::GetBuilder;
+    ///
+    /// let query_builder = GetBuilder::new("JeopardyQuestion", vec!["question"])
+    ///     .with_group_by("{path: [\"round\"], groups: 2, objectsPerGroup: 3}");
     /// ```
     pub fn with_group_by(mut self, group_by: &str) -> GetBuilder {
         self.group_by = Some(group_by.into());
         self
     }
 
+    /// Specify the `ask` operator of the qna-transformers module in the get query filters.
+    ///
+    /// The answer can be retrieved by requesting the `answer` additional property, for example
+    /// with `AdditionalBuilder::with_answer`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{Additional, Ask, GetBuilder};
+    ///
+    /// let ask = Ask::builder("Who is the king of the Netherlands?")
+    ///     .with_properties(vec!["summary"])
+    ///     .build();
+    /// let query_builder = GetBuilder::new("Article", vec!["title"])
+    ///     .with_ask(ask)
+    ///     .with_additional_properties(Additional::builder().with_answer().build());
+    /// ```
+    pub fn with_ask(mut self, ask: Ask) -> GetBuilder {
+        self.ask = Some(ask.to_string());
+        self
+    }
+
+    /// Rerank the results of the query with a reranker module, retrieving the `rerank` score
+    /// alongside any other `_additional` properties.
+    ///
+    /// # Parameters
+    /// - rerank: the property and query to rerank the results by
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{GetBuilder, Rerank};
     ///
-    pub fn with_ask(mut self, ask: &str) -> GetBuilder {
-        self.ask = Some(ask.into());
+    /// let query_builder = GetBuilder::new("JeopardyQuestion", vec!["question", "answer"])
+    ///     .with_near_text("{concepts: [\"flying\"]}")
+    ///     .with_rerank(Rerank::new("answer", "floating"));
+    /// ```
+    pub fn with_rerank(mut self, rerank: Rerank) -> GetBuilder {
+        self.rerank = Some(rerank.to_string());
         self
     }
 
     /// Build the `GetQuery` to use within within a GraphQL Get request.
     ///
+    /// Returns a `WeaviateError::InvalidRequest` for combinations Weaviate rejects:
+    /// - more than one `near<Media>` filter
+    /// - `autocut` without a `near<Media>`, `bm25`, or `hybrid` operator
+    /// - `after` together with `where`, a `near<Media>` filter, `bm25`, `hybrid`, `sort`, or
+    ///   `offset`
+    ///
     /// # Example
     /// ```
     /// use weaviate_community::collections::query::GetBuilder;
@@ -1007,7 +1699,7 @@ impl GetBuilder {
     /// let query = GetBuilder::new(
     ///     "JeopardyQuestion",
     ///     vec!["question", "answer", "points"]
-    /// ).build();
+    /// ).build().unwrap();
     /// ```
     ///
     /// ```
@@ -1016,7 +1708,7 @@ impl GetBuilder {
     /// let query = GetQuery::builder(
     ///     "JeopardyQuestion",
     ///     vec!["question", "answer", "points"]
-    /// ).build();
+    /// ).build().unwrap();
     /// ```
     ///
     /// Both examples will create the following GetQuery:
@@ -1034,7 +1726,9 @@ impl GetBuilder {
     ///   }"
     /// }
     /// ```
-    pub fn build(&self) -> GetQuery {
+    pub fn build(&self) -> Result<GetQuery, WeaviateError> {
+        self.validate()?;
+
         // Path
         let mut query = String::from("{\n");
         query.push_str("  Get {\n");
@@ -1053,31 +1747,49 @@ impl GetBuilder {
                 query.push_str(format!("      offset: {}\n", offset).as_str());
             }
             if let Some(near_text) = &self.near_text {
-                query.push_str(format!("      nearText: {}\n", near_text).as_str());
+                query.push_str(
+                    format!("      nearText: {}\n", self.near_argument(near_text)).as_str(),
+                );
             }
             if let Some(near_vector) = &self.near_vector {
-                query.push_str(format!("      nearVector: {}\n", near_vector).as_str());
+                query.push_str(
+                    format!("      nearVector: {}\n", self.near_argument(near_vector)).as_str(),
+                );
             }
             if let Some(near_object) = &self.near_object {
-                query.push_str(format!("      nearObject: {}\n", near_object).as_str());
+                query.push_str(
+                    format!("      nearObject: {}\n", self.near_argument(near_object)).as_str(),
+                );
             }
             if let Some(near_image) = &self.near_image {
-                query.push_str(format!("      nearImage: {}\n", near_image).as_str());
+                query.push_str(
+                    format!("      nearImage: {}\n", self.near_argument(near_image)).as_str(),
+                );
             }
             if let Some(near_audio) = &self.near_audio {
-                query.push_str(format!("      nearAudio: {}\n", near_audio).as_str());
+                query.push_str(
+                    format!("      nearAudio: {}\n", self.near_argument(near_audio)).as_str(),
+                );
             }
             if let Some(near_video) = &self.near_video {
-                query.push_str(format!("      nearVideo: {}\n", near_video).as_str());
+                query.push_str(
+                    format!("      nearVideo: {}\n", self.near_argument(near_video)).as_str(),
+                );
             }
             if let Some(near_thermal) = &self.near_thermal {
-                query.push_str(format!("      nearThermal: {}\n", near_thermal).as_str());
+                query.push_str(
+                    format!("      nearThermal: {}\n", self.near_argument(near_thermal)).as_str(),
+                );
             }
             if let Some(near_imu) = &self.near_imu {
-                query.push_str(format!("      nearIMU: {}\n", near_imu).as_str());
+                query.push_str(
+                    format!("      nearIMU: {}\n", self.near_argument(near_imu)).as_str(),
+                );
             }
             if let Some(near_depth) = &self.near_depth {
-                query.push_str(format!("      nearDepth: {}\n", near_depth).as_str());
+                query.push_str(
+                    format!("      nearDepth: {}\n", self.near_argument(near_depth)).as_str(),
+                );
             }
             if let Some(bm25) = &self.bm25 {
                 query.push_str(format!("      bm25: {}\n", bm25).as_str());
@@ -1086,13 +1798,14 @@ impl GetBuilder {
                 query.push_str(format!("      hybrid: {}\n", hybrid).as_str());
             }
             if let Some(group_by) = &self.group_by {
-                query.push_str(format!("      group_by: {}\n", group_by).as_str());
+                query.push_str(format!("      groupBy: {}\n", group_by).as_str());
             }
             if let Some(after) = &self.after {
+                let after = graphql_string(&after.to_string());
                 query.push_str(format!("      after: {}\n", after).as_str());
             }
             if let Some(tenant) = &self.tenant {
-                query.push_str(format!("      tenant: {}\n", tenant).as_str());
+                query.push_str(format!("      tenant: {}\n", graphql_string(tenant)).as_str());
             }
             if let Some(autocut) = &self.autocut {
                 query.push_str(format!("      autocut: {}\n", autocut).as_str());
@@ -1111,7 +1824,11 @@ impl GetBuilder {
         query.push_str("    {\n");
         query.push_str(format!("      {}\n", self.properties.join(" ")).as_str());
 
-        if let Some(additional) = &self.additional {
+        let mut additional = self.additional.clone().unwrap_or_default();
+        if let Some(rerank) = &self.rerank {
+            additional.push(rerank.clone());
+        }
+        if !additional.is_empty() {
             query.push_str("      _additional {\n");
             query.push_str(format!("        {}\n", additional.join(" ")).as_str());
             query.push_str("      }\n");
@@ -1119,13 +1836,159 @@ impl GetBuilder {
         query.push_str("    }\n");
         query.push_str("  }\n");
         query.push_str("}");
-        GetQuery { query }
+        Ok(GetQuery { query })
     }
 
-    /// Check if the query contains a filter.
-    fn contains_filter(&self) -> bool {
-        match self.limit.is_some()
-            || self.offset.is_some()
+    /// Check the filters set on the builder can be used together.
+    fn validate(&self) -> Result<(), WeaviateError> {
+        check_page_depth(
+            self.offset.map(u64::from),
+            self.limit.map(u64::from),
+            self.query_maximum_results.unwrap_or(QUERY_MAXIMUM_RESULTS),
+        )
+        .map_err(WeaviateError::InvalidRequest)?;
+        let near = self.near_filters();
+        if near.len() > 1 {
+            return Err(WeaviateError::InvalidRequest(format!(
+                "only one near<Media> filter can be used, found: {}",
+                near.join(", ")
+            )));
+        }
+        if self.target_vectors.is_some() && !self.contains_near() {
+            return Err(WeaviateError::InvalidRequest(
+                "`target_vectors` requires a `near<Media>` filter".into(),
+            ));
+        }
+        if self.target_vectors.is_some() {
+            let duplicated = self
+                .near_filter_values()
+                .into_iter()
+                .find(|(_, filter)| filter.contains("targetVectors"));
+            if let Some((name, _)) = duplicated {
+                return Err(WeaviateError::InvalidRequest(format!(
+                    "`target_vectors` is set on both the query and the {} filter",
+                    name
+                )));
+            }
+        }
+        if self.autocut.is_some()
+            && !self.contains_near()
+            && self.bm25.is_none()
+            && self.hybrid.is_none()
+        {
+            return Err(WeaviateError::InvalidRequest(
+                "`autocut` requires a `near<Media>`, `bm25`, or `hybrid` operator".into(),
+            ));
+        }
+        if self.after.is_some() {
+            let mut conflicts = Vec::new();
+            if self.where_clause.is_some() {
+                conflicts.push("where");
+            }
+            if self.contains_near() {
+                conflicts.push("near<Media>");
+            }
+            if self.bm25.is_some() {
+                conflicts.push("bm25");
+            }
+            if self.hybrid.is_some() {
+                conflicts.push("hybrid");
+            }
+            if self.sort.is_some() {
+                conflicts.push("sort");
+            }
+            if self.offset.is_some() {
+                conflicts.push("offset");
+            }
+            if !conflicts.is_empty() {
+                return Err(WeaviateError::InvalidRequest(format!(
+                    "`after` can't be combined with: {}",
+                    conflicts.join(", ")
+                )));
+            }
+        }
+        let operators = self.search_operators();
+        if operators.len() > 1 {
+            return Err(WeaviateError::InvalidRequest(format!(
+                "only one search operator can be used, found: {}",
+                operators.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Add the `targetVectors` to a `near<Media>` filter argument, if they have been set.
+    fn near_argument(&self, filter: &str) -> String {
+        let target_vectors = match &self.target_vectors {
+            Some(target_vectors) => target_vectors,
+            None => return filter.to_string(),
+        };
+        let mut targets = String::new();
+        write_graphql_value(&serde_json::json!(target_vectors), &mut targets);
+        let filter = filter.trim();
+        let inner = filter
+            .strip_prefix('{')
+            .and_then(|f| f.strip_suffix('}'))
+            .unwrap_or(filter)
+            .trim();
+        if inner.is_empty() {
+            format!("{{targetVectors: {}}}", targets)
+        } else {
+            format!("{{{}, targetVectors: {}}}", inner, targets)
+        }
+    }
+
+    /// The names and values of the `near<Media>` filters set in the query.
+    fn near_filter_values(&self) -> Vec<(&str, &str)> {
+        [
+            ("nearText", &self.near_text),
+            ("nearVector", &self.near_vector),
+            ("nearObject", &self.near_object),
+            ("nearImage", &self.near_image),
+            ("nearVideo", &self.near_video),
+            ("nearAudio", &self.near_audio),
+            ("nearThermal", &self.near_thermal),
+            ("nearIMU", &self.near_imu),
+            ("nearDepth", &self.near_depth),
+        ]
+        .into_iter()
+        .filter_map(|(name, filter)| filter.as_deref().map(|filter| (name, filter)))
+        .collect()
+    }
+
+    /// The names of the `near<Media>` filters set in the query.
+    fn near_filters(&self) -> Vec<&str> {
+        self.near_filter_values()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Get the names of the search operators set on the query. Each of these ranks the results
+    /// differently, so Weaviate only accepts one per query.
+    fn search_operators(&self) -> Vec<&str> {
+        let mut operators = self.near_filters();
+        if self.bm25.is_some() {
+            operators.push("bm25");
+        }
+        if self.hybrid.is_some() {
+            operators.push("hybrid");
+        }
+        if self.ask.is_some() {
+            operators.push("ask");
+        }
+        operators
+    }
+
+    /// Check if the query contains a `near<Media>` filter.
+    fn contains_near(&self) -> bool {
+        !self.near_filters().is_empty()
+    }
+
+    /// Check if the query contains a filter.
+    fn contains_filter(&self) -> bool {
+        match self.limit.is_some()
+            || self.offset.is_some()
             || self.after.is_some()
             || self.autocut.is_some()
             || self.tenant.is_some()
@@ -1134,10 +1997,16 @@ impl GetBuilder {
             || self.near_vector.is_some()
             || self.near_image.is_some()
             || self.near_object.is_some()
+            || self.near_video.is_some()
+            || self.near_audio.is_some()
+            || self.near_thermal.is_some()
+            || self.near_imu.is_some()
+            || self.near_depth.is_some()
             || self.hybrid.is_some()
             || self.bm25.is_some()
             || self.sort.is_some()
             || self.ask.is_some()
+            || self.group_by.is_some()
         {
             true => true,
             false => false,
@@ -1145,9 +2014,2760 @@ impl GetBuilder {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    //use super::GetBuilder;
+/// The `_additional` properties to retrieve in a Get query.
+///
+/// More information on the additional properties can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-properties)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Additional {
+    pub fields: Vec<String>,
+}
+
+impl Additional {
+    /// Create a new builder for the `_additional` properties.
+    ///
+    /// This is the same as `AdditionalBuilder::new()`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::Additional;
+    ///
+    /// let additional = Additional::builder().with_id().with_distance().build();
+    /// ```
+    pub fn builder() -> AdditionalBuilder {
+        AdditionalBuilder::new()
+    }
+}
+
+/// The builder for the `Additional` properties.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct AdditionalBuilder {
+    pub fields: Vec<String>,
+}
+
+impl AdditionalBuilder {
+    /// Create a new builder for the `_additional` properties.
+    ///
+    /// This is the same as `Additional::builder()`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_id().build();
+    /// ```
+    pub fn new() -> AdditionalBuilder {
+        AdditionalBuilder { fields: Vec::new() }
+    }
+
+    /// Retrieve the `id` of the object.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_id().build();
+    /// ```
+    pub fn with_id(mut self) -> AdditionalBuilder {
+        self.fields.push("id".into());
+        self
+    }
+
+    /// Retrieve the `vector` of the object.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_vector().build();
+    /// ```
+    pub fn with_vector(mut self) -> AdditionalBuilder {
+        self.fields.push("vector".into());
+        self
+    }
+
+    /// Retrieve the `certainty` of the object for a vector search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_certainty().build();
+    /// ```
+    pub fn with_certainty(mut self) -> AdditionalBuilder {
+        self.fields.push("certainty".into());
+        self
+    }
+
+    /// Retrieve the `distance` of the object for a vector search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_distance().build();
+    /// ```
+    pub fn with_distance(mut self) -> AdditionalBuilder {
+        self.fields.push("distance".into());
+        self
+    }
+
+    /// Retrieve the `answer` extracted by the qna-transformers module, when using the `ask`
+    /// operator.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_answer().build();
+    /// ```
+    pub fn with_answer(mut self) -> AdditionalBuilder {
+        self.fields.push(
+            "answer { hasAnswer property result certainty startPosition endPosition }".into(),
+        );
+        self
+    }
+
+    /// Retrieve the named entity `tokens` extracted by the ner-transformers module.
+    ///
+    /// # Parameters
+    /// - tokens: the tokens to extract
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AdditionalBuilder, Tokens};
+    ///
+    /// let additional = AdditionalBuilder::new()
+    ///     .with_tokens(Tokens::builder(vec!["summary"]).with_certainty(0.8).build())
+    ///     .build();
+    /// ```
+    pub fn with_tokens(mut self, tokens: Tokens) -> AdditionalBuilder {
+        self.fields.push(tokens.to_string());
+        self
+    }
+
+    /// Retrieve the `featureProjection` of the object, reducing its vector to a lower number of
+    /// dimensions for visualisation.
+    ///
+    /// More information on feature projection can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-properties#feature-projection)
+    ///
+    /// # Parameters
+    /// - dimensions: the number of dimensions to project to
+    /// - algorithm: the algorithm to use for the projection
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AdditionalBuilder, ProjectionAlgorithm};
+    ///
+    /// let additional = AdditionalBuilder::new()
+    ///     .with_feature_projection(2, ProjectionAlgorithm::TSNE)
+    ///     .build();
+    /// ```
+    pub fn with_feature_projection(
+        mut self,
+        dimensions: u32,
+        algorithm: ProjectionAlgorithm,
+    ) -> AdditionalBuilder {
+        self.fields.push(format!(
+            "featureProjection(dimensions: {}, algorithm: \"{}\") {{ vector }}",
+            dimensions,
+            algorithm.value()
+        ));
+        self
+    }
+
+    /// Retrieve the `rerank` score given to the object by a reranker module.
+    ///
+    /// # Parameters
+    /// - rerank: the property and query to rerank the results by
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{AdditionalBuilder, Rerank};
+    ///
+    /// let additional = AdditionalBuilder::new()
+    ///     .with_rerank(Rerank::new("answer", "floating"))
+    ///     .build();
+    /// ```
+    pub fn with_rerank(mut self, rerank: Rerank) -> AdditionalBuilder {
+        self.fields.push(rerank.to_string());
+        self
+    }
+
+    /// Build the `Additional` properties from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AdditionalBuilder;
+    ///
+    /// let additional = AdditionalBuilder::new().with_id().build();
+    /// ```
+    pub fn build(self) -> Additional {
+        Additional {
+            fields: self.fields,
+        }
+    }
+}
+
+/// The `ask` operator of the qna-transformers module, used to extract answers from objects.
+///
+/// More information on the `ask` operator can be found [here](https://weaviate.io/developers/weaviate/modules/reader-generator-modules/qna-transformers)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Ask {
+    pub question: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub properties: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub rerank: Option<bool>,
+}
+
+impl Ask {
+    /// Create a new builder for the `ask` operator.
+    ///
+    /// This is the same as `AskBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - question: the question to answer
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::Ask;
+    ///
+    /// let ask = Ask::builder("Who is the king of the Netherlands?").build();
+    /// ```
+    pub fn builder(question: &str) -> AskBuilder {
+        AskBuilder::new(question)
+    }
+}
+
+impl std::fmt::Display for Ask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for the `Ask` operator.
+#[derive(Debug)]
+pub struct AskBuilder {
+    pub question: String,
+    pub properties: Option<Vec<String>>,
+    pub rerank: Option<bool>,
+}
+
+impl AskBuilder {
+    /// Create a new builder for the `ask` operator.
+    ///
+    /// This is the same as `Ask::builder()`.
+    ///
+    /// # Parameters
+    /// - question: the question to answer
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AskBuilder;
+    ///
+    /// let ask = AskBuilder::new("Who is the king of the Netherlands?").build();
+    /// ```
+    pub fn new(question: &str) -> AskBuilder {
+        AskBuilder {
+            question: question.into(),
+            properties: None,
+            rerank: None,
+        }
+    }
+
+    /// Limit the properties the answer is extracted from.
+    ///
+    /// # Parameters
+    /// - properties: the properties to extract the answer from
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AskBuilder;
+    ///
+    /// let ask = AskBuilder::new("Who is the king of the Netherlands?")
+    ///     .with_properties(vec!["summary"])
+    ///     .build();
+    /// ```
+    pub fn with_properties(mut self, properties: Vec<&str>) -> AskBuilder {
+        self.properties = Some(properties.iter().map(|p| p.to_string()).collect());
+        self
+    }
+
+    /// Rerank the results by the certainty of their answers.
+    ///
+    /// # Parameters
+    /// - rerank: whether to rerank the results
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AskBuilder;
+    ///
+    /// let ask = AskBuilder::new("Who is the king of the Netherlands?")
+    ///     .with_rerank(true)
+    ///     .build();
+    /// ```
+    pub fn with_rerank(mut self, rerank: bool) -> AskBuilder {
+        self.rerank = Some(rerank);
+        self
+    }
+
+    /// Build the `Ask` operator from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::AskBuilder;
+    ///
+    /// let ask = AskBuilder::new("Who is the king of the Netherlands?").build();
+    /// ```
+    pub fn build(self) -> Ask {
+        Ask {
+            question: self.question,
+            properties: self.properties,
+            rerank: self.rerank,
+        }
+    }
+}
+
+/// The answer extracted by the qna-transformers module, parsed from the `answer` additional
+/// property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Answer {
+    #[serde(default)]
+    pub has_answer: Option<bool>,
+    #[serde(default)]
+    pub property: Option<String>,
+    #[serde(default)]
+    pub result: Option<String>,
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(default)]
+    pub start_position: Option<u32>,
+    #[serde(default)]
+    pub end_position: Option<u32>,
+}
+
+/// The `tokens` additional property of the ner-transformers module, used to extract named
+/// entities from objects.
+///
+/// More information on the ner-transformers module can be found [here](https://weaviate.io/developers/weaviate/modules/reader-generator-modules/ner-transformers)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Tokens {
+    pub properties: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl Tokens {
+    /// Create a new builder for the `tokens` additional property.
+    ///
+    /// This is the same as `TokensBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - properties: the properties to extract the entities from
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::Tokens;
+    ///
+    /// let tokens = Tokens::builder(vec!["summary"]).build();
+    /// ```
+    pub fn builder(properties: Vec<&str>) -> TokensBuilder {
+        TokensBuilder::new(properties)
+    }
+}
+
+impl std::fmt::Display for Tokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut arguments = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut arguments);
+        let arguments = &arguments[1..arguments.len() - 1];
+        write!(
+            f,
+            "tokens({}) {{ entity property word certainty distance startPosition endPosition }}",
+            arguments
+        )
+    }
+}
+
+/// The builder for the `Tokens` additional property.
+#[derive(Debug)]
+pub struct TokensBuilder {
+    pub properties: Vec<String>,
+    pub certainty: Option<f32>,
+    pub limit: Option<u32>,
+}
+
+impl TokensBuilder {
+    /// Create a new builder for the `tokens` additional property.
+    ///
+    /// This is the same as `Tokens::builder()`.
+    ///
+    /// # Parameters
+    /// - properties: the properties to extract the entities from
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::TokensBuilder;
+    ///
+    /// let tokens = TokensBuilder::new(vec!["summary"]).build();
+    /// ```
+    pub fn new(properties: Vec<&str>) -> TokensBuilder {
+        TokensBuilder {
+            properties: properties.iter().map(|p| p.to_string()).collect(),
+            certainty: None,
+            limit: None,
+        }
+    }
+
+    /// Only return the entities with at least this certainty.
+    ///
+    /// # Parameters
+    /// - certainty: the minimum certainty of the entities
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::TokensBuilder;
+    ///
+    /// let tokens = TokensBuilder::new(vec!["summary"]).with_certainty(0.8).build();
+    /// ```
+    pub fn with_certainty(mut self, certainty: f32) -> TokensBuilder {
+        self.certainty = Some(certainty);
+        self
+    }
+
+    /// Limit the number of entities returned per object.
+    ///
+    /// # Parameters
+    /// - limit: the maximum number of entities
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::TokensBuilder;
+    ///
+    /// let tokens = TokensBuilder::new(vec!["summary"]).with_limit(5).build();
+    /// ```
+    pub fn with_limit(mut self, limit: u32) -> TokensBuilder {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Build the `Tokens` additional property from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::TokensBuilder;
+    ///
+    /// let tokens = TokensBuilder::new(vec!["summary"]).build();
+    /// ```
+    pub fn build(self) -> Tokens {
+        Tokens {
+            properties: self.properties,
+            certainty: self.certainty,
+            limit: self.limit,
+        }
+    }
+}
+
+/// A named entity extracted by the ner-transformers module, parsed from the `tokens` additional
+/// property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+    pub entity: String,
+    #[serde(default)]
+    pub property: Option<String>,
+    pub word: String,
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(default)]
+    pub distance: Option<f32>,
+    #[serde(default)]
+    pub start_position: Option<u32>,
+    #[serde(default)]
+    pub end_position: Option<u32>,
+}
+
+/// The `rerank` additional property of the reranker modules, used to reorder the results of a
+/// search by their relevance to a query.
+///
+/// More information on the reranker modules can be found [here](https://weaviate.io/developers/weaviate/search/rerank)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Rerank {
+    pub property: String,
+    pub query: String,
+}
+
+impl Rerank {
+    /// Create a new `rerank` additional property.
+    ///
+    /// # Parameters
+    /// - property: the property to rerank the results by
+    /// - query: the query to score the property against
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::Rerank;
+    ///
+    /// let rerank = Rerank::new("answer", "floating");
+    /// ```
+    pub fn new(property: &str, query: &str) -> Rerank {
+        Rerank {
+            property: property.into(),
+            query: query.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Rerank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut arguments = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut arguments);
+        let arguments = &arguments[1..arguments.len() - 1];
+        write!(f, "rerank({}) {{ score }}", arguments)
+    }
+}
+
+/// The dimensionality reduction algorithms available for `featureProjection`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ProjectionAlgorithm {
+    #[serde(rename = "tsne")]
+    TSNE,
+}
+
+impl ProjectionAlgorithm {
+    /// Get the value of the ProjectionAlgorithm
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::ProjectionAlgorithm;
+    ///
+    /// let value = ProjectionAlgorithm::TSNE.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            ProjectionAlgorithm::TSNE => "tsne",
+        }
+    }
+}
+
+/// Strict definitions of the operators available to a `where` filter.
+///
+/// More information on the operators can be found [here](https://weaviate.io/developers/weaviate/api/graphql/filters)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum FilterOperator {
+    #[serde(rename = "And")]
+    AND,
+    #[serde(rename = "Or")]
+    OR,
+    #[serde(rename = "Equal")]
+    EQUAL,
+    #[serde(rename = "NotEqual")]
+    NOTEQUAL,
+    #[serde(rename = "GreaterThan")]
+    GREATERTHAN,
+    #[serde(rename = "GreaterThanEqual")]
+    GREATERTHANEQUAL,
+    #[serde(rename = "LessThan")]
+    LESSTHAN,
+    #[serde(rename = "LessThanEqual")]
+    LESSTHANEQUAL,
+    #[serde(rename = "Like")]
+    LIKE,
+    #[serde(rename = "WithinGeoRange")]
+    WITHINGEORANGE,
+    #[serde(rename = "IsNull")]
+    ISNULL,
+    #[serde(rename = "ContainsAny")]
+    CONTAINSANY,
+    #[serde(rename = "ContainsAll")]
+    CONTAINSALL,
+}
+
+/// A typed `where` filter.
+///
+/// The same filter can be used in a GraphQL query, where it is rendered through its `Display`
+/// implementation, or in a REST request body (such as a batch delete), where it is serialized as
+/// JSON.
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::collections::query::{FilterOperator, GetBuilder, WhereFilter};
+///
+/// let filter = WhereFilter::builder(vec!["wordCount"], FilterOperator::GREATERTHAN)
+///     .with_value_int(1000)
+///     .build();
+/// let query = GetBuilder::new("Article", vec!["title"])
+///     .with_where(&filter.to_string())
+///     .build();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WhereFilter {
+    pub operator: FilterOperator,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub path: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_int: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_number: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_boolean: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_geo_range: Option<GeoRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub value_text_array: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub operands: Option<Vec<WhereFilter>>,
+}
+
+impl WhereFilter {
+    /// Create a new builder for a single condition `where` filter.
+    ///
+    /// This is the same as `WhereFilterBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - path: the path to the property to filter on
+    /// - operator: the operator to apply
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    ///
+    /// let builder = WhereFilter::builder(vec!["name"], FilterOperator::EQUAL);
+    /// ```
+    pub fn builder(path: Vec<&str>, operator: FilterOperator) -> WhereFilterBuilder {
+        WhereFilterBuilder::new(path, operator)
+    }
+
+    /// Combine multiple filters with the `And` operator.
+    ///
+    /// # Parameters
+    /// - operands: the filters that must all match
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    ///
+    /// let filter = WhereFilter::and(vec![
+    ///     WhereFilter::builder(vec!["name"], FilterOperator::EQUAL)
+    ///         .with_value_text("test")
+    ///         .build(),
+    ///     WhereFilter::builder(vec!["wordCount"], FilterOperator::LESSTHAN)
+    ///         .with_value_int(10)
+    ///         .build(),
+    /// ]);
+    /// ```
+    pub fn and(operands: Vec<WhereFilter>) -> WhereFilter {
+        WhereFilter::with_operands(FilterOperator::AND, operands)
+    }
+
+    /// Combine multiple filters with the `Or` operator.
+    ///
+    /// # Parameters
+    /// - operands: the filters of which at least one must match
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    ///
+    /// let filter = WhereFilter::or(vec![
+    ///     WhereFilter::builder(vec!["name"], FilterOperator::EQUAL)
+    ///         .with_value_text("a")
+    ///         .build(),
+    ///     WhereFilter::builder(vec!["name"], FilterOperator::EQUAL)
+    ///         .with_value_text("b")
+    ///         .build(),
+    /// ]);
+    /// ```
+    pub fn or(operands: Vec<WhereFilter>) -> WhereFilter {
+        WhereFilter::with_operands(FilterOperator::OR, operands)
+    }
+
+    /// Check the filter against the definition of the class it will be applied to.
+    ///
+    /// Each `path` must start with a property of the class (or an object metadata field, such as
+    /// `id` or `_creationTimeUnix`), and only paths through a cross-reference may have more than
+    /// one segment. Range operators such as `GreaterThan` are only allowed on `int`, `number`,
+    /// and `date` properties, `Like` on `text` properties, and `WithinGeoRange` on
+    /// `geoCoordinates` properties. Segments past a cross-reference are not checked, as they
+    /// belong to another class.
+    ///
+    /// Returns a `WeaviateError::InvalidRequest` listing every violation found.
+    ///
+    /// # Parameters
+    /// - class: the class the filter will be applied to
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    /// use weaviate_community::collections::schema::{Class, Properties, Property};
+    ///
+    /// let class = Class::builder("Article")
+    ///     .with_properties(Properties::new(vec![
+    ///         Property::builder("wordCount", vec!["int"]).build(),
+    ///     ]))
+    ///     .build();
+    /// let filter = WhereFilter::builder(vec!["wordCount"], FilterOperator::GREATERTHAN)
+    ///     .with_value_int(1000)
+    ///     .build();
+    /// assert!(filter.validate_against(&class).is_ok());
+    /// ```
+    pub fn validate_against(&self, class: &Class) -> Result<(), WeaviateError> {
+        let mut violations = Vec::new();
+        self.collect_violations(class, &mut violations);
+        if violations.is_empty() {
+            return Ok(());
+        }
+        Err(WeaviateError::InvalidRequest(format!(
+            "invalid where filter for class {}: {}",
+            class.class,
+            violations.join("; ")
+        )))
+    }
+
+    /// Add a message to `violations` for each part of the filter that the class can't satisfy.
+    fn collect_violations(&self, class: &Class, violations: &mut Vec<String>) {
+        for operand in self.operands.iter().flatten() {
+            operand.collect_violations(class, violations);
+        }
+        let path = match &self.path {
+            Some(path) if !path.is_empty() => path,
+            _ => return,
+        };
+        let name = path[0].as_str();
+        if matches!(
+            name,
+            "id" | "_id" | "_creationTimeUnix" | "_lastUpdateTimeUnix"
+        ) {
+            return;
+        }
+        let (name, is_length) = match name.strip_prefix("len(").and_then(|n| n.strip_suffix(')')) {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let property = class
+            .properties
+            .iter()
+            .flat_map(|properties| properties.0.iter())
+            .find(|property| property.name == name);
+        let property = match property {
+            Some(property) => property,
+            None => {
+                violations.push(format!("property `{}` does not exist", name));
+                return;
+            }
+        };
+        let data_type = match property.data_type.first() {
+            Some(data_type) => data_type.trim_end_matches("[]"),
+            None => return,
+        };
+        let is_reference = data_type.starts_with(|c: char| c.is_ascii_uppercase());
+        if path.len() > 1 {
+            if !is_reference {
+                violations.push(format!(
+                    "property `{}` is not a cross-reference, so the path `{}` can't continue",
+                    name,
+                    path.join(".")
+                ));
+            }
+            return;
+        }
+        let data_type = if is_length { "int" } else { data_type };
+        let allowed = match self.operator {
+            FilterOperator::GREATERTHAN
+            | FilterOperator::GREATERTHANEQUAL
+            | FilterOperator::LESSTHAN
+            | FilterOperator::LESSTHANEQUAL => matches!(data_type, "int" | "number" | "date"),
+            FilterOperator::LIKE => matches!(data_type, "text" | "string"),
+            FilterOperator::WITHINGEORANGE => data_type == "geoCoordinates",
+            _ => true,
+        };
+        if !allowed {
+            violations.push(format!(
+                "operator {} can't be used on property `{}` of type {}",
+                serde_json::json!(self.operator)
+                    .as_str()
+                    .unwrap_or_default(),
+                name,
+                data_type
+            ));
+        }
+    }
+
+    /// Create a filter with nested operands.
+    fn with_operands(operator: FilterOperator, operands: Vec<WhereFilter>) -> WhereFilter {
+        WhereFilter {
+            operator,
+            path: None,
+            value_text: None,
+            value_int: None,
+            value_number: None,
+            value_boolean: None,
+            value_date: None,
+            value_geo_range: None,
+            value_text_array: None,
+            operands: Some(operands),
+        }
+    }
+}
+
+impl std::fmt::Display for WhereFilter {
+    /// Render the filter as a GraphQL argument, for example
+    /// `{operator: Equal, path: ["name"], valueText: "test"}`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut out = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for a `WhereFilter`.
+#[derive(Debug)]
+pub struct WhereFilterBuilder {
+    pub path: Vec<String>,
+    pub operator: FilterOperator,
+    pub value_text: Option<String>,
+    pub value_int: Option<i64>,
+    pub value_number: Option<f64>,
+    pub value_boolean: Option<bool>,
+    pub value_date: Option<String>,
+    pub value_geo_range: Option<GeoRange>,
+    pub value_text_array: Option<Vec<String>>,
+}
+
+impl WhereFilterBuilder {
+    /// Create a new WhereFilterBuilder item.
+    ///
+    /// This is the same as `WhereFilter::builder()`.
+    ///
+    /// # Parameters
+    /// - path: the path to the property to filter on
+    /// - operator: the operator to apply
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["name"], FilterOperator::EQUAL);
+    /// ```
+    pub fn new(path: Vec<&str>, operator: FilterOperator) -> WhereFilterBuilder {
+        WhereFilterBuilder {
+            path: path.iter().map(|item| item.to_string()).collect(),
+            operator,
+            value_text: None,
+            value_int: None,
+            value_number: None,
+            value_boolean: None,
+            value_date: None,
+            value_geo_range: None,
+            value_text_array: None,
+        }
+    }
+
+    /// Compare the property against a text value.
+    ///
+    /// # Parameters
+    /// - value: the value to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["name"], FilterOperator::EQUAL)
+    ///     .with_value_text("test");
+    /// ```
+    pub fn with_value_text(mut self, value: &str) -> WhereFilterBuilder {
+        self.value_text = Some(value.into());
+        self
+    }
+
+    /// Compare the property against an integer value.
+    ///
+    /// # Parameters
+    /// - value: the value to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["wordCount"], FilterOperator::GREATERTHAN)
+    ///     .with_value_int(100);
+    /// ```
+    pub fn with_value_int(mut self, value: i64) -> WhereFilterBuilder {
+        self.value_int = Some(value);
+        self
+    }
+
+    /// Compare the property against a number value.
+    ///
+    /// # Parameters
+    /// - value: the value to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["price"], FilterOperator::LESSTHAN)
+    ///     .with_value_number(9.99);
+    /// ```
+    pub fn with_value_number(mut self, value: f64) -> WhereFilterBuilder {
+        self.value_number = Some(value);
+        self
+    }
+
+    /// Compare the property against a boolean value.
+    ///
+    /// # Parameters
+    /// - value: the value to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["published"], FilterOperator::EQUAL)
+    ///     .with_value_boolean(true);
+    /// ```
+    pub fn with_value_boolean(mut self, value: bool) -> WhereFilterBuilder {
+        self.value_boolean = Some(value);
+        self
+    }
+
+    /// Compare the property against a date value, in RFC 3339 format.
+    ///
+    /// # Parameters
+    /// - value: the value to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["published"], FilterOperator::GREATERTHAN)
+    ///     .with_value_date("2023-01-01T00:00:00Z");
+    /// ```
+    pub fn with_value_date(mut self, value: &str) -> WhereFilterBuilder {
+        self.value_date = Some(value.into());
+        self
+    }
+
+    /// Match properties of type `geoCoordinates` within a range of a location.
+    ///
+    /// Should be used with the `FilterOperator::WITHINGEORANGE` operator.
+    ///
+    /// # Parameters
+    /// - value: the geo range to match against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::GeoCoordinates;
+    /// use weaviate_community::collections::query::{
+    ///     FilterOperator, GeoRange, WhereFilterBuilder
+    /// };
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["location"], FilterOperator::WITHINGEORANGE)
+    ///     .with_value_geo_range(GeoRange::new(GeoCoordinates::new(52.366667, 4.9), 2000.0));
+    /// ```
+    pub fn with_value_geo_range(mut self, value: GeoRange) -> WhereFilterBuilder {
+        self.value_geo_range = Some(value);
+        self
+    }
+
+    /// Compare the property against a list of text values.
+    ///
+    /// Should be used with the `FilterOperator::CONTAINSANY` or `FilterOperator::CONTAINSALL`
+    /// operators.
+    ///
+    /// # Parameters
+    /// - values: the values to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let builder = WhereFilterBuilder::new(vec!["tags"], FilterOperator::CONTAINSANY)
+    ///     .with_value_text_array(vec!["news", "sport"]);
+    /// ```
+    pub fn with_value_text_array(mut self, values: Vec<&str>) -> WhereFilterBuilder {
+        self.value_text_array = Some(values.iter().map(|value| value.to_string()).collect());
+        self
+    }
+
+    /// Build the `WhereFilter`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilterBuilder};
+    ///
+    /// let filter = WhereFilterBuilder::new(vec!["name"], FilterOperator::EQUAL)
+    ///     .with_value_text("test")
+    ///     .build();
+    /// ```
+    pub fn build(self) -> WhereFilter {
+        WhereFilter {
+            operator: self.operator,
+            path: Some(self.path),
+            value_text: self.value_text,
+            value_int: self.value_int,
+            value_number: self.value_number,
+            value_boolean: self.value_boolean,
+            value_date: self.value_date,
+            value_geo_range: self.value_geo_range,
+            value_text_array: self.value_text_array,
+            operands: None,
+        }
+    }
+}
+
+/// The value of a `WithinGeoRange` `where` filter.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoRange {
+    pub geo_coordinates: GeoCoordinates,
+    pub distance: GeoDistance,
+}
+
+impl GeoRange {
+    /// Create a new GeoRange.
+    ///
+    /// # Parameters
+    /// - geo_coordinates: the location to measure from
+    /// - max_distance: the maximum distance from the location, in meters
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::objects::GeoCoordinates;
+    /// use weaviate_community::collections::query::GeoRange;
+    ///
+    /// let range = GeoRange::new(GeoCoordinates::new(52.366667, 4.9), 2000.0);
+    /// ```
+    pub fn new(geo_coordinates: GeoCoordinates, max_distance: f32) -> GeoRange {
+        GeoRange {
+            geo_coordinates,
+            distance: GeoDistance { max: max_distance },
+        }
+    }
+}
+
+/// The distance component of a `GeoRange`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct GeoDistance {
+    pub max: f32,
+}
+
+/// The `nearText` filter, used to find objects near the vectorized concepts.
+///
+/// More information on the `nearText` filter can be found [here](https://weaviate.io/developers/weaviate/api/graphql/search-operators#neartext)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NearText {
+    pub concepts: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub distance: Option<f32>,
+    #[serde(rename = "targetVectors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearText {
+    /// Create a new builder for the `nearText` filter.
+    ///
+    /// This is the same as `NearTextBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - concepts: the concepts to search near
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearText;
+    ///
+    /// let near_text = NearText::builder(vec!["New Yorker"]).build();
+    /// ```
+    pub fn builder(concepts: Vec<&str>) -> NearTextBuilder {
+        NearTextBuilder::new(concepts)
+    }
+}
+
+impl std::fmt::Display for NearText {
+    /// Render the filter as a GraphQL argument, for example
+    /// `{certainty: 0.7, concepts: ["New Yorker"]}`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut out = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for the `NearText` filter.
+#[derive(Debug)]
+pub struct NearTextBuilder {
+    pub concepts: Vec<String>,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearTextBuilder {
+    /// Create a new builder for the `nearText` filter.
+    ///
+    /// This is the same as `NearText::builder()`.
+    ///
+    /// # Parameters
+    /// - concepts: the concepts to search near
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearTextBuilder;
+    ///
+    /// let near_text = NearTextBuilder::new(vec!["New Yorker"]).build();
+    /// ```
+    pub fn new(concepts: Vec<&str>) -> NearTextBuilder {
+        NearTextBuilder {
+            concepts: concepts.iter().map(|c| c.to_string()).collect(),
+            certainty: None,
+            distance: None,
+            target_vectors: None,
+        }
+    }
+
+    /// Only return objects with at least this certainty.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any distance already set.
+    ///
+    /// # Parameters
+    /// - certainty: the minimum certainty, between 0 and 1
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearTextBuilder;
+    ///
+    /// let near_text = NearTextBuilder::new(vec!["New Yorker"]).with_certainty(0.7).build();
+    /// ```
+    pub fn with_certainty(mut self, certainty: f32) -> NearTextBuilder {
+        self.certainty = Some(certainty);
+        self.distance = None;
+        self
+    }
+
+    /// Only return objects within this distance.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any certainty already set.
+    ///
+    /// # Parameters
+    /// - distance: the maximum distance
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearTextBuilder;
+    ///
+    /// let near_text = NearTextBuilder::new(vec!["New Yorker"]).with_distance(0.3).build();
+    /// ```
+    pub fn with_distance(mut self, distance: f32) -> NearTextBuilder {
+        self.distance = Some(distance);
+        self.certainty = None;
+        self
+    }
+
+    /// Search against these named vectors, for classes configured with multiple named vectors.
+    ///
+    /// # Parameters
+    /// - target_vectors: the names of the vectors to search
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearTextBuilder;
+    ///
+    /// let near_text = NearTextBuilder::new(vec!["New Yorker"])
+    ///     .with_target_vectors(vec!["title"]).build();
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> NearTextBuilder {
+        self.target_vectors = Some(target_vectors.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Build the `NearText` filter from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearTextBuilder;
+    ///
+    /// let near_text = NearTextBuilder::new(vec!["New Yorker"]).build();
+    /// ```
+    pub fn build(self) -> NearText {
+        NearText {
+            concepts: self.concepts,
+            certainty: self.certainty,
+            distance: self.distance,
+            target_vectors: self.target_vectors,
+        }
+    }
+}
+
+/// The `nearVector` filter, used to find objects near a vector.
+///
+/// More information on the `nearVector` filter can be found [here](https://weaviate.io/developers/weaviate/api/graphql/search-operators#nearvector)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NearVector {
+    pub vector: Vec<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub distance: Option<f32>,
+    #[serde(rename = "targetVectors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearVector {
+    /// Create a new builder for the `nearVector` filter.
+    ///
+    /// This is the same as `NearVectorBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - vector: the vector to search near
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVector;
+    ///
+    /// let near_vector = NearVector::builder(vec![0.1, 0.2, 0.3]).build();
+    /// ```
+    pub fn builder(vector: Vec<f32>) -> NearVectorBuilder {
+        NearVectorBuilder::new(vector)
+    }
+}
+
+impl std::fmt::Display for NearVector {
+    /// Render the filter as a GraphQL argument, for example
+    /// `{distance: 0.3, vector: [0.1, 0.2, 0.3]}`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut out = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for the `NearVector` filter.
+#[derive(Debug)]
+pub struct NearVectorBuilder {
+    pub vector: Vec<f32>,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearVectorBuilder {
+    /// Create a new builder for the `nearVector` filter.
+    ///
+    /// This is the same as `NearVector::builder()`.
+    ///
+    /// # Parameters
+    /// - vector: the vector to search near
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVectorBuilder;
+    ///
+    /// let near_vector = NearVectorBuilder::new(vec![0.1, 0.2, 0.3]).build();
+    /// ```
+    pub fn new(vector: Vec<f32>) -> NearVectorBuilder {
+        NearVectorBuilder {
+            vector,
+            certainty: None,
+            distance: None,
+            target_vectors: None,
+        }
+    }
+
+    /// Only return objects with at least this certainty.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any distance already set.
+    ///
+    /// # Parameters
+    /// - certainty: the minimum certainty, between 0 and 1
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVectorBuilder;
+    ///
+    /// let near_vector = NearVectorBuilder::new(vec![0.1, 0.2, 0.3]).with_certainty(0.7).build();
+    /// ```
+    pub fn with_certainty(mut self, certainty: f32) -> NearVectorBuilder {
+        self.certainty = Some(certainty);
+        self.distance = None;
+        self
+    }
+
+    /// Only return objects within this distance.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any certainty already set.
+    ///
+    /// # Parameters
+    /// - distance: the maximum distance
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVectorBuilder;
+    ///
+    /// let near_vector = NearVectorBuilder::new(vec![0.1, 0.2, 0.3]).with_distance(0.3).build();
+    /// ```
+    pub fn with_distance(mut self, distance: f32) -> NearVectorBuilder {
+        self.distance = Some(distance);
+        self.certainty = None;
+        self
+    }
+
+    /// Search against these named vectors, for classes configured with multiple named vectors.
+    ///
+    /// # Parameters
+    /// - target_vectors: the names of the vectors to search
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVectorBuilder;
+    ///
+    /// let near_vector = NearVectorBuilder::new(vec![0.1, 0.2, 0.3])
+    ///     .with_target_vectors(vec!["title"]).build();
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> NearVectorBuilder {
+        self.target_vectors = Some(target_vectors.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Build the `NearVector` filter from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearVectorBuilder;
+    ///
+    /// let near_vector = NearVectorBuilder::new(vec![0.1, 0.2, 0.3]).build();
+    /// ```
+    pub fn build(self) -> NearVector {
+        NearVector {
+            vector: self.vector,
+            certainty: self.certainty,
+            distance: self.distance,
+            target_vectors: self.target_vectors,
+        }
+    }
+}
+
+/// The `nearObject` filter, used to find objects near an existing object.
+///
+/// Exactly one of `id` or `beacon` identifies the object to search near.
+///
+/// More information on the `nearObject` filter can be found [here](https://weaviate.io/developers/weaviate/api/graphql/search-operators#nearobject)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NearObject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub beacon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub certainty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub distance: Option<f32>,
+    #[serde(rename = "targetVectors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearObject {
+    /// Create a new builder for the `nearObject` filter.
+    ///
+    /// This is the same as `NearObjectBuilder::new()`.
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObject;
+    ///
+    /// let near_object = NearObject::builder().with_id(Uuid::new_v4()).build().unwrap();
+    /// ```
+    pub fn builder() -> NearObjectBuilder {
+        NearObjectBuilder::new()
+    }
+}
+
+impl std::fmt::Display for NearObject {
+    /// Render the filter as a GraphQL argument, for example
+    /// `{certainty: 0.7, id: "ee22d1b8-3b95-4e94-96d5-9a2b60fbd303"}`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut out = String::new();
+        write_graphql_value(&serde_json::json!(self), &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for the `NearObject` filter.
+#[derive(Debug, Default)]
+pub struct NearObjectBuilder {
+    pub id: Option<Uuid>,
+    pub beacon: Option<String>,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearObjectBuilder {
+    /// Create a new builder for the `nearObject` filter.
+    ///
+    /// This is the same as `NearObject::builder()`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new();
+    /// ```
+    pub fn new() -> NearObjectBuilder {
+        NearObjectBuilder::default()
+    }
+
+    /// Search near the object with this id.
+    ///
+    /// # Parameters
+    /// - id: the uuid of the object to search near
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new().with_id(Uuid::new_v4());
+    /// ```
+    pub fn with_id(mut self, id: Uuid) -> NearObjectBuilder {
+        self.id = Some(id);
+        self
+    }
+
+    /// Search near the object with this beacon.
+    ///
+    /// # Parameters
+    /// - beacon: the beacon of the object to search near
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new()
+    ///     .with_beacon("weaviate://localhost/Article/ee22d1b8-3b95-4e94-96d5-9a2b60fbd303");
+    /// ```
+    pub fn with_beacon(mut self, beacon: &str) -> NearObjectBuilder {
+        self.beacon = Some(beacon.into());
+        self
+    }
+
+    /// Only return objects with at least this certainty.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any distance already set.
+    ///
+    /// # Parameters
+    /// - certainty: the minimum certainty, between 0 and 1
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new().with_id(Uuid::new_v4()).with_certainty(0.7);
+    /// ```
+    pub fn with_certainty(mut self, certainty: f32) -> NearObjectBuilder {
+        self.certainty = Some(certainty);
+        self.distance = None;
+        self
+    }
+
+    /// Only return objects within this distance.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any certainty already set.
+    ///
+    /// # Parameters
+    /// - distance: the maximum distance
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new().with_id(Uuid::new_v4()).with_distance(0.3);
+    /// ```
+    pub fn with_distance(mut self, distance: f32) -> NearObjectBuilder {
+        self.distance = Some(distance);
+        self.certainty = None;
+        self
+    }
+
+    /// Search against these named vectors, for classes configured with multiple named vectors.
+    ///
+    /// # Parameters
+    /// - target_vectors: the names of the vectors to search
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let builder = NearObjectBuilder::new().with_id(Uuid::new_v4())
+    ///     .with_target_vectors(vec!["title"]);
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> NearObjectBuilder {
+        self.target_vectors = Some(target_vectors.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Build the `NearObject` filter from the builder.
+    ///
+    /// Returns a `WeaviateError::InvalidRequest` unless exactly one of `with_id` or
+    /// `with_beacon` has been set.
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::collections::query::NearObjectBuilder;
+    ///
+    /// let near_object = NearObjectBuilder::new().with_id(Uuid::new_v4()).build().unwrap();
+    /// assert!(NearObjectBuilder::new().build().is_err());
+    /// ```
+    pub fn build(self) -> Result<NearObject, WeaviateError> {
+        if self.id.is_some() == self.beacon.is_some() {
+            return Err(WeaviateError::InvalidRequest(
+                "nearObject requires exactly one of `id` or `beacon`".into(),
+            ));
+        }
+        Ok(NearObject {
+            id: self.id,
+            beacon: self.beacon,
+            certainty: self.certainty,
+            distance: self.distance,
+            target_vectors: self.target_vectors,
+        })
+    }
+}
+
+/// The media types that can be searched with a `near<Media>` filter.
+///
+/// Each requires a multi-modal module such as `multi2vec-bind` to be enabled in Weaviate.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MediaType {
+    #[serde(rename = "image")]
+    IMAGE,
+    #[serde(rename = "audio")]
+    AUDIO,
+    #[serde(rename = "video")]
+    VIDEO,
+    #[serde(rename = "depth")]
+    DEPTH,
+    #[serde(rename = "thermal")]
+    THERMAL,
+    #[serde(rename = "imu")]
+    IMU,
+}
+
+impl MediaType {
+    /// Get the value of the MediaType, used as the key of the media in the filter.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::MediaType;
+    ///
+    /// let value = MediaType::IMAGE.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            MediaType::IMAGE => "image",
+            MediaType::AUDIO => "audio",
+            MediaType::VIDEO => "video",
+            MediaType::DEPTH => "depth",
+            MediaType::THERMAL => "thermal",
+            MediaType::IMU => "imu",
+        }
+    }
+}
+
+/// A `near<Media>` filter, such as `nearImage` or `nearAudio`, used to find objects near a
+/// base64 encoded piece of media.
+///
+/// More information on the `nearImage` filter can be found [here](https://weaviate.io/developers/weaviate/api/graphql/search-operators#nearimage)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearMedia {
+    pub media_type: MediaType,
+    pub content: String,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearMedia {
+    /// Create a new builder for a `near<Media>` filter from base64 encoded media.
+    ///
+    /// This is the same as `NearMediaBuilder::new()`.
+    ///
+    /// # Parameters
+    /// - media_type: the type of the media
+    /// - base64: the base64 encoded media
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::builder(MediaType::IMAGE, "iVBORw0KGgoAAAANS").build();
+    /// ```
+    pub fn builder(media_type: MediaType, base64: &str) -> NearMediaBuilder {
+        NearMediaBuilder::new(media_type, base64)
+    }
+}
+
+impl std::fmt::Display for NearMedia {
+    /// Render the filter as a GraphQL argument, for example
+    /// `{certainty: 0.7, image: "iVBORw0KGgoAAAANS"}`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut value = serde_json::json!({ self.media_type.value(): self.content });
+        if let Some(certainty) = self.certainty {
+            value["certainty"] = serde_json::json!(certainty);
+        }
+        if let Some(distance) = self.distance {
+            value["distance"] = serde_json::json!(distance);
+        }
+        if let Some(target_vectors) = &self.target_vectors {
+            value["targetVectors"] = serde_json::json!(target_vectors);
+        }
+        let mut out = String::new();
+        write_graphql_value(&value, &mut out);
+        write!(f, "{}", out)
+    }
+}
+
+/// The builder for a `NearMedia` filter.
+#[derive(Debug)]
+pub struct NearMediaBuilder {
+    pub media_type: MediaType,
+    pub content: String,
+    pub certainty: Option<f32>,
+    pub distance: Option<f32>,
+    pub target_vectors: Option<Vec<String>>,
+}
+
+impl NearMediaBuilder {
+    /// Create a new builder for a `near<Media>` filter from base64 encoded media.
+    ///
+    /// This is the same as `NearMedia::builder()`.
+    ///
+    /// # Parameters
+    /// - media_type: the type of the media
+    /// - base64: the base64 encoded media
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let near_image = NearMediaBuilder::new(MediaType::IMAGE, "iVBORw0KGgoAAAANS").build();
+    /// ```
+    pub fn new(media_type: MediaType, base64: &str) -> NearMediaBuilder {
+        NearMediaBuilder {
+            media_type,
+            content: base64.into(),
+            certainty: None,
+            distance: None,
+            target_vectors: None,
+        }
+    }
+
+    /// Create a new builder for a `near<Media>` filter from raw bytes, which are base64 encoded.
+    ///
+    /// # Parameters
+    /// - media_type: the type of the media
+    /// - bytes: the raw media, for example the contents of an image file
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let bytes = std::fs::read("cat.png").unwrap();
+    /// let near_image = NearMediaBuilder::from_bytes(MediaType::IMAGE, &bytes).build();
+    /// ```
+    pub fn from_bytes(media_type: MediaType, bytes: &[u8]) -> NearMediaBuilder {
+        let content = base64::engine::general_purpose::STANDARD.encode(bytes);
+        NearMediaBuilder::new(media_type, &content)
+    }
+
+    /// Only return objects with at least this certainty.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any distance already set.
+    ///
+    /// # Parameters
+    /// - certainty: the minimum certainty, between 0 and 1
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let near_image = NearMediaBuilder::new(MediaType::IMAGE, "iVBORw0KGgoAAAANS")
+    ///     .with_certainty(0.7)
+    ///     .build();
+    /// ```
+    pub fn with_certainty(mut self, certainty: f32) -> NearMediaBuilder {
+        self.certainty = Some(certainty);
+        self.distance = None;
+        self
+    }
+
+    /// Only return objects within this distance.
+    ///
+    /// Certainty and distance are mutually exclusive, so this clears any certainty already set.
+    ///
+    /// # Parameters
+    /// - distance: the maximum distance
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let near_image = NearMediaBuilder::new(MediaType::IMAGE, "iVBORw0KGgoAAAANS")
+    ///     .with_distance(0.3)
+    ///     .build();
+    /// ```
+    pub fn with_distance(mut self, distance: f32) -> NearMediaBuilder {
+        self.distance = Some(distance);
+        self.certainty = None;
+        self
+    }
+
+    /// Search against these named vectors, for classes configured with multiple named vectors.
+    ///
+    /// # Parameters
+    /// - target_vectors: the names of the vectors to search
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let near_image = NearMediaBuilder::new(MediaType::IMAGE, "iVBORw0KGgoAAAANS")
+    ///     .with_target_vectors(vec!["title"]).build();
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> NearMediaBuilder {
+        self.target_vectors = Some(target_vectors.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Build the `NearMedia` filter from the builder.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::collections::query::{MediaType, NearMediaBuilder};
+    ///
+    /// let near_image = NearMediaBuilder::new(MediaType::IMAGE, "iVBORw0KGgoAAAANS").build();
+    /// ```
+    pub fn build(self) -> NearMedia {
+        NearMedia {
+            media_type: self.media_type,
+            content: self.content,
+            certainty: self.certainty,
+            distance: self.distance,
+            target_vectors: self.target_vectors,
+        }
+    }
+}
+
+/// Render a value as a quoted GraphQL string, escaping any quotes and control characters.
+fn graphql_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// Render a JSON value using GraphQL input syntax.
+///
+/// Object keys are left unquoted, and the `operator` values are emitted as enum values.
+fn write_graphql_value(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            out.push('{');
+            for (i, (key, value)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                out.push_str(key);
+                out.push_str(": ");
+                match (key.as_str(), value) {
+                    ("operator", serde_json::Value::String(op)) => out.push_str(op),
+                    _ => write_graphql_value(value, out),
+                }
+            }
+            out.push('}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_graphql_value(item, out);
+            }
+            out.push(']');
+        }
+        serde_json::Value::Number(number) => match number.as_f64() {
+            // Values stored as f32 widen to long f64 decimals, so print them at f32 precision
+            Some(float) if number.is_f64() && (float as f32) as f64 == float => {
+                out.push_str(&format!("{:?}", float as f32))
+            }
+            _ => out.push_str(&number.to_string()),
+        },
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //use super::GetBuilder;
+    use super::{
+        Additional, AggregateBuilder, AggregateField, Ask, ExploreBuilder, FilterOperator,
+        GeoRange, GetBuilder, GraphQLResponse, MediaType, NearMedia, NearMediaBuilder, NearObject,
+        NearText, NearVector, ProjectionAlgorithm, Rerank, Tokens, WhereFilter,
+    };
+    use crate::collections::error::WeaviateError;
+    use crate::collections::objects::GeoCoordinates;
+    use crate::collections::schema::{Class, Properties, Property};
+    use serde::Deserialize;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_where_filter_geo_range() {
+        let filter = WhereFilter::builder(vec!["location"], FilterOperator::WITHINGEORANGE)
+            .with_value_geo_range(GeoRange::new(GeoCoordinates::new(52.5, 4.5), 2000.0))
+            .build();
+        assert_eq!(
+            serde_json::json!({
+                "operator": "WithinGeoRange",
+                "path": ["location"],
+                "valueGeoRange": {
+                    "geoCoordinates": {"latitude": 52.5, "longitude": 4.5},
+                    "distance": {"max": 2000.0}
+                }
+            }),
+            serde_json::to_value(&filter).unwrap()
+        );
+        assert_eq!(
+            "{operator: WithinGeoRange, path: [\"location\"], valueGeoRange: \
+            {distance: {max: 2000.0}, geoCoordinates: {latitude: 52.5, longitude: 4.5}}}",
+            filter.to_string()
+        );
+    }
+
+    #[derive(Deserialize)]
+    struct Question {
+        question: String,
+        points: u32,
+    }
+
+    #[test]
+    fn test_get_hits() {
+        let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "JeopardyQuestion": [
+                        {
+                            "question": "This prophet passed the time he spent inside a fish",
+                            "points": 100,
+                            "_additional": {
+                                "certainty": 0.9,
+                                "distance": 0.2,
+                                "id": "ee22d1b8-3b95-4e94-96d5-9a2b60fbd303"
+                            }
+                        },
+                        {
+                            "question": "Pythons",
+                            "points": 200
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let hits = res.get_hits::<Question>("JeopardyQuestion").unwrap();
+        assert_eq!(2, hits.len());
+        assert_eq!(100, hits[0].object.points);
+        assert_eq!(Some(0.9), hits[0].certainty);
+        assert_eq!(Some(0.2), hits[0].distance);
+        assert_eq!(
+            Some(Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap()),
+            hits[0].id
+        );
+        assert_eq!("Pythons", hits[1].object.question);
+        assert!(hits[1].certainty.is_none());
+        assert!(hits[1].id.is_none());
+        assert!(res.get_hits::<Question>("Missing").is_err());
+    }
+
+    #[test]
+    fn test_additional_feature_projection() {
+        let additional = Additional::builder()
+            .with_id()
+            .with_feature_projection(2, ProjectionAlgorithm::TSNE)
+            .build();
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_additional_properties(additional)
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "_additional {\n        id featureProjection(dimensions: 2, algorithm: \"tsne\") { vector }\n"
+        ));
+
+        let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "Article": [
+                        {
+                            "title": "test",
+                            "_additional": {
+                                "featureProjection": {"vector": [-115.17, 11.73]}
+                            }
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let hits = res.get_hits::<serde_json::Value>("Article").unwrap();
+        assert_eq!(Some(vec![-115.17, 11.73]), hits[0].feature_projection);
+    }
+
+    #[test]
+    fn test_ask_answer() {
+        let ask = Ask::builder("Who is the king of the Netherlands?")
+            .with_properties(vec!["summary"])
+            .with_rerank(true)
+            .build();
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_ask(ask)
+            .with_additional_properties(Additional::builder().with_answer().build())
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "ask: {properties: [\"summary\"], question: \"Who is the king of the Netherlands?\", rerank: true}"
+        ));
+        assert!(query
+            .query
+            .contains("answer { hasAnswer property result certainty startPosition endPosition }"));
+
+        let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "Article": [
+                        {
+                            "title": "Willem-Alexander",
+                            "_additional": {
+                                "answer": {
+                                    "hasAnswer": true,
+                                    "property": "summary",
+                                    "result": "Willem-Alexander",
+                                    "certainty": 0.73,
+                                    "startPosition": 109,
+                                    "endPosition": 125
+                                }
+                            }
+                        },
+                        {"title": "No answer"}
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let hits = res.get_hits::<serde_json::Value>("Article").unwrap();
+        let answer = hits[0].answer.as_ref().unwrap();
+        assert_eq!(Some("Willem-Alexander".into()), answer.result);
+        assert_eq!(Some(0.73), answer.certainty);
+        assert_eq!(Some(109), answer.start_position);
+        assert_eq!(Some(125), answer.end_position);
+        assert!(hits[1].answer.is_none());
+    }
+
+    #[test]
+    fn test_tokens() {
+        let tokens = Tokens::builder(vec!["summary"])
+            .with_certainty(0.7)
+            .with_limit(2)
+            .build();
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_additional_properties(Additional::builder().with_tokens(tokens).build())
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "tokens(certainty: 0.7, limit: 2, properties: [\"summary\"]) { entity property word certainty distance startPosition endPosition }"
+        ));
+
+        let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "Article": [
+                        {
+                            "title": "test",
+                            "_additional": {
+                                "tokens": [
+                                    {
+                                        "entity": "PER",
+                                        "property": "summary",
+                                        "word": "Sarah",
+                                        "certainty": 0.99,
+                                        "distance": 0.01,
+                                        "startPosition": 11,
+                                        "endPosition": 16
+                                    }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let hits = res.get_hits::<serde_json::Value>("Article").unwrap();
+        let tokens = hits[0].tokens.as_ref().unwrap();
+        assert_eq!(1, tokens.len());
+        assert_eq!("PER", tokens[0].entity);
+        assert_eq!("Sarah", tokens[0].word);
+        assert_eq!(Some(11), tokens[0].start_position);
+    }
+
+    #[test]
+    fn test_rerank() {
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_near_text("{concepts: [\"flying\"]}")
+            .with_additional(vec!["distance"])
+            .with_rerank(Rerank::new("answer", "floating"))
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "_additional {\n        distance rerank(property: \"answer\", query: \"floating\") { score }\n"
+        ));
+
+        let additional = Additional::builder()
+            .with_rerank(Rerank::new("answer", "floating"))
+            .build();
+        assert_eq!(
+            vec!["rerank(property: \"answer\", query: \"floating\") { score }".to_string()],
+            additional.fields
+        );
+
+        let res: GraphQLResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "JeopardyQuestion": [
+                        {
+                            "question": "test",
+                            "_additional": {
+                                "rerank": [{"score": 0.96}]
+                            }
+                        },
+                        {"question": "not reranked"}
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let hits = res
+            .get_hits::<serde_json::Value>("JeopardyQuestion")
+            .unwrap();
+        assert_eq!(Some(0.96), hits[0].rerank_score);
+        assert!(hits[1].rerank_score.is_none());
+    }
+
+    #[test]
+    fn test_non_ascii_values() {
+        let where_filter = WhereFilter::builder(vec!["question"], FilterOperator::EQUAL)
+            .with_value_text("東京タワー 🗼 \"café\"")
+            .build();
+        assert_eq!(
+            "{operator: Equal, path: [\"question\"], valueText: \"東京タワー 🗼 \\\"café\\\"\"}",
+            where_filter.to_string()
+        );
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_where(&where_filter.to_string())
+            .with_bm25("{query: \"寿司 🍣\"}")
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("valueText: \"東京タワー 🗼 \\\"café\\\"\""));
+        assert!(query.query.contains("bm25: {query: \"寿司 🍣\"}"));
+
+        let payload = serde_json::to_string(&query).unwrap();
+        let round_trip: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(query.query, round_trip["query"].as_str().unwrap());
+
+        let near_text = NearText::builder(vec!["ラーメン", "🍜"]).build();
+        assert_eq!("{concepts: [\"ラーメン\", \"🍜\"]}", near_text.to_string());
+    }
+
+    #[test]
+    fn test_near_text() {
+        let near_text = NearText::builder(vec!["New Yorker", "magazine"])
+            .with_certainty(0.7)
+            .build();
+        assert_eq!(
+            "{certainty: 0.7, concepts: [\"New Yorker\", \"magazine\"]}",
+            near_text.to_string()
+        );
+
+        // the last of certainty and distance set takes precedence
+        let near_text = NearText::builder(vec!["New Yorker"])
+            .with_certainty(0.7)
+            .with_distance(0.3)
+            .build();
+        assert_eq!(None, near_text.certainty);
+        assert_eq!(
+            "{concepts: [\"New Yorker\"], distance: 0.3}",
+            near_text.to_string()
+        );
+
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_near_text(near_text.clone())
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearText: {concepts: [\"New Yorker\"], distance: 0.3}"));
+        let query = AggregateBuilder::new("Article")
+            .with_near_text(near_text)
+            .with_meta_count()
+            .build();
+        assert!(query
+            .query
+            .contains("nearText: {concepts: [\"New Yorker\"], distance: 0.3}"));
+    }
+
+    #[test]
+    fn test_get_builder_page_depth() {
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_offset(9_900)
+            .with_limit(100)
+            .build();
+        assert!(res.is_ok());
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_offset(9_901)
+            .with_limit(100)
+            .build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_query_maximum_results(10_000)
+            .with_offset(9_901)
+            .with_limit(100)
+            .build();
+        match res {
+            Err(WeaviateError::InvalidRequest(msg)) => assert!(msg.contains("10001")),
+            _ => panic!("expected an InvalidRequest error"),
+        }
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_query_maximum_results(20_000)
+            .with_offset(9_901)
+            .with_limit(100)
+            .build();
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_target_vectors() {
+        let near_text = NearText::builder(vec!["New Yorker"])
+            .with_target_vectors(vec!["title", "body"])
+            .build();
+        assert_eq!(
+            "{concepts: [\"New Yorker\"], targetVectors: [\"title\", \"body\"]}",
+            near_text.to_string()
+        );
+        let near_image = NearMedia::builder(MediaType::IMAGE, "iVBORw0KGgoAAAANS")
+            .with_target_vectors(vec!["image"])
+            .build();
+        assert_eq!(
+            "{image: \"iVBORw0KGgoAAAANS\", targetVectors: [\"image\"]}",
+            near_image.to_string()
+        );
+
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_near_vector(NearVector::builder(vec![0.1, 0.2]).build())
+            .with_target_vectors(vec!["title"])
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearVector: {vector: [0.1, 0.2], targetVectors: [\"title\"]}"));
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_target_vectors(vec!["title"])
+            .build();
+        assert!(res.is_err());
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_near_text(near_text)
+            .with_target_vectors(vec!["title"])
+            .build();
+        match res {
+            Err(WeaviateError::InvalidRequest(msg)) => assert!(msg.contains("nearText")),
+            _ => panic!("expected an InvalidRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_near_vector() {
+        let near_vector = NearVector::builder(vec![0.1, -0.25])
+            .with_distance(0.3)
+            .with_certainty(0.8)
+            .build();
+        assert_eq!(None, near_vector.distance);
+        assert_eq!(
+            "{certainty: 0.8, vector: [0.1, -0.25]}",
+            near_vector.to_string()
+        );
+
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_near_vector(near_vector.clone())
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearVector: {certainty: 0.8, vector: [0.1, -0.25]}"));
+        let query = ExploreBuilder::new()
+            .with_near_vector(near_vector)
+            .with_fields(vec!["className"])
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearVector: {certainty: 0.8, vector: [0.1, -0.25]}"));
+    }
+
+    #[test]
+    fn test_near_object_id() {
+        let id = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+        let near_object = NearObject::builder()
+            .with_id(id)
+            .with_distance(0.3)
+            .build()
+            .unwrap();
+        assert_eq!(
+            "{distance: 0.3, id: \"ee22d1b8-3b95-4e94-96d5-9a2b60fbd303\"}",
+            near_object.to_string()
+        );
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_near_object(near_object.clone())
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearObject: {distance: 0.3, id: \"ee22d1b8-3b95-4e94-96d5-9a2b60fbd303\"}"));
+        let query = AggregateBuilder::new("Article")
+            .with_near_object(near_object)
+            .with_meta_count()
+            .build();
+        assert!(query.query.contains("nearObject: {distance: 0.3, id: "));
+    }
+
+    #[test]
+    fn test_aggregate_fields_numeric() {
+        let query = AggregateBuilder::new("Article")
+            .with_meta_count()
+            .with_aggregate_fields(vec![AggregateField::property("wordCount")
+                .count()
+                .minimum()
+                .maximum()
+                .mean()
+                .median()
+                .mode()
+                .sum()
+                .data_type()])
+            .unwrap()
+            .build();
+        assert!(query.query.contains(
+            "      meta{count}\n      wordCount { count minimum maximum mean median mode sum type }\n"
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_fields_text() {
+        let query = AggregateBuilder::new("Article")
+            .with_fields(vec!["wordCount { mean }"])
+            .with_aggregate_fields(vec![
+                AggregateField::property("title")
+                    .count()
+                    .top_occurrences(Some(5)),
+                AggregateField::property("summary").top_occurrences(None),
+            ])
+            .unwrap()
+            .with_group_by(vec!["inPublication"])
+            .build();
+        assert!(query.query.contains("      groupBy: [\"inPublication\"]\n"));
+        assert!(query.query.contains(
+            "      groupedBy { value path }\n      wordCount { mean } \
+            title { count topOccurrences(limit: 5) { value occurs } } \
+            summary { topOccurrences { value occurs } }\n"
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_fields_empty_selection() {
+        let err = AggregateBuilder::new("Article")
+            .with_aggregate_fields(vec![
+                AggregateField::property("wordCount").mean(),
+                AggregateField::property("title"),
+            ])
+            .unwrap_err();
+        assert!(err.status.is_none());
+        assert!(err.message.contains("`title`"));
+    }
+
+    #[test]
+    fn test_aggregate_group_by_filter_same_body() {
+        let typed = AggregateBuilder::new("Article")
+            .with_group_by(vec!["inPublication"])
+            .with_meta_count()
+            .build();
+        let raw = AggregateBuilder::new("Article")
+            .with_group_by_filter("[\"inPublication\"]")
+            .with_meta_count()
+            .build();
+        assert_eq!(typed.query, raw.query);
+
+        let selected = AggregateBuilder::new("Article")
+            .with_group_by_filter("[\"inPublication\"]")
+            .with_fields(vec!["groupedBy {value path}"])
+            .build();
+        assert_eq!(1, selected.query.matches("groupedBy").count());
+    }
+
+    #[test]
+    fn test_aggregate_group_limit_and_object_limit() {
+        let query = AggregateBuilder::new("Article")
+            .with_near_text(NearText::builder(vec!["fashion"]).build())
+            .with_object_limit(100)
+            .with_group_by(vec!["inPublication"])
+            .with_group_limit(5)
+            .with_meta_count()
+            .build();
+        assert!(query.query.contains("      objectLimit: 100\n"));
+        assert!(query.query.contains("      limit: 5\n"));
+        assert!(query.query.contains("      groupBy: [\"inPublication\"]\n"));
+
+        let query = AggregateBuilder::new("Article")
+            .with_group_by(vec!["inPublication"])
+            .with_group_limit(5)
+            .build();
+        assert!(query.query.contains("      limit: 5\n"));
+        assert!(!query.query.contains("objectLimit"));
+    }
+
+    #[test]
+    fn test_near_object_beacon() {
+        let near_object = NearObject::builder()
+            .with_beacon("weaviate://localhost/Article/ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .with_certainty(0.7)
+            .build()
+            .unwrap();
+        assert_eq!(
+            "{beacon: \"weaviate://localhost/Article/ee22d1b8-3b95-4e94-96d5-9a2b60fbd303\", certainty: 0.7}",
+            near_object.to_string()
+        );
+    }
+
+    #[test]
+    fn test_near_object_requires_one_target() {
+        let res = NearObject::builder().build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+        let res = NearObject::builder()
+            .with_id(Uuid::new_v4())
+            .with_beacon("weaviate://localhost/Article/ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_near_media_from_bytes() {
+        let near_image = NearMediaBuilder::from_bytes(MediaType::IMAGE, b"hello weaviate")
+            .with_certainty(0.7)
+            .build();
+        assert_eq!("aGVsbG8gd2VhdmlhdGU=", near_image.content);
+        assert_eq!(
+            "{certainty: 0.7, image: \"aGVsbG8gd2VhdmlhdGU=\"}",
+            near_image.to_string()
+        );
+        let query = GetBuilder::new("Dog", vec!["breed"])
+            .with_near_media(near_image.clone())
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("nearImage: {certainty: 0.7, image: \"aGVsbG8gd2VhdmlhdGU=\"}"));
+
+        let near_audio = NearMedia::builder(MediaType::AUDIO, "UklGRg==")
+            .with_distance(0.3)
+            .build();
+        let query = AggregateBuilder::new("Dog")
+            .with_near_media(near_audio)
+            .with_meta_count()
+            .build();
+        assert!(query
+            .query
+            .contains("nearAudio: {audio: \"UklGRg==\", distance: 0.3}"));
+
+        let near_video = NearMedia::builder(MediaType::VIDEO, "AAAAIGZ0eXA=").build();
+        let query = GetBuilder::new("Dog", vec!["breed"])
+            .with_near_media(near_video)
+            .build()
+            .unwrap();
+        assert!(query.query.contains("nearVideo: {video: \"AAAAIGZ0eXA=\"}"));
+    }
+
+    #[test]
+    fn test_get_builder_autocut_requires_operator() {
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_autocut(1)
+            .build();
+        match res {
+            Err(WeaviateError::InvalidRequest(msg)) => assert!(msg.contains("autocut")),
+            _ => panic!("expected an InvalidRequest error"),
+        }
+        assert!(GetBuilder::new("Article", vec!["title"])
+            .with_autocut(1)
+            .with_bm25("{query: \"food\"}")
+            .build()
+            .is_ok());
+        assert!(GetBuilder::new("Article", vec!["title"])
+            .with_autocut(1)
+            .with_near_text(NearText::builder(vec!["food"]).build())
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_string_filters_quoted() {
+        let query = AggregateBuilder::new("Article")
+            .with_group_by(vec!["inPublication"])
+            .with_group_limit(10)
+            .with_tenant("tenantA")
+            .with_meta_count()
+            .build();
+        assert!(query.query.contains("      tenant: \"tenantA\"\n"));
+        assert!(query.query.contains("      limit: 10\n"));
+
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_limit(10)
+            .with_tenant("tenantA")
+            .build()
+            .unwrap();
+        assert!(query.query.contains("      tenant: \"tenantA\"\n"));
+
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_tenant("租户_ü")
+            .build()
+            .unwrap();
+        assert!(query.query.contains("      tenant: \"租户_ü\"\n"));
+        assert_eq!("\"tenant\\\"A\\n\"", super::graphql_string("tenant\"A\n"));
+    }
+
+    #[test]
+    fn test_aggregate_builder_filter_values() {
+        let query = AggregateBuilder::new("Article")
+            .with_where("{path: [\"wordCount\"], operator: GreaterThan, valueInt: 10}")
+            .with_near_vector(NearVector::builder(vec![0.1]).build())
+            .with_object_limit(100)
+            .with_group_by(vec!["inPublication"])
+            .with_group_limit(5)
+            .with_tenant("tenantA")
+            .with_meta_count()
+            .build();
+        assert!(query.query.contains(
+            "      where: {path: [\"wordCount\"], operator: GreaterThan, valueInt: 10}\n"
+        ));
+        assert!(query.query.contains("      groupBy: [\"inPublication\"]\n"));
+        assert!(query.query.contains("      nearVector: {vector: [0.1]}\n"));
+        assert!(query.query.contains("      objectLimit: 100\n"));
+        assert!(query.query.contains("      limit: 5\n"));
+        assert!(query.query.contains("      tenant: \"tenantA\"\n"));
+    }
+
+    #[test]
+    fn test_get_builder_filter_values() {
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_where("{path: [\"wordCount\"], operator: GreaterThan, valueInt: 10}")
+            .with_limit(10)
+            .with_offset(20)
+            .with_autocut(2)
+            .with_bm25("{query: \"food\"}")
+            .with_tenant("tenantA")
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "      where: {path: [\"wordCount\"], operator: GreaterThan, valueInt: 10}\n"
+        ));
+        assert!(query.query.contains("      limit: 10\n"));
+        assert!(query.query.contains("      offset: 20\n"));
+        assert!(query.query.contains("      autocut: 2\n"));
+        assert!(query.query.contains("      tenant: \"tenantA\"\n"));
+
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_near_text(NearText::builder(vec!["animals"]).build())
+            .with_group_by("{path: [\"round\"], groups: 2, objectsPerGroup: 3}")
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("      groupBy: {path: [\"round\"], groups: 2, objectsPerGroup: 3}\n"));
+    }
+
+    #[test]
+    fn test_get_builder_group_by_only() {
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_group_by("{path: [\"round\"], groups: 2, objectsPerGroup: 3}")
+            .build()
+            .unwrap();
+        assert!(query.query.contains(
+            "    (\n      groupBy: {path: [\"round\"], groups: 2, objectsPerGroup: 3}\n    )\n"
+        ));
+    }
+
+    #[test]
+    fn test_explore_builder_filter_values() {
+        let query = ExploreBuilder::new()
+            .with_limit(3)
+            .with_near_text(NearText::builder(vec!["animals"]).build())
+            .with_fields(vec!["className"])
+            .build()
+            .unwrap();
+        assert!(query.query.contains("    limit: 3\n"));
+        assert!(query
+            .query
+            .contains("    nearText: {concepts: [\"animals\"]}\n"));
+    }
+
+    #[test]
+    fn test_get_builder_after_quoted() {
+        let after = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("      after: \"ee22d1b8-3b95-4e94-96d5-9a2b60fbd303\"\n"));
+
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_sort("{path: [\"points\"], order: desc}")
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("      sort: {path: [\"points\"], order: desc}\n"));
+
+        let ask = Ask::builder("Who wrote \"Dune\"?").build();
+        let query = GetBuilder::new("Article", vec!["title"])
+            .with_ask(ask)
+            .build()
+            .unwrap();
+        assert!(query
+            .query
+            .contains("      ask: {question: \"Who wrote \\\"Dune\\\"?\"}\n"));
+    }
+
+    #[test]
+    fn test_get_builder_after_conflicts() {
+        let after = Uuid::new_v4();
+        assert!(GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .build()
+            .is_ok());
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .with_where("{path: [\"wordCount\"], operator: GreaterThan, valueInt: 10}")
+            .build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("where")));
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .with_near_vector(NearVector::builder(vec![0.1]).build())
+            .build();
+        assert!(
+            matches!(res, Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("near<Media>"))
+        );
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .with_sort("{path: [\"title\"]}")
+            .build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("sort")));
+
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_after(after)
+            .with_bm25("{query: \"food\"}")
+            .with_hybrid("{query: \"food\"}")
+            .with_offset(10)
+            .build();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("bm25, hybrid, offset")
+        ));
+    }
+
+    #[test]
+    fn test_get_builder_single_near_filter() {
+        let res = GetBuilder::new("Article", vec!["title"])
+            .with_near_text(NearText::builder(vec!["food"]).build())
+            .with_near_vector(NearVector::builder(vec![0.1]).build())
+            .build();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("nearText, nearVector")
+        ));
+    }
+
+    #[test]
+    fn test_get_builder_hybrid_with_where() {
+        let filter = WhereFilter::builder(vec!["points"], FilterOperator::GREATERTHAN)
+            .with_value_int(200)
+            .build();
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_hybrid("{query: \"food\", alpha: 0.5}")
+            .with_where(&filter.to_string())
+            .with_limit(3)
+            .build()
+            .unwrap();
+        assert_eq!(
+            format!(
+                "{{\n  Get {{\n    JeopardyQuestion \n    (\n      where: {}\n      \
+                 limit: 3\n      hybrid: {{query: \"food\", alpha: 0.5}}\n    )\n    {{\n      \
+                 question\n    }}\n  }}\n}}",
+                filter
+            ),
+            query.query
+        );
+
+        assert!(GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_bm25("{query: \"food\"}")
+            .with_where(&filter.to_string())
+            .build()
+            .is_ok());
+        let res = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_hybrid("{query: \"food\"}")
+            .with_bm25("{query: \"food\"}")
+            .build();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("bm25, hybrid")
+        ));
+        let res = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .with_near_text(NearText::builder(vec!["food"]).build())
+            .with_hybrid("{query: \"food\"}")
+            .build();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::InvalidRequest(msg)) if msg.ends_with("nearText, hybrid")
+        ));
+    }
+
+    #[test]
+    fn test_explore_builder_requires_near() {
+        let res = ExploreBuilder::new().with_limit(1).build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_where_filter_graphql() {
+        let filter = WhereFilter::and(vec![
+            WhereFilter::builder(vec!["name"], FilterOperator::EQUAL)
+                .with_value_text("test")
+                .build(),
+            WhereFilter::builder(vec!["wordCount"], FilterOperator::GREATERTHAN)
+                .with_value_int(10)
+                .build(),
+        ]);
+        assert_eq!(
+            "{operands: [{operator: Equal, path: [\"name\"], valueText: \"test\"}, \
+            {operator: GreaterThan, path: [\"wordCount\"], valueInt: 10}], operator: And}",
+            filter.to_string()
+        );
+    }
+
+    #[test]
+    fn test_where_filter_validate_against() {
+        let class = Class::builder("Article")
+            .with_properties(Properties::new(vec![
+                Property::builder("title", vec!["text"]).build(),
+                Property::builder("wordCount", vec!["int"]).build(),
+                Property::builder("tags", vec!["text[]"]).build(),
+                Property::builder("inPublication", vec!["Publication"]).build(),
+            ]))
+            .build();
+
+        let valid = WhereFilter::and(vec![
+            WhereFilter::builder(vec!["title"], FilterOperator::LIKE)
+                .with_value_text("*news*")
+                .build(),
+            WhereFilter::builder(vec!["wordCount"], FilterOperator::GREATERTHAN)
+                .with_value_int(10)
+                .build(),
+            WhereFilter::builder(vec!["len(tags)"], FilterOperator::LESSTHAN)
+                .with_value_int(3)
+                .build(),
+            WhereFilter::builder(
+                vec!["inPublication", "Publication", "name"],
+                FilterOperator::EQUAL,
+            )
+            .with_value_text("Wired")
+            .build(),
+            WhereFilter::builder(vec!["id"], FilterOperator::EQUAL)
+                .with_value_text("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+                .build(),
+        ]);
+        assert!(valid.validate_against(&class).is_ok());
+
+        let invalid = WhereFilter::or(vec![
+            WhereFilter::builder(vec!["author"], FilterOperator::EQUAL)
+                .with_value_text("test")
+                .build(),
+            WhereFilter::builder(vec!["title"], FilterOperator::GREATERTHAN)
+                .with_value_text("a")
+                .build(),
+            WhereFilter::builder(vec!["title", "Other", "name"], FilterOperator::EQUAL)
+                .with_value_text("a")
+                .build(),
+        ]);
+        let err = invalid.validate_against(&class).unwrap_err();
+        let msg = match err {
+            WeaviateError::InvalidRequest(msg) => msg,
+            _ => panic!("expected an InvalidRequest, got {:?}", err),
+        };
+        assert!(msg.contains("property `author` does not exist"));
+        assert!(msg.contains("operator GreaterThan can't be used on property `title` of type text"));
+        assert!(msg.contains("property `title` is not a cross-reference"));
+    }
 
     #[test]
     fn test_get_query_builder() {
@@ -1162,6 +4782,6 @@ mod tests {
         //)
         //.with_limit(1)
         //.with_offset(1);
-        //println!("{}", query.build());
+        //println!("{}", query.build().unwrap());
     }
 }
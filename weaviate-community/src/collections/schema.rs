@@ -1,9 +1,11 @@
 /// All schema associated type components
 /// https://weaviate.io/developers/weaviate/config-refs/schema#auto-schema
+use crate::collections::error::WeaviateError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Storage for multiple classes.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Classes {
     pub classes: Vec<Class>,
 }
@@ -28,10 +30,145 @@ impl Classes {
     pub fn new(classes: Vec<Class>) -> Classes {
         Classes { classes }
     }
+
+    /// Find a class by name, capitalizing the first letter as Weaviate does.
+    pub(crate) fn find_class(&self, class_name: &str) -> Option<&Class> {
+        let class_name = capitalize(class_name);
+        self.classes
+            .iter()
+            .find(|c| capitalize(&c.class) == class_name)
+    }
+}
+
+/// The changes required to bring a live schema in line with a desired schema, computed by
+/// `Schema::diff` and applied with `Schema::apply`.
+///
+/// Weaviate can't drop properties, so only added properties are tracked. Removed classes, and
+/// existing classes whose configuration differs from the desired class, are reported, but never
+/// changed by `Schema::apply`.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDiff {
+    pub added_classes: Vec<Class>,
+    pub removed_classes: Vec<String>,
+    pub added_properties: BTreeMap<String, Vec<Property>>,
+    pub changed_classes: Vec<String>,
+}
+
+impl SchemaDiff {
+    /// Compute the difference between the current and desired schemas.
+    ///
+    /// Classes are matched by name with the first letter capitalized, as Weaviate stores them, so
+    /// a desired `article` matches a live `Article`. Existing classes are compared with
+    /// `Class::semantically_equals`, so the defaults Weaviate fills in are not reported as
+    /// changes.
+    ///
+    /// # Parameters
+    /// - current: the schema as it currently exists
+    /// - desired: the schema that should exist
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{Class, Classes, SchemaDiff};
+    ///
+    /// let current = Classes::new(vec![Class::builder("Journal").build()]);
+    /// let desired = Classes::new(vec![Class::builder("Article").build()]);
+    /// let diff = SchemaDiff::new(&current, &desired);
+    /// assert_eq!("Article", diff.added_classes[0].class);
+    /// assert_eq!(vec!["Journal"], diff.removed_classes);
+    /// ```
+    pub fn new(current: &Classes, desired: &Classes) -> SchemaDiff {
+        let mut diff = SchemaDiff::default();
+        for class in &desired.classes {
+            match current.find_class(&class.class) {
+                None => diff.added_classes.push(class.clone()),
+                Some(existing) if class.semantically_equals(existing) => {}
+                Some(existing) => {
+                    let added: Vec<Property> = class
+                        .properties
+                        .iter()
+                        .flat_map(|properties| properties.0.iter())
+                        .filter(|property| {
+                            !existing
+                                .properties
+                                .iter()
+                                .flat_map(|properties| properties.0.iter())
+                                .any(|p| p.name == property.name)
+                        })
+                        .cloned()
+                        .collect();
+                    let mut patched = existing.clone();
+                    if !added.is_empty() {
+                        let mut properties =
+                            patched.properties.take().unwrap_or(Properties(Vec::new()));
+                        properties.0.extend(added.iter().cloned());
+                        patched.properties = Some(properties);
+                        diff.added_properties.insert(existing.class.clone(), added);
+                    }
+                    if !class.semantically_equals(&patched) {
+                        diff.changed_classes.push(existing.class.clone());
+                    }
+                }
+            }
+        }
+        diff.removed_classes = current
+            .classes
+            .iter()
+            .filter(|class| desired.find_class(&class.class).is_none())
+            .map(|class| class.class.clone())
+            .collect();
+        diff
+    }
+
+    /// Check whether the schemas are already in sync.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{Class, Classes, SchemaDiff};
+    ///
+    /// let classes = Classes::new(vec![Class::builder("Article").build()]);
+    /// assert!(SchemaDiff::new(&classes, &classes).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.added_classes.is_empty()
+            && self.removed_classes.is_empty()
+            && self.added_properties.is_empty()
+            && self.changed_classes.is_empty()
+    }
+}
+
+impl std::fmt::Display for SchemaDiff {
+    /// Render the diff one change per line, for example
+    /// ```text
+    /// + class Article
+    /// + property Journal.title
+    /// ~ class Publication
+    /// - class Magazine
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no changes");
+        }
+        let mut lines = Vec::new();
+        for class in &self.added_classes {
+            lines.push(format!("+ class {}", class.class));
+        }
+        for (class_name, properties) in &self.added_properties {
+            for property in properties {
+                lines.push(format!("+ property {}.{}", class_name, property.name));
+            }
+        }
+        for class_name in &self.changed_classes {
+            lines.push(format!("~ class {}", class_name));
+        }
+        for class_name in &self.removed_classes {
+            lines.push(format!("- class {}", class_name));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
 }
 
 /// Full class definition and configuration options.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Class {
     pub class: String,
@@ -84,6 +221,107 @@ impl Class {
     pub fn builder(class_name: &str) -> ClassBuilder {
         ClassBuilder::new(class_name)
     }
+
+    /// Check whether this class describes the class returned by Weaviate.
+    ///
+    /// Weaviate fills in a default for every option a new class leaves unset, so a class read
+    /// back from the server never equals the class it was created from. Instead, `self` should be
+    /// the desired class and `other` the class returned by the server: every option set on `self`
+    /// must match `other`, while options left unset are taken to be server defaults and ignored,
+    /// as are read-only fields such as `actualCount`.
+    ///
+    /// Properties are matched by name, and both classes must have the same properties. Leaving
+    /// the properties unset ignores them altogether. The class names are compared the way
+    /// Weaviate stores them, with the first letter capitalized.
+    ///
+    /// # Parameters
+    /// - other: the class returned by Weaviate
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::Class;
+    ///
+    /// let desired = Class::builder("Article").with_vectorizer("none").build();
+    /// let live: Class = serde_json::from_value(serde_json::json!({
+    ///     "class": "Article",
+    ///     "vectorizer": "none",
+    ///     "vectorIndexType": "hnsw",
+    ///     "replicationConfig": {"factor": 1}
+    /// }))
+    /// .unwrap();
+    /// assert!(desired.semantically_equals(&live));
+    /// ```
+    pub fn semantically_equals(&self, other: &Class) -> bool {
+        if capitalize(&self.class) != capitalize(&other.class) {
+            return false;
+        }
+        let desired = serde_json::to_value(self);
+        let actual = serde_json::to_value(other);
+        let (mut desired, mut actual) = match (desired, actual) {
+            (Ok(serde_json::Value::Object(d)), Ok(serde_json::Value::Object(a))) => (d, a),
+            _ => return false,
+        };
+        desired.remove("class");
+        actual.remove("class");
+        if let Some(properties) = desired.remove("properties") {
+            let live_properties = actual.remove("properties").unwrap_or_default();
+            if !properties_match(&properties, &live_properties) {
+                return false;
+            }
+        }
+        is_subset(&desired.into(), &actual.into())
+    }
+}
+
+/// The fields that are set by Weaviate and ignored when comparing classes.
+const READ_ONLY_FIELDS: &[&str] = &["actualCount", "actualVirtualCount"];
+
+/// Capitalize the first letter of a class name, as Weaviate does when storing the class.
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Check the serialized properties of two classes have the same names, and that each desired
+/// property is a subset of the live property of the same name.
+fn properties_match(desired: &serde_json::Value, actual: &serde_json::Value) -> bool {
+    let empty = Vec::new();
+    let desired = desired.as_array().unwrap_or(&empty);
+    let actual = actual.as_array().unwrap_or(&empty);
+    desired.len() == actual.len()
+        && desired.iter().all(|property| {
+            actual
+                .iter()
+                .find(|p| p["name"] == property["name"])
+                .is_some_and(|p| is_subset(property, p))
+        })
+}
+
+/// Check every value set in `desired` is also set to the same value in `actual`.
+///
+/// Objects may hold extra keys in `actual`, which are the defaults filled in by Weaviate, while
+/// arrays must match element by element. Numbers are compared by value, so `1` equals `1.0`.
+pub(crate) fn is_subset(desired: &serde_json::Value, actual: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    match (desired, actual) {
+        (Value::Null, _) => true,
+        (Value::Object(desired), Value::Object(actual)) => desired
+            .iter()
+            .filter(|(key, _)| !READ_ONLY_FIELDS.contains(&key.as_str()))
+            .all(|(key, value)| match actual.get(key) {
+                Some(actual) => is_subset(value, actual),
+                None => value.is_null(),
+            }),
+        (Value::Array(desired), Value::Array(actual)) => {
+            desired.len() == actual.len()
+                && desired.iter().zip(actual).all(|(d, a)| is_subset(d, a))
+        }
+        (Value::Number(desired), Value::Number(actual)) => desired.as_f64() == actual.as_f64(),
+        _ => desired == actual,
+    }
 }
 
 /// ClassBuilder for building new classes
@@ -371,11 +609,19 @@ impl ClassBuilder {
 
 /// Strict definitions of Vector Index types.
 ///
-/// Currently Weaviate only supports HNSW.
-#[derive(Serialize, Deserialize, Debug)]
+/// Currently supports the following:
+/// - HNSW
+/// - Flat
+/// - Dynamic, which starts as a flat index and switches to HNSW once the number of objects
+///   passes the threshold set in `DynamicIndexConfig`
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum VectorIndexType {
     #[serde(rename = "hnsw")]
     HNSW,
+    #[serde(rename = "flat")]
+    FLAT,
+    #[serde(rename = "dynamic")]
+    DYNAMIC,
 }
 
 /// Controls default for Class vector_index_type
@@ -384,7 +630,7 @@ fn default_vector_index_type() -> Option<VectorIndexType> {
 }
 
 /// Wrapper for multiple properties
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Properties(pub Vec<Property>);
 
@@ -409,7 +655,7 @@ impl Properties {
 }
 
 /// Configuration options for a property
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Property {
     pub name: String,
@@ -431,7 +677,13 @@ pub struct Property {
     pub index_searchable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
+    pub index_range_filters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
     pub inverted_index_config: Option<InvertedIndexConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nested_properties: Option<Vec<Property>>,
 }
 
 impl Property {
@@ -466,7 +718,9 @@ pub struct PropertyBuilder {
     pub module_config: Option<serde_json::Value>,
     pub index_filterable: Option<bool>,
     pub index_searchable: Option<bool>,
+    pub index_range_filters: Option<bool>,
     pub inverted_index_config: Option<InvertedIndexConfig>,
+    pub nested_properties: Option<Vec<Property>>,
 }
 
 impl PropertyBuilder {
@@ -496,8 +750,114 @@ impl PropertyBuilder {
             module_config: None,
             index_filterable: None,
             index_searchable: None,
+            index_range_filters: None,
             inverted_index_config: None,
+            nested_properties: None,
+        }
+    }
+
+    /// Set the data types of the property using the strictly typed `DataType`.
+    ///
+    /// This replaces any data types set when creating the builder. Only cross-reference
+    /// properties can have more than one data type.
+    ///
+    /// # Parameters
+    /// - data_types: the data types of the property
+    ///
+    /// # Errors
+    /// Returns a `WeaviateError::InvalidRequest` if multiple data types are given and any of them
+    /// is not a cross-reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{DataType, PropertyBuilder};
+    ///
+    /// let property = PropertyBuilder::new("tags", vec![])
+    ///     .with_data_types(vec![DataType::TEXTARRAY])
+    ///     .unwrap()
+    ///     .build();
+    /// assert_eq!(vec!["text[]"], property.data_type);
+    ///
+    /// let property = PropertyBuilder::new("inPublication", vec![])
+    ///     .with_data_types(vec![
+    ///         DataType::CrossReference("Publication".into()),
+    ///         DataType::CrossReference("Magazine".into()),
+    ///     ])
+    ///     .unwrap()
+    ///     .build();
+    /// assert_eq!(vec!["Publication", "Magazine"], property.data_type);
+    ///
+    /// assert!(PropertyBuilder::new("title", vec![])
+    ///     .with_data_types(vec![DataType::TEXT, DataType::INT])
+    ///     .is_err());
+    /// ```
+    pub fn with_data_types(
+        mut self,
+        data_types: Vec<DataType>,
+    ) -> Result<PropertyBuilder, WeaviateError> {
+        if data_types.len() > 1
+            && data_types
+                .iter()
+                .any(|dt| !matches!(dt, DataType::CrossReference(_)))
+        {
+            return Err(WeaviateError::InvalidRequest(format!(
+                "property `{}` can only have multiple data types if they are all cross-references",
+                self.name
+            )));
+        }
+        self.data_type = data_types.iter().map(|dt| dt.value().to_string()).collect();
+        Ok(self)
+    }
+
+    /// Mark the property as a cross-reference to one or more target classes.
+    ///
+    /// This replaces any data types set when creating the builder. Each target must be a valid
+    /// class name, starting with an uppercase letter and containing only alphanumeric characters
+    /// or underscores, so that scalar data types such as `text` can't be passed by mistake.
+    ///
+    /// # Parameters
+    /// - classes: the names of the classes the property references
+    ///
+    /// # Errors
+    /// Returns a `WeaviateError::InvalidRequest` if no target is given or a target is not a valid
+    /// class name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::PropertyBuilder;
+    ///
+    /// let property = PropertyBuilder::new("inPublication", vec![])
+    ///     .with_reference_to(vec!["Publication", "Magazine"])
+    ///     .unwrap()
+    ///     .build();
+    /// assert_eq!(vec!["Publication", "Magazine"], property.data_type);
+    ///
+    /// assert!(PropertyBuilder::new("inPublication", vec![]).with_reference_to(vec![]).is_err());
+    /// ```
+    pub fn with_reference_to(
+        mut self,
+        classes: Vec<&str>,
+    ) -> Result<PropertyBuilder, WeaviateError> {
+        if classes.is_empty() {
+            return Err(WeaviateError::InvalidRequest(format!(
+                "cross-reference property `{}` requires at least one target class",
+                self.name
+            )));
         }
+        let invalid: Vec<&str> = classes
+            .iter()
+            .filter(|class| !is_valid_class_name(class))
+            .copied()
+            .collect();
+        if !invalid.is_empty() {
+            return Err(WeaviateError::InvalidRequest(format!(
+                "cross-reference property `{}` has invalid target classes: {}",
+                self.name,
+                invalid.join(", ")
+            )));
+        }
+        self.data_type = classes.iter().map(|class| class.to_string()).collect();
+        Ok(self)
     }
 
     /// Add a value to the optional `description` value of the property.
@@ -590,6 +950,26 @@ impl PropertyBuilder {
         self
     }
 
+    /// Add a value to the optional `index_range_filters` value of the property.
+    ///
+    /// Enables the range index for `int`, `number` and `date` properties, making range queries
+    /// (such as `GreaterThan`) more efficient.
+    ///
+    /// # Parameters
+    /// - index_range_filters: the index_range_filters to use for the property
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::PropertyBuilder;
+    ///
+    /// let builder = PropertyBuilder::new("wordCount", vec!["int"])
+    ///     .with_index_range_filters(true);
+    /// ```
+    pub fn with_index_range_filters(mut self, index_range_filters: bool) -> PropertyBuilder {
+        self.index_range_filters = Some(index_range_filters);
+        self
+    }
+
     /// Add a value to the optional `inverted_index_config` value of the property.
     ///
     /// # Parameters
@@ -611,6 +991,31 @@ impl PropertyBuilder {
         self
     }
 
+    /// Add a value to the optional `nested_properties` value of the property.
+    ///
+    /// Only applicable to properties with the `object` or `object[]` data types, and describes
+    /// the properties of the nested object.
+    ///
+    /// # Parameters
+    /// - nested_properties: the properties of the nested object
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{DataType, Property, PropertyBuilder};
+    ///
+    /// let builder = PropertyBuilder::new("author", vec![])
+    ///     .with_data_types(vec![DataType::OBJECT])
+    ///     .unwrap()
+    ///     .with_nested_properties(vec![
+    ///         Property::builder("name", vec!["text"]).build(),
+    ///         Property::builder("age", vec!["int"]).build(),
+    ///     ]);
+    /// ```
+    pub fn with_nested_properties(mut self, nested_properties: Vec<Property>) -> PropertyBuilder {
+        self.nested_properties = Some(nested_properties);
+        self
+    }
+
     /// Build the Property from the PropertyBuilder
     ///
     /// # Example
@@ -636,13 +1041,87 @@ impl PropertyBuilder {
             module_config: self.module_config,
             index_filterable: self.index_filterable,
             index_searchable: self.index_searchable,
+            index_range_filters: self.index_range_filters,
             inverted_index_config: self.inverted_index_config,
+            nested_properties: self.nested_properties,
+        }
+    }
+}
+
+/// Check that a name is a valid Weaviate class name, matching `^[A-Z][_0-9A-Za-z]*$`.
+fn is_valid_class_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_uppercase() => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Strict definitions of the property data types.
+///
+/// Cross-references are defined using the name of the class being referenced.
+///
+/// More information on the data types can be found [here](https://weaviate.io/developers/weaviate/config-refs/datatypes)
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataType {
+    TEXT,
+    TEXTARRAY,
+    INT,
+    INTARRAY,
+    NUMBER,
+    NUMBERARRAY,
+    BOOLEAN,
+    BOOLEANARRAY,
+    DATE,
+    DATEARRAY,
+    UUID,
+    UUIDARRAY,
+    GEOCOORDINATES,
+    PHONENUMBER,
+    BLOB,
+    OBJECT,
+    OBJECTARRAY,
+    CrossReference(String),
+}
+
+impl DataType {
+    /// Get the value of the data type as expected by Weaviate.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DataType;
+    ///
+    /// assert_eq!("int[]", DataType::INTARRAY.value());
+    /// assert_eq!("Article", DataType::CrossReference("Article".into()).value());
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            DataType::TEXT => "text",
+            DataType::TEXTARRAY => "text[]",
+            DataType::INT => "int",
+            DataType::INTARRAY => "int[]",
+            DataType::NUMBER => "number",
+            DataType::NUMBERARRAY => "number[]",
+            DataType::BOOLEAN => "boolean",
+            DataType::BOOLEANARRAY => "boolean[]",
+            DataType::DATE => "date",
+            DataType::DATEARRAY => "date[]",
+            DataType::UUID => "uuid",
+            DataType::UUIDARRAY => "uuid[]",
+            DataType::GEOCOORDINATES => "geoCoordinates",
+            DataType::PHONENUMBER => "phoneNumber",
+            DataType::BLOB => "blob",
+            DataType::OBJECT => "object",
+            DataType::OBJECTARRAY => "object[]",
+            DataType::CrossReference(class_name) => class_name,
         }
     }
 }
 
 /// Configuration options for VectorIndexConfig
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct VectorIndexConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -681,6 +1160,8 @@ pub struct VectorIndexConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub skip: Option<bool>,
+    #[serde(flatten)]
+    pub dynamic: DynamicIndexConfig,
 }
 
 impl VectorIndexConfig {
@@ -714,6 +1195,7 @@ pub struct VectorIndexConfigBuilder {
     pub cleanup_interval_seconds: Option<u64>,
     pub pq: Option<PqConfig>,
     pub skip: Option<bool>,
+    pub dynamic: DynamicIndexConfig,
 }
 
 impl VectorIndexConfigBuilder {
@@ -741,6 +1223,7 @@ impl VectorIndexConfigBuilder {
             cleanup_interval_seconds: None,
             pq: None,
             skip: None,
+            dynamic: DynamicIndexConfig::default(),
         }
     }
 
@@ -949,6 +1432,28 @@ impl VectorIndexConfigBuilder {
         self
     }
 
+    /// Add the `dynamic` settings of the VectorIndexConfig.
+    ///
+    /// Only used when the class has a `VectorIndexType::DYNAMIC` vector index.
+    ///
+    /// # Parameters
+    /// - dynamic: the dynamic index config to use for the vector index config
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{
+    ///     DynamicIndexConfig,
+    ///     VectorIndexConfigBuilder,
+    /// };
+    ///
+    /// let dynamic = DynamicIndexConfig::builder().with_threshold(10_000).build();
+    /// let builder = VectorIndexConfigBuilder::new().with_dynamic(dynamic);
+    /// ```
+    pub fn with_dynamic(mut self, dynamic: DynamicIndexConfig) -> VectorIndexConfigBuilder {
+        self.dynamic = dynamic;
+        self
+    }
+
     /// Build the VectorIndexConfig from the VectorIndexConfigBuilder
     ///
     /// # Example
@@ -979,12 +1484,156 @@ impl VectorIndexConfigBuilder {
             cleanup_interval_seconds: self.cleanup_interval_seconds,
             pq: self.pq,
             skip: self.skip,
+            dynamic: self.dynamic,
+        }
+    }
+}
+
+/// The configuration options for a dynamic vector index.
+///
+/// A dynamic index starts as a flat index, and is switched to an HNSW index once the number of
+/// objects passes the `threshold`. These fields are serialized alongside the rest of the
+/// VectorIndexConfig, with the settings of each index nested under `hnsw` and `flat`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicIndexConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub threshold: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub hnsw: Option<Box<VectorIndexConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub flat: Option<Box<VectorIndexConfig>>,
+}
+
+impl DynamicIndexConfig {
+    /// Create a new builder for the DynamicIndexConfig object.
+    ///
+    /// This is the same as `DynamicIndexConfigBuilder::new()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DynamicIndexConfig;
+    ///
+    /// let builder = DynamicIndexConfig::builder();
+    /// ```
+    pub fn builder() -> DynamicIndexConfigBuilder {
+        DynamicIndexConfigBuilder::default()
+    }
+}
+
+/// DynamicIndexConfigBuilder for building a new DynamicIndexConfig
+#[derive(Default)]
+pub struct DynamicIndexConfigBuilder {
+    pub threshold: Option<u64>,
+    pub hnsw: Option<VectorIndexConfig>,
+    pub flat: Option<VectorIndexConfig>,
+}
+
+impl DynamicIndexConfigBuilder {
+    /// Create a new builder for the DynamicIndexConfig object.
+    ///
+    /// This is the same as `DynamicIndexConfig::builder()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DynamicIndexConfigBuilder;
+    ///
+    /// let builder = DynamicIndexConfigBuilder::new();
+    /// ```
+    pub fn new() -> DynamicIndexConfigBuilder {
+        DynamicIndexConfigBuilder {
+            threshold: None,
+            hnsw: None,
+            flat: None,
+        }
+    }
+
+    /// Add a value to the optional `threshold` value of the DynamicIndexConfig.
+    ///
+    /// # Parameters
+    /// - threshold: the number of objects at which the flat index is switched to HNSW
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DynamicIndexConfigBuilder;
+    ///
+    /// let builder = DynamicIndexConfigBuilder::new().with_threshold(10_000);
+    /// ```
+    pub fn with_threshold(mut self, threshold: u64) -> DynamicIndexConfigBuilder {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Add a value to the optional `hnsw` value of the DynamicIndexConfig.
+    ///
+    /// # Parameters
+    /// - hnsw: the config to use once the index has switched to HNSW
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{
+    ///     DynamicIndexConfigBuilder,
+    ///     VectorIndexConfig,
+    /// };
+    ///
+    /// let hnsw = VectorIndexConfig::builder().with_ef_construction(128).build();
+    /// let builder = DynamicIndexConfigBuilder::new().with_hnsw(hnsw);
+    /// ```
+    pub fn with_hnsw(mut self, hnsw: VectorIndexConfig) -> DynamicIndexConfigBuilder {
+        self.hnsw = Some(hnsw);
+        self
+    }
+
+    /// Add a value to the optional `flat` value of the DynamicIndexConfig.
+    ///
+    /// # Parameters
+    /// - flat: the config to use while the index is flat
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{
+    ///     DynamicIndexConfigBuilder,
+    ///     VectorIndexConfig,
+    /// };
+    ///
+    /// let flat = VectorIndexConfig::builder().with_vector_cache_max_objects(1000).build();
+    /// let builder = DynamicIndexConfigBuilder::new().with_flat(flat);
+    /// ```
+    pub fn with_flat(mut self, flat: VectorIndexConfig) -> DynamicIndexConfigBuilder {
+        self.flat = Some(flat);
+        self
+    }
+
+    /// Build the DynamicIndexConfig from the DynamicIndexConfigBuilder
+    ///
+    /// # Example
+    /// Using DynamicIndexConfigBuilder
+    /// ```rust
+    /// use weaviate_community::collections::schema::DynamicIndexConfigBuilder;
+    ///
+    /// let config = DynamicIndexConfigBuilder::new().build();
+    /// ```
+    ///
+    /// Using DynamicIndexConfig
+    /// ```rust
+    /// use weaviate_community::collections::schema::DynamicIndexConfig;
+    ///
+    /// let config = DynamicIndexConfig::builder().build();
+    /// ```
+    pub fn build(self) -> DynamicIndexConfig {
+        DynamicIndexConfig {
+            threshold: self.threshold,
+            hnsw: self.hnsw.map(Box::new),
+            flat: self.flat.map(Box::new),
         }
     }
 }
 
 /// The configuration options for pq
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PqConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1189,7 +1838,7 @@ impl PqConfigBuilder {
 ///
 /// - distribution
 /// - encoder_type
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EncoderConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
@@ -1293,7 +1942,7 @@ impl EncoderConfigBuilder {
 /// Strict definitions of distributions.
 ///
 /// Currently, Weaviate only allows log-normal and normal for kmeans
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Distribution {
     #[serde(rename = "log-normal")]
     LOGNORMAL,
@@ -1304,7 +1953,7 @@ pub enum Distribution {
 /// Strict definitions of encoders.
 ///
 /// Currently only supports KMeans and Tile
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum EncoderType {
     #[serde(rename = "kmeans")]
     KMEANS,
@@ -1320,7 +1969,7 @@ pub enum EncoderType {
 /// - L2 squared
 /// - Hamming
 /// - Manhattan
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum DistanceMetric {
     #[serde(rename = "cosine")]
@@ -1335,8 +1984,55 @@ pub enum DistanceMetric {
     MANHATTAN,
 }
 
+impl DistanceMetric {
+    /// Check whether distance `a` is more similar than distance `b` under this metric.
+    ///
+    /// Weaviate reports every metric as a distance, where a lower value is more similar. For
+    /// `DOT` this is the negative dot product, so results may be negative.
+    ///
+    /// # Parameters
+    /// - a: the distance to check
+    /// - b: the distance to compare against
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DistanceMetric;
+    ///
+    /// assert!(DistanceMetric::COSINE.is_more_similar(0.1, 0.4));
+    /// assert!(DistanceMetric::DOT.is_more_similar(-12.0, -3.0));
+    /// ```
+    pub fn is_more_similar(&self, a: f32, b: f32) -> bool {
+        a < b
+    }
+
+    /// Convert a distance into a certainty between 0 and 1.
+    ///
+    /// Certainty is only defined for `COSINE`, where a distance in the range 0 to 2 maps to a
+    /// certainty of 1 to 0. Every other metric returns `None`.
+    ///
+    /// # Parameters
+    /// - distance: the distance reported by Weaviate
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::DistanceMetric;
+    ///
+    /// assert_eq!(Some(0.75), DistanceMetric::COSINE.certainty_from_distance(0.5));
+    /// assert_eq!(None, DistanceMetric::L2SQUARED.certainty_from_distance(0.5));
+    /// ```
+    pub fn certainty_from_distance(&self, distance: f32) -> Option<f32> {
+        match self {
+            DistanceMetric::COSINE => Some(1.0 - distance / 2.0),
+            DistanceMetric::DOT
+            | DistanceMetric::L2SQUARED
+            | DistanceMetric::HAMMING
+            | DistanceMetric::MANHATTAN => None,
+        }
+    }
+}
+
 /// The configuration options for ShardingConfig.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ShardingConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1588,7 +2284,7 @@ impl ShardingConfigBuilder {
 ///
 /// The default will usually be _ID, unless MultiTenancy is enabled, where the
 /// default will be an empty string.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ShardingKey {
     #[serde(rename = "_id")]
     _ID,
@@ -1600,7 +2296,7 @@ pub enum ShardingKey {
 ///
 /// The default will usually be HASH, unless MultiTenancy is enabled, where the
 /// default will be an empty string.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ShardingStrategy {
     #[serde(rename = "hash")]
     HASH,
@@ -1612,7 +2308,7 @@ pub enum ShardingStrategy {
 ///
 /// The default will usually be MURMUR3, unless MultiTenancy is enabled, where the
 /// default will be an empty string.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ShardingFunction {
     #[serde(rename = "murmur3")]
     MURMUR3,
@@ -1621,7 +2317,7 @@ pub enum ShardingFunction {
 }
 
 /// MultiTenancyConfig holds the configuration options for multi tenancy.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MultiTenancyConfig {
     pub enabled: bool,
 }
@@ -1644,7 +2340,7 @@ impl MultiTenancyConfig {
 }
 
 /// The configuration options for InvertedIndexConfig
-#[derive(Serialize, Deserialize, Debug, PartialEq, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct InvertedIndexConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1849,7 +2545,7 @@ impl InvertedIndexConfigBuilder {
 }
 
 /// The configuration options for Stopwords.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct StopwordsConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1984,7 +2680,7 @@ impl StopwordsConfigBuilder {
 /// Strict definitions of Stopword presets.
 ///
 /// Weaviate supports EN and NONE
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum StopwordPreset {
     #[serde(rename = "en")]
     EN,
@@ -1993,7 +2689,7 @@ pub enum StopwordPreset {
 }
 
 /// The configuration options for the ReplicationConfig
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReplicationConfig {
     pub factor: u64,
 }
@@ -2042,6 +2738,29 @@ impl Tenants {
     pub fn new(tenants: Vec<Tenant>) -> Tenants {
         Tenants { tenants }
     }
+
+    /// Set the activity status of all of the tenants.
+    ///
+    /// # Parameters
+    /// - activity_status: the activity status to set
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::{ActivityStatus, Tenants, Tenant};
+    ///
+    /// let mut tenants = Tenants::new(
+    ///     vec![
+    ///         Tenant::builder("abcde").build(),
+    ///         Tenant::builder("fghij").build(),
+    ///     ]
+    /// );
+    /// tenants.set_all_status(ActivityStatus::COLD);
+    /// ```
+    pub fn set_all_status(&mut self, activity_status: ActivityStatus) {
+        for tenant in &mut self.tenants {
+            tenant.activity_status = Some(activity_status);
+        }
+    }
 }
 
 /// The configuration options for a Tenant.
@@ -2149,14 +2868,14 @@ impl TenantBuilder {
 /// Strict definitions of ActivityStatus of a tenant.
 ///
 /// The activity status of a tenant can either be `hot` or `cold`.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum ActivityStatus {
     HOT,
     COLD,
 }
 
 /// The configuration options for BM25.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Bm25 {
     pub b: f64,
     pub k1: f64,
@@ -2187,7 +2906,7 @@ impl Bm25 {
 /// - Lowercase
 /// - Whitespace
 /// - Field
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum Tokenization {
     #[serde(rename = "word")]
     WORD,
@@ -2260,9 +2979,28 @@ impl Shard {
 
 /// Strict definitions of ShardStatus.
 ///
-/// Weaviate supports READONLY and READY shard status.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// A shard can be in any of these statuses, but only READONLY and READY can be set through
+/// `Schema::update_class_shard`. INDEXING and LOADING are set by Weaviate while the shard is
+/// being indexed or loaded.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum ShardStatus {
     READONLY,
     READY,
+    INDEXING,
+    LOADING,
+}
+
+impl ShardStatus {
+    /// Check whether a shard can be set to this status through `Schema::update_class_shard`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::collections::schema::ShardStatus;
+    ///
+    /// assert!(ShardStatus::READONLY.is_settable());
+    /// assert!(!ShardStatus::INDEXING.is_settable());
+    /// ```
+    pub fn is_settable(&self) -> bool {
+        matches!(self, ShardStatus::READONLY | ShardStatus::READY)
+    }
 }
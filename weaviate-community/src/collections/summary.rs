@@ -0,0 +1,44 @@
+/// All cluster summary associated type components
+use crate::collections::nodes::Node;
+
+/// A snapshot of the state of a Weaviate instance, as returned by `WeaviateClient::summary`.
+///
+/// There should never be a need for this to be created manually.
+#[derive(Debug)]
+pub struct ClusterSummary {
+    /// The version of Weaviate reported by the meta endpoint
+    pub version: String,
+    /// Each class in the schema, in schema order
+    pub classes: Vec<ClassSummary>,
+    /// The status of each node in the cluster
+    pub nodes: Vec<Node>,
+}
+
+impl ClusterSummary {
+    /// Get the names of the classes in the schema.
+    pub fn class_names(&self) -> Vec<&str> {
+        self.classes
+            .iter()
+            .map(|class| class.name.as_str())
+            .collect()
+    }
+
+    /// Get the total number of objects across the classes that could be counted.
+    pub fn total_objects(&self) -> u64 {
+        self.classes
+            .iter()
+            .filter_map(|class| class.object_count)
+            .sum()
+    }
+}
+
+/// The name and object count of a single class within a `ClusterSummary`.
+#[derive(Debug, PartialEq)]
+pub struct ClassSummary {
+    /// The name of the class
+    pub name: String,
+    /// The number of objects in the class.
+    ///
+    /// This is `None` for multi-tenant classes, as their objects can only be counted per tenant.
+    pub object_count: Option<u64>,
+}
@@ -0,0 +1,237 @@
+//! The shared request path used by each of the endpoints.
+use crate::collections::auth::Auth;
+use crate::collections::objects::ConsistencyLevel;
+use reqwest::header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Request, RequestBuilder, Response, Url};
+use std::ops::Deref;
+
+/// The query parameter Weaviate reads the consistency level of a REST request from.
+pub(crate) const CONSISTENCY_LEVEL_PARAM: &str = "consistency_level";
+
+/// A hook that can observe or mutate each request made to Weaviate, and observe each response.
+///
+/// Hooks are registered with `WeaviateClientBuilder::with_hook` and run in the order they were
+/// added. Both methods default to doing nothing, so only the needed one has to be implemented.
+///
+/// # Example
+/// ```
+/// use reqwest::header::HeaderValue;
+/// use weaviate_community::{RequestHook, WeaviateClient};
+///
+/// #[derive(Debug)]
+/// struct TraceHeader;
+///
+/// impl RequestHook for TraceHeader {
+///     fn on_request(&self, request: &mut reqwest::Request) {
+///         request
+///             .headers_mut()
+///             .insert("x-trace-id", HeaderValue::from_static("abc123"));
+///     }
+/// }
+///
+/// let client = WeaviateClient::builder("http://localhost:8080")
+///     .with_hook(Box::new(TraceHeader))
+///     .build();
+/// ```
+pub trait RequestHook: std::fmt::Debug + Send + Sync {
+    /// Called with each request before it is sent.
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// Called with each response once it has been received.
+    fn on_response(&self, _response: &Response) {}
+}
+
+/// The `reqwest::Client` shared by each of the endpoints, along with the authentication and the
+/// registered hooks.
+#[derive(Debug)]
+pub(crate) struct HttpClient {
+    client: reqwest::Client,
+    auth: Auth,
+    hooks: Vec<Box<dyn RequestHook>>,
+    default_consistency: Option<ConsistencyLevel>,
+}
+
+impl HttpClient {
+    pub(crate) fn new(
+        client: reqwest::Client,
+        auth: Auth,
+        hooks: Vec<Box<dyn RequestHook>>,
+        default_consistency: Option<ConsistencyLevel>,
+    ) -> Self {
+        HttpClient {
+            client,
+            auth,
+            hooks,
+            default_consistency,
+        }
+    }
+
+    /// Resolve the consistency level of a request, falling back to the client default when the
+    /// call doesn't set one.
+    pub(crate) fn consistency_level(
+        &self,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Option<ConsistencyLevel> {
+        consistency_level.or(self.default_consistency)
+    }
+
+    /// Add the resolved consistency level of a request to the query string of `endpoint`.
+    ///
+    /// Weaviate silently falls back to its own default for an unknown parameter, so every REST
+    /// endpoint goes through here to keep the name exactly `CONSISTENCY_LEVEL_PARAM`.
+    pub(crate) fn set_consistency_level(
+        &self,
+        endpoint: &mut Url,
+        consistency_level: Option<ConsistencyLevel>,
+    ) {
+        if let Some(cl) = self.consistency_level(consistency_level) {
+            endpoint
+                .query_pairs_mut()
+                .append_pair(CONSISTENCY_LEVEL_PARAM, cl.value());
+        }
+    }
+
+    /// Send a request to Weaviate, applying the authentication and running the registered hooks
+    /// around it.
+    ///
+    /// Every request accepts JSON, and every request with a body is sent as JSON, unless the
+    /// endpoint has already set those headers itself.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut request = request.build()?;
+        let json = HeaderValue::from_static("application/json");
+        if request.body().is_some() {
+            request
+                .headers_mut()
+                .entry(CONTENT_TYPE)
+                .or_insert_with(|| json.clone());
+        }
+        request.headers_mut().entry(ACCEPT).or_insert(json);
+        if let Some(value) = self.auth.get_header_value() {
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+        for hook in &self.hooks {
+            hook.on_request(&mut request);
+        }
+        let res = self.execute(request).await?;
+        for hook in &self.hooks {
+            hook.on_response(&res);
+        }
+        Ok(res)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    async fn execute(&self, request: Request) -> reqwest::Result<Response> {
+        self.client.execute(request).await
+    }
+
+    /// Execute the request, recording the method, endpoint, status, and duration in a
+    /// `weaviate_request` span.
+    ///
+    /// When the response is an error and `trace` level is enabled, the response body is emitted
+    /// too. The body is buffered to do so, and handed back in the returned response.
+    #[cfg(feature = "tracing")]
+    async fn execute(&self, request: Request) -> reqwest::Result<Response> {
+        use tracing::Instrument;
+
+        let span = tracing::debug_span!(
+            "weaviate_request",
+            method = %request.method(),
+            endpoint = %request.url(),
+            status = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+        );
+
+        async move {
+            let start = std::time::Instant::now();
+            let res = self.client.execute(request).await;
+            let span = tracing::Span::current();
+            span.record("duration_ms", start.elapsed().as_millis() as u64);
+
+            let res = match res {
+                Ok(res) => res,
+                Err(err) => {
+                    tracing::debug!(error = %err, "request failed");
+                    return Err(err);
+                }
+            };
+
+            let status = res.status();
+            span.record("status", status.as_u16());
+            if !(status.is_client_error() || status.is_server_error())
+                || !tracing::enabled!(tracing::Level::TRACE)
+            {
+                tracing::debug!("request complete");
+                return Ok(res);
+            }
+
+            let headers = res.headers().clone();
+            let body = res.bytes().await?;
+            tracing::trace!(body = %String::from_utf8_lossy(&body), "error response received");
+            let mut res = http::Response::new(body);
+            *res.status_mut() = status;
+            *res.headers_mut() = headers;
+            Ok(res.into())
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+impl Deref for HttpClient {
+    type Target = reqwest::Client;
+
+    fn deref(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::WeaviateClient;
+    use tracing_test::traced_test;
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_span() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/ready")
+            .with_status(200)
+            .create();
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        let res = client.is_ready().await;
+        mock.assert();
+        assert!(res.unwrap());
+        assert!(logs_contain("weaviate_request"));
+        assert!(logs_contain("method=GET"));
+        assert!(logs_contain(&format!(
+            "endpoint={}/v1/.well-known/ready",
+            host
+        )));
+        assert!(logs_contain("status=200"));
+        assert!(logs_contain("duration_ms="));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_error_body_traced() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let mock = mock_server
+            .mock("GET", "/v1/schema/Missing")
+            .with_status(404)
+            .with_body("{\"error\":[{\"message\":\"class Missing not found\"}]}")
+            .create();
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        let res = client.schema.get_class("Missing").await;
+        mock.assert();
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains("class Missing not found"));
+        assert!(logs_contain("status=404"));
+        assert!(logs_contain("error response received"));
+        assert!(logs_contain("class Missing not found"));
+    }
+}
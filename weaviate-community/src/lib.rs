@@ -6,6 +6,7 @@ mod backups;
 mod batch;
 mod classification;
 pub mod collections;
+mod http;
 mod meta;
 mod modules;
 mod nodes;
@@ -13,29 +14,48 @@ mod objects;
 mod oidc;
 mod query;
 mod schema;
+mod scoped;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub use self::backups::Backups;
 pub use self::batch::Batch;
 pub use self::classification::Classification;
+pub use self::http::RequestHook;
 pub use self::meta::Meta;
 pub use self::modules::Modules;
 pub use self::nodes::Nodes;
-pub use self::objects::Objects;
+pub use self::objects::{ObjectCursor, Objects};
 pub use self::oidc::Oidc;
 pub use self::query::Query;
 pub use self::schema::Schema;
-use collections::auth::{ApiKey, AuthApiKey};
+pub use self::scoped::{ScopedBatch, ScopedClient, ScopedObjects, ScopedQuery};
+use collections::auth::{ApiKey, Auth, AuthApiKey};
+use collections::error::{QueryError, WeaviateError};
+use collections::objects::ConsistencyLevel;
+use collections::summary::{ClassSummary, ClusterSummary};
+use http::HttpClient;
 
+use futures::stream::{self, StreamExt, TryStreamExt};
 use std::error::Error;
 use std::sync::Arc;
+use std::time::Duration;
 
-use reqwest::header::{HeaderMap, AUTHORIZATION};
-use reqwest::Url;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Certificate, Url};
+
+/// The port the Weaviate gRPC service listens on by default.
+pub const DEFAULT_GRPC_PORT: u16 = 50051;
+
+/// The maximum number of concurrent object count requests issued by `WeaviateClient::summary`.
+const SUMMARY_CONCURRENCY: usize = 8;
 
 /// An asynchronous `WeaviateClient` to interact with a Weaviate database.
 #[derive(Debug)]
 pub struct WeaviateClient {
     pub base_url: Url,
-    client: Arc<reqwest::Client>,
+    /// The url of the gRPC service, which listens on a separate port to the REST API.
+    pub grpc_url: Url,
+    client: Arc<HttpClient>,
     pub schema: Schema,
     pub objects: Objects,
     pub batch: Batch,
@@ -93,55 +113,10 @@ impl WeaviateClient {
         auth_client_secret: Option<AuthApiKey>,
         api_keys: Option<Vec<ApiKey>>,
     ) -> Result<Self, Box<dyn Error>> {
-        let base = Url::parse(url)?;
-        let mut client_builder = reqwest::Client::builder();
-
-        let mut headers = HeaderMap::new();
-
-        // Add the authorization header to the client if it is present
-        if let Some(auth) = auth_client_secret {
-            headers.insert(AUTHORIZATION, auth.get_header_value());
-        };
-
-        // Add any of the other header keys to the client, for example, OpenAI
-        if let Some(keys) = api_keys {
-            for i in 0..keys.len() {
-                headers.insert(
-                    keys.get(i).unwrap().get_header_name(),
-                    keys.get(i).unwrap().get_header_value(),
-                );
-            }
-        }
-
-        client_builder = client_builder.default_headers(headers);
-
-        // Each of the endpoint categories hold a strong ref to the main client.
-        let client = Arc::new(client_builder.build()?);
-        let schema = Schema::new(&base, Arc::clone(&client))?;
-        let objects = Objects::new(&base, Arc::clone(&client))?;
-        let batch = Batch::new(&base, Arc::clone(&client))?;
-        let backups = Backups::new(&base, Arc::clone(&client))?;
-        let classification = Classification::new(&base, Arc::clone(&client))?;
-        let meta = Meta::new(&base, Arc::clone(&client))?;
-        let nodes = Nodes::new(&base, Arc::clone(&client))?;
-        let oidc = Oidc::new(&base, Arc::clone(&client))?;
-        let modules = Modules::new(&base, Arc::clone(&client))?;
-        let query = Query::new(&base, Arc::clone(&client))?;
-
-        Ok(WeaviateClient {
-            base_url: base,
-            client,
-            schema,
-            objects,
-            batch,
-            backups,
-            classification,
-            meta,
-            nodes,
-            oidc,
-            modules,
-            query,
-        })
+        let mut builder = WeaviateClientBuilder::new(url);
+        builder.auth = auth_client_secret.map(Auth::from).unwrap_or_default();
+        builder.api_keys = api_keys.unwrap_or_default();
+        builder.build()
     }
 
     /// Determine if the application is ready to receive traffic.
@@ -174,7 +149,7 @@ impl WeaviateClient {
     /// ```
     pub async fn is_live(&self) -> Result<bool, Box<dyn Error>> {
         let endpoint = self.base_url.join("/v1/.well-known/live")?;
-        let resp = self.client.get(endpoint).send().await?;
+        let resp = self.client.send(self.client.get(endpoint)).await?;
         match resp.status() {
             reqwest::StatusCode::OK => Ok(true),
             _ => Ok(false),
@@ -202,7 +177,7 @@ impl WeaviateClient {
     /// ```
     pub async fn is_ready(&self) -> Result<bool, Box<dyn Error>> {
         let endpoint = self.base_url.join("/v1/.well-known/ready")?;
-        let resp = self.client.get(endpoint).send().await?;
+        let resp = self.client.send(self.client.get(endpoint)).await?;
         match resp.status() {
             reqwest::StatusCode::OK => Ok(true),
             _ => Ok(false),
@@ -232,14 +207,198 @@ impl WeaviateClient {
     pub fn builder(base_url: &str) -> WeaviateClientBuilder {
         WeaviateClientBuilder::new(base_url)
     }
+
+    /// Check whether enough nodes are healthy to meet a ConsistencyLevel.
+    ///
+    /// The live node statuses are compared against the number of replicas the ConsistencyLevel
+    /// requires for the given replication factor. Writing at `ConsistencyLevel::ALL` while this
+    /// returns false will fail.
+    ///
+    /// # Parameters
+    /// - consistency_level: the consistency level to check
+    /// - replication_factor: the replication factor of the class being written to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::ConsistencyLevel;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     if client.can_satisfy(ConsistencyLevel::ALL, 3).await? {
+    ///         // safe to write at ALL
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn can_satisfy(
+        &self,
+        consistency_level: ConsistencyLevel,
+        replication_factor: u64,
+    ) -> Result<bool, Box<dyn Error>> {
+        if replication_factor == 0 {
+            return Err(Box::new(WeaviateError::InvalidRequest(
+                "replication factor must be at least 1".into(),
+            )));
+        }
+        let healthy = self.nodes.healthy_count().await? as u64;
+        Ok(healthy >= consistency_level.required_replicas(replication_factor))
+    }
+
+    /// Gather a summary of the Weaviate instance in a single call.
+    ///
+    /// The schema, node statuses and meta version are fetched concurrently, followed by the
+    /// object count of every class, with at most 8 counts in flight. Multi-tenant classes are
+    /// listed without a count, as their objects can only be counted per tenant.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let summary = client.summary().await?;
+    ///     for class in summary.classes {
+    ///         println!("{}: {:?}", class.name, class.object_count);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn summary(&self) -> Result<ClusterSummary, Box<dyn Error>> {
+        let (schema, nodes, meta) = futures::try_join!(
+            self.schema.get(),
+            self.nodes.get_nodes_status(),
+            self.meta.get_meta(),
+        )?;
+        let counts = stream::iter(&schema.classes).map(|class| async move {
+            let multi_tenant = class
+                .multi_tenancy_config
+                .as_ref()
+                .is_some_and(|config| config.enabled);
+            let object_count = match multi_tenant {
+                true => None,
+                false => Some(self.objects.count(&class.class, None, None).await?),
+            };
+            Ok::<_, Box<dyn Error>>(ClassSummary {
+                name: class.class.clone(),
+                object_count,
+            })
+        });
+        let classes = counts.buffered(SUMMARY_CONCURRENCY).try_collect().await?;
+        Ok(ClusterSummary {
+            version: meta.version,
+            classes,
+            nodes: nodes.nodes,
+        })
+    }
+
+    /// Send a request to any Weaviate REST endpoint, for endpoints this client does not wrap yet.
+    ///
+    /// The request is sent with the authentication, headers and hooks configured for the client,
+    /// and `path` is resolved against its base url. A successful response is returned as JSON,
+    /// or `serde_json::Value::Null` when it has no body. Any other response returns a
+    /// `QueryError` carrying its status code.
+    ///
+    /// This is unstable: it exists as an escape hatch for new or preview endpoints, and may change
+    /// or be removed as those endpoints are wrapped. Prefer the typed endpoints where they exist.
+    ///
+    /// # Parameters
+    /// - method: the HTTP method of the request
+    /// - path: the path of the endpoint, including the version, such as `/v1/schema`
+    /// - body: the JSON body to send, if any
+    ///
+    /// # Example
+    /// ```no_run
+    /// use reqwest::Method;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let res = client.raw_request(Method::GET, "/v1/cluster/statistics", None).await?;
+    ///     println!("{}", res);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn raw_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, Box<dyn Error>> {
+        let endpoint = self.base_url.join(path)?;
+        let mut request = self.client.request(method, endpoint);
+        if let Some(body) = &body {
+            request = request.json(body);
+        }
+        let res = self.client.send(request).await?;
+        let status = res.status();
+        let text = res.text().await?;
+        if !status.is_success() {
+            return Err(Box::new(QueryError::with_status(
+                status,
+                format!(
+                    "Status code `{}` received when calling {} endpoint. Response: {}",
+                    status, path, text
+                ),
+            )));
+        }
+        match text.is_empty() {
+            true => Ok(serde_json::Value::Null),
+            false => Ok(serde_json::from_str(&text)?),
+        }
+    }
+
+    /// Get a view of the client scoped to a single tenant.
+    ///
+    /// The tenant is applied automatically to every `objects`, `batch` and `query` call made
+    /// through the returned `ScopedClient`, which shares the connection of this client.
+    ///
+    /// # Parameters
+    /// - tenant: the name of the tenant to scope the client to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::query::GetBuilder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let tenant = client.for_tenant("TENANT_A");
+    ///     let res = tenant.query.get(GetBuilder::new("Article", vec!["title"])).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn for_tenant(&self, tenant: &str) -> ScopedClient<'_> {
+        ScopedClient::new(&self.objects, &self.batch, &self.query, tenant)
+    }
 }
 
 /// A `WeaviateClientBuilder` can be used to create a new `WeaviateClient`.
 #[derive(Default, Debug)]
 pub struct WeaviateClientBuilder {
     pub base_url: String,
+    pub grpc_url: Option<String>,
+    /// Used as `Auth::ApiKey` when `auth` is `Auth::None`.
+    #[deprecated(note = "use `auth` or `with_auth_secret` instead")]
     pub auth_secret: Option<AuthApiKey>,
+    pub auth: Auth,
     pub api_keys: Vec<ApiKey>,
+    pub compression: bool,
+    pub hooks: Vec<Box<dyn RequestHook>>,
+    pub pool_max_idle_per_host: Option<usize>,
+    pub pool_idle_timeout: Option<Duration>,
+    pub timeout: Option<Duration>,
+    pub danger_accept_invalid_certs: bool,
+    pub root_certificates: Vec<Certificate>,
+    pub default_consistency: Option<ConsistencyLevel>,
+    pub query_maximum_results: Option<u64>,
+    pub beacon_host: Option<String>,
+    pub schema_cache_ttl: Option<Duration>,
+    pub restore_precheck: bool,
 }
 
 impl WeaviateClientBuilder {
@@ -268,8 +427,23 @@ impl WeaviateClientBuilder {
     pub fn new(base_url: &str) -> WeaviateClientBuilder {
         WeaviateClientBuilder {
             base_url: base_url.into(),
+            grpc_url: None,
+            #[allow(deprecated)]
             auth_secret: None,
+            auth: Auth::None,
             api_keys: Vec::new(),
+            compression: false,
+            hooks: Vec::new(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
+            danger_accept_invalid_certs: false,
+            root_certificates: Vec::new(),
+            default_consistency: None,
+            query_maximum_results: None,
+            beacon_host: None,
+            schema_cache_ttl: None,
+            restore_precheck: false,
         }
     }
 
@@ -287,7 +461,48 @@ impl WeaviateClientBuilder {
     ///     .build();
     /// ```
     pub fn with_auth_secret(mut self, auth_secret: &str) -> WeaviateClientBuilder {
-        self.auth_secret = Some(AuthApiKey::new(auth_secret));
+        self.auth = Auth::ApiKey(auth_secret.into());
+        self
+    }
+
+    /// Sets an OIDC bearer token to be used by the client.
+    ///
+    /// This replaces any auth secret set with `with_auth_secret`, and can be combined with module
+    /// api keys set with `with_api_key`.
+    ///
+    /// # Parameters
+    /// - token: the bearer (access) token
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_bearer_token("your-token")
+    ///     .with_api_key("X-OpenAI-Api-Key", "abcdefg")
+    ///     .build();
+    /// ```
+    pub fn with_bearer_token(mut self, token: &str) -> WeaviateClientBuilder {
+        self.auth = Auth::Bearer(token.into());
+        self
+    }
+
+    /// Sets the authentication scheme to be used by the client.
+    ///
+    /// # Parameters
+    /// - auth: the Auth to set in the client
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    /// use weaviate_community::collections::auth::Auth;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_auth(Auth::ApiKey("your-key".into()))
+    ///     .build();
+    /// ```
+    pub fn with_auth(mut self, auth: Auth) -> WeaviateClientBuilder {
+        self.auth = auth;
         self
     }
 
@@ -313,6 +528,321 @@ impl WeaviateClientBuilder {
         self
     }
 
+    /// Enables gzip compression for requests and responses.
+    ///
+    /// When enabled, the client advertises `Accept-Encoding: gzip` and transparently decompresses
+    /// responses. Batch request bodies larger than 1 KiB are gzip compressed and sent with
+    /// `Content-Encoding: gzip`; smaller bodies are sent as-is to avoid the overhead.
+    ///
+    /// This relies on the `gzip` feature of `reqwest`, which this crate enables.
+    ///
+    /// # Parameters
+    /// - compression: whether to enable compression
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_compression(true)
+    ///     .build();
+    /// ```
+    pub fn with_compression(mut self, compression: bool) -> WeaviateClientBuilder {
+        self.compression = compression;
+        self
+    }
+
+    /// Registers a hook that is called around every request made by the client.
+    ///
+    /// Hooks can mutate outgoing requests, for example to add custom headers or sign requests,
+    /// and observe the responses. They are called in the order they were registered.
+    ///
+    /// # Parameters
+    /// - hook: the hook to register
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::{RequestHook, WeaviateClientBuilder};
+    ///
+    /// #[derive(Debug)]
+    /// struct LogStatus;
+    ///
+    /// impl RequestHook for LogStatus {
+    ///     fn on_response(&self, response: &reqwest::Response) {
+    ///         println!("{}", response.status());
+    ///     }
+    /// }
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_hook(Box::new(LogStatus))
+    ///     .build();
+    /// ```
+    pub fn with_hook(mut self, hook: Box<dyn RequestHook>) -> WeaviateClientBuilder {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host in the connection pool.
+    ///
+    /// By default there is no limit. Bulk-ingest and high-QPS search workloads may want to bound
+    /// this to match the expected request concurrency, for example `32`.
+    ///
+    /// # Parameters
+    /// - max_idle: the maximum number of idle connections per host
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_pool_max_idle_per_host(32)
+    ///     .build();
+    /// ```
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> WeaviateClientBuilder {
+        self.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Sets how long an idle connection is kept open in the connection pool.
+    ///
+    /// Defaults to 90 seconds. Lower this if a load balancer in front of Weaviate closes idle
+    /// connections sooner.
+    ///
+    /// # Parameters
+    /// - timeout: how long an idle connection is kept alive
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_pool_idle_timeout(Duration::from_secs(30))
+    ///     .build();
+    /// ```
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> WeaviateClientBuilder {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout applied to every request made by the client, from connecting until the
+    /// response body has been read.
+    ///
+    /// There is no timeout by default. Slow calls, such as large batches or backups, can override
+    /// this with their `_with_timeout` variants, such as `Batch::objects_batch_add_with_timeout`.
+    ///
+    /// # Parameters
+    /// - timeout: the maximum duration of each request
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_timeout(Duration::from_secs(10))
+    ///     .build();
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> WeaviateClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Disables TLS certificate verification.
+    ///
+    /// **This is dangerous and intended for development only**, for example when connecting to a
+    /// local Weaviate instance with a self-signed certificate. Any certificate will be trusted,
+    /// including expired or mismatched ones, leaving the connection open to interception. Prefer
+    /// `with_root_certificate` wherever possible.
+    ///
+    /// # Parameters
+    /// - accept_invalid_certs: whether to accept invalid certificates
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("https://localhost:8443")
+    ///     .with_danger_accept_invalid_certs(true)
+    ///     .build();
+    /// ```
+    pub fn with_danger_accept_invalid_certs(
+        mut self,
+        accept_invalid_certs: bool,
+    ) -> WeaviateClientBuilder {
+        self.danger_accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Adds a custom root certificate to trust, for example the CA of a self-hosted Weaviate.
+    ///
+    /// Can be called multiple times to trust multiple certificates.
+    ///
+    /// # Parameters
+    /// - certificate: the root certificate to trust
+    ///
+    /// # Example
+    /// ```no_run
+    /// use reqwest::Certificate;
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let pem = std::fs::read("ca.pem")?;
+    ///     let client = WeaviateClientBuilder::new("https://localhost:8443")
+    ///         .with_root_certificate(Certificate::from_pem(&pem)?)
+    ///         .build()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_root_certificate(mut self, certificate: Certificate) -> WeaviateClientBuilder {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Sets the consistency level used by requests that don't specify one.
+    ///
+    /// Passing a consistency level to an individual call still overrides this default.
+    ///
+    /// # Parameters
+    /// - consistency_level: the default consistency level
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    /// use weaviate_community::collections::objects::ConsistencyLevel;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_default_consistency(ConsistencyLevel::QUORUM)
+    ///     .build();
+    /// ```
+    pub fn with_default_consistency(
+        mut self,
+        consistency_level: ConsistencyLevel,
+    ) -> WeaviateClientBuilder {
+        self.default_consistency = Some(consistency_level);
+        self
+    }
+
+    /// Sets the `QUERY_MAXIMUM_RESULTS` configured on the Weaviate instance.
+    ///
+    /// `Objects::list` requests, and Get queries built with `Query::get_builder` or run through
+    /// `WeaviateClient::for_tenant`, where `offset + limit` exceeds this are rejected before being
+    /// sent, unless the `GetBuilder` sets its own maximum. Defaults to 10000, the Weaviate default.
+    ///
+    /// # Parameters
+    /// - query_maximum_results: the maximum results configured on the Weaviate instance
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_query_maximum_results(20_000)
+    ///     .build();
+    /// ```
+    pub fn with_query_maximum_results(
+        mut self,
+        query_maximum_results: u64,
+    ) -> WeaviateClientBuilder {
+        self.query_maximum_results = Some(query_maximum_results);
+        self
+    }
+
+    /// Sets the url of the Weaviate gRPC service.
+    ///
+    /// Weaviate serves gRPC on a separate port to the REST API, so gRPC requests are sent to this
+    /// url while REST requests continue to use the `base_url`. Defaults to the host of the
+    /// `base_url` on port `DEFAULT_GRPC_PORT` (50051).
+    ///
+    /// The url must use the `http` or `https` scheme and contain a host, otherwise `build` returns
+    /// a `WeaviateError::InvalidRequest`.
+    ///
+    /// # Parameters
+    /// - grpc_url: the root url of the gRPC service
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_grpc_url("http://localhost:50052")
+    ///     .build();
+    /// ```
+    pub fn with_grpc_url(mut self, grpc_url: &str) -> WeaviateClientBuilder {
+        self.grpc_url = Some(grpc_url.into());
+        self
+    }
+
+    /// Sets the host written into the beacons of references, in the format
+    /// `weaviate://{host}/{class_name}/{id}`.
+    ///
+    /// Defaults to `localhost`. Weaviate resolves a beacon within the cluster that receives it
+    /// rather than by connecting to its host, and uses `localhost` in the beacons it returns, so
+    /// this only needs changing to match beacons written by other tooling or to make them
+    /// clearer in logs.
+    ///
+    /// # Parameters
+    /// - beacon_host: the host to use within beacons
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_beacon_host("weaviate-cluster")
+    ///     .build();
+    /// ```
+    pub fn with_beacon_host(mut self, beacon_host: &str) -> WeaviateClientBuilder {
+        self.beacon_host = Some(beacon_host.into());
+        self
+    }
+
+    /// Enables the schema cache, so the responses of `schema.get_class` and `schema.get` are
+    /// reused for `ttl` instead of being fetched on every call.
+    ///
+    /// The cache is shared by every task using the client, and is dropped whenever the schema is
+    /// changed through the client. Changes made elsewhere are only seen once `ttl`
+    /// has passed or `schema.invalidate_cache()` is called. Disabled by default.
+    ///
+    /// # Parameters
+    /// - ttl: how long a fetched class or schema is reused for
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_schema_cache(Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    pub fn with_schema_cache(mut self, ttl: Duration) -> WeaviateClientBuilder {
+        self.schema_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Sets whether `backups.restore` checks the backup exists before restoring it.
+    ///
+    /// With the precheck enabled, restoring a backup that does not exist returns a
+    /// `WeaviateError::NotFound`, at the cost of an extra request for each restore. Disabled by
+    /// default.
+    ///
+    /// # Parameters
+    /// - restore_precheck: whether to check the backup exists before restoring
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_restore_precheck(true)
+    ///     .build();
+    /// ```
+    pub fn with_restore_precheck(mut self, restore_precheck: bool) -> WeaviateClientBuilder {
+        self.restore_precheck = restore_precheck;
+        self
+    }
+
     /// Build a `WeaviateClient` from the values set in the WeaviateClientBuilder.
     ///
     /// # Example
@@ -321,15 +851,117 @@ impl WeaviateClientBuilder {
     ///
     /// let client = WeaviateClientBuilder::new("http://localhost:8080").build();
     /// ```
-    pub fn build(self) -> Result<WeaviateClient, Box<dyn Error>> {
-        let client = WeaviateClient::new(&self.base_url, self.auth_secret, Some(self.api_keys))?;
-        Ok(client)
+    pub fn build(mut self) -> Result<WeaviateClient, Box<dyn Error>> {
+        #[allow(deprecated)]
+        if let (Auth::None, Some(auth_secret)) = (&self.auth, self.auth_secret.take()) {
+            self.auth = auth_secret.into();
+        }
+        let base = Url::parse(&self.base_url)?;
+        let grpc_url = match &self.grpc_url {
+            Some(grpc_url) => Url::parse(grpc_url)?,
+            None => {
+                let mut grpc_url = base.join("/")?;
+                let _ = grpc_url.set_port(Some(DEFAULT_GRPC_PORT));
+                grpc_url
+            }
+        };
+        if !matches!(grpc_url.scheme(), "http" | "https") || !grpc_url.has_host() {
+            return Err(Box::new(WeaviateError::InvalidRequest(format!(
+                "invalid gRPC url `{}`: expected an http or https url with a host",
+                grpc_url
+            ))));
+        }
+        if let Auth::ApiKey(token) | Auth::Bearer(token) = &self.auth {
+            if HeaderValue::from_str(token).is_err() {
+                return Err(Box::new(WeaviateError::InvalidRequest(
+                    "invalid auth token: tokens cannot contain control characters".into(),
+                )));
+            }
+        }
+        // The connection settings are shared with the client used for the OIDC token issuer.
+        let configure = |mut client_builder: reqwest::ClientBuilder| {
+            if let Some(max_idle) = self.pool_max_idle_per_host {
+                client_builder = client_builder.pool_max_idle_per_host(max_idle);
+            }
+            if let Some(timeout) = self.pool_idle_timeout {
+                client_builder = client_builder.pool_idle_timeout(timeout);
+            }
+            if let Some(timeout) = self.timeout {
+                client_builder = client_builder.timeout(timeout);
+            }
+            for certificate in &self.root_certificates {
+                client_builder = client_builder.add_root_certificate(certificate.clone());
+            }
+            client_builder.danger_accept_invalid_certs(self.danger_accept_invalid_certs)
+        };
+        let mut client_builder = configure(reqwest::Client::builder().gzip(self.compression));
+        let issuer_client = configure(reqwest::Client::builder()).build()?;
+
+        let mut headers = HeaderMap::new();
+
+        // Add any of the other header keys to the client, for example, OpenAI
+        for key in &self.api_keys {
+            headers.insert(key.get_header_name(), key.get_header_value());
+        }
+
+        client_builder = client_builder.default_headers(headers);
+
+        // Each of the endpoint categories hold a strong ref to the main client.
+        let client = Arc::new(HttpClient::new(
+            client_builder.build()?,
+            self.auth,
+            self.hooks,
+            self.default_consistency,
+        ));
+        let mut schema = Schema::new(&base, Arc::clone(&client))?;
+        if let Some(ttl) = self.schema_cache_ttl {
+            schema.set_cache_ttl(ttl);
+        }
+        let mut objects = Objects::new(&base, Arc::clone(&client))?;
+        if let Some(query_maximum_results) = self.query_maximum_results {
+            objects.set_query_maximum_results(query_maximum_results);
+        }
+        let mut batch = Batch::new(&base, Arc::clone(&client))?;
+        batch.set_compression(self.compression);
+        if let Some(beacon_host) = &self.beacon_host {
+            objects.set_beacon_host(beacon_host);
+            batch.set_beacon_host(beacon_host);
+        }
+        let mut backups = Backups::new(&base, Arc::clone(&client))?;
+        backups.set_restore_precheck(self.restore_precheck);
+        let classification = Classification::new(&base, Arc::clone(&client))?;
+        let meta = Meta::new(&base, Arc::clone(&client))?;
+        let nodes = Nodes::new(&base, Arc::clone(&client))?;
+        let oidc = Oidc::new(&base, Arc::clone(&client), issuer_client)?;
+        let modules = Modules::new(&base, Arc::clone(&client))?;
+        let mut query = Query::new(&base, Arc::clone(&client))?;
+        if let Some(query_maximum_results) = self.query_maximum_results {
+            query.set_query_maximum_results(query_maximum_results);
+        }
+
+        Ok(WeaviateClient {
+            base_url: base,
+            grpc_url,
+            client,
+            schema,
+            objects,
+            batch,
+            backups,
+            classification,
+            meta,
+            nodes,
+            oidc,
+            modules,
+            query,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::collections::nodes::NodeStatus;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
         let mock_server = mockito::Server::new_async().await;
@@ -381,6 +1013,454 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    fn test_nodes(statuses: &[&str]) -> String {
+        let nodes: Vec<_> = statuses
+            .iter()
+            .enumerate()
+            .map(|(i, status)| {
+                serde_json::json!({"name": format!("weaviate-{}", i), "status": status})
+            })
+            .collect();
+        serde_json::json!({ "nodes": nodes }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_can_satisfy_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = test_nodes(&["HEALTHY", "HEALTHY", "HEALTHY"]);
+        let mock = mock_get(&mut mock_server, "/v1/nodes/", 200, &body).await;
+        let res = client.can_satisfy(ConsistencyLevel::ALL, 3).await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_can_satisfy_unhealthy() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = test_nodes(&["HEALTHY", "UNHEALTHY", "HEALTHY"]);
+        let mock = mock_server
+            .mock("GET", "/v1/nodes/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&body)
+            .expect(2)
+            .create();
+        let all = client.can_satisfy(ConsistencyLevel::ALL, 3).await;
+        let quorum = client.can_satisfy(ConsistencyLevel::QUORUM, 3).await;
+        mock.assert();
+        assert!(!all.unwrap());
+        assert!(quorum.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_summary_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let schema = serde_json::json!({
+            "classes": [
+                {"class": "Article"},
+                {"class": "Author"},
+                {"class": "Tenanted", "multiTenancyConfig": {"enabled": true}}
+            ]
+        })
+        .to_string();
+        let meta = serde_json::json!({
+            "hostname": "http://[::]:8080",
+            "modules": {},
+            "version": "1.23.7"
+        })
+        .to_string();
+        let schema_mock = mock_get(&mut mock_server, "/v1/schema/", 200, &schema).await;
+        let nodes = test_nodes(&["HEALTHY", "UNHEALTHY"]);
+        let nodes_mock = mock_get(&mut mock_server, "/v1/nodes/", 200, &nodes).await;
+        let meta_mock = mock_get(&mut mock_server, "/v1/meta/", 200, &meta).await;
+        let mut count_mocks = Vec::new();
+        for (class_name, count) in [("Article", 42), ("Author", 7)] {
+            let body = serde_json::json!({
+                "data": {"Aggregate": {class_name: [{"meta": {"count": count}}]}}
+            })
+            .to_string();
+            let mock = mock_server
+                .mock("POST", "/v1/graphql")
+                .match_body(mockito::Matcher::Regex(class_name.into()))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(&body)
+                .create();
+            count_mocks.push(mock);
+        }
+        let res = client.summary().await.unwrap();
+        schema_mock.assert();
+        nodes_mock.assert();
+        meta_mock.assert();
+        count_mocks.iter().for_each(|mock| mock.assert());
+        assert_eq!("1.23.7", res.version);
+        assert_eq!(vec!["Article", "Author", "Tenanted"], res.class_names());
+        assert_eq!(Some(42), res.classes[0].object_count);
+        assert_eq!(Some(7), res.classes[1].object_count);
+        assert_eq!(None, res.classes[2].object_count);
+        assert_eq!(49, res.total_objects());
+        assert_eq!(2, res.nodes.len());
+        assert_eq!(Some(NodeStatus::UNHEALTHY), res.nodes[1].status);
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/preview/echo")
+            .match_header("accept", "application/json")
+            .match_body(mockito::Matcher::Json(serde_json::json!({"name": "test"})))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"echo": {"name": "test"}}"#)
+            .create();
+        let res = client
+            .raw_request(
+                reqwest::Method::POST,
+                "/v1/preview/echo",
+                Some(serde_json::json!({"name": "test"})),
+            )
+            .await;
+        mock.assert();
+        assert_eq!("test", res.unwrap()["echo"]["name"]);
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_empty_body() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", "/v1/preview/echo")
+            .with_status(204)
+            .create();
+        let res = client
+            .raw_request(reqwest::Method::DELETE, "/v1/preview/echo", None)
+            .await;
+        mock.assert();
+        assert_eq!(serde_json::Value::Null, res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/preview/echo", 404, "").await;
+        let res = client
+            .raw_request(reqwest::Method::GET, "/v1/preview/echo", None)
+            .await;
+        mock.assert();
+        let err = res.unwrap_err();
+        let err = err.downcast_ref::<QueryError>().unwrap();
+        assert_eq!(Some(reqwest::StatusCode::NOT_FOUND), err.status);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingHook {
+        requests: AtomicUsize,
+        responses: AtomicUsize,
+    }
+
+    impl RequestHook for Arc<CountingHook> {
+        fn on_request(&self, request: &mut reqwest::Request) {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            request.headers_mut().insert(
+                "x-trace-id",
+                reqwest::header::HeaderValue::from_static("abc123"),
+            );
+        }
+
+        fn on_response(&self, response: &reqwest::Response) {
+            assert_eq!(reqwest::StatusCode::OK, response.status());
+            self.responses.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Debug)]
+    struct NoopHook;
+
+    impl RequestHook for NoopHook {}
+
+    #[tokio::test]
+    async fn test_request_hook() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let hook = Arc::new(CountingHook::default());
+        let client = WeaviateClient::builder(&host)
+            .with_hook(Box::new(NoopHook))
+            .with_hook(Box::new(Arc::clone(&hook)))
+            .build()
+            .unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .match_header("x-trace-id", "abc123")
+            .with_status(200)
+            .create();
+        let res = client.is_live().await;
+        mock.assert();
+        assert!(res.unwrap());
+        assert_eq!(1, hook.requests.load(Ordering::SeqCst));
+        assert_eq!(1, hook.responses.load(Ordering::SeqCst));
+    }
+
+    async fn assert_auth_headers(mut builder: WeaviateClientBuilder, auth: mockito::Matcher) {
+        let mut mock_server = mockito::Server::new_async().await;
+        builder.base_url = format!("http://{}", mock_server.host_with_port());
+        let client = builder.build().unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .match_header("authorization", auth)
+            .match_header("x-openai-api-key", "abcdefg")
+            .with_status(200)
+            .create();
+        let res = client.is_live().await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_auth_api_key_with_module_key() {
+        let builder = WeaviateClient::builder("")
+            .with_auth_secret("test-key")
+            .with_api_key("X-OpenAI-Api-Key", "abcdefg");
+        assert_auth_headers(builder, "Bearer test-key".into()).await;
+    }
+
+    #[tokio::test]
+    async fn test_auth_bearer_with_module_key() {
+        let builder = WeaviateClient::builder("")
+            .with_bearer_token("oidc-token")
+            .with_api_key("X-OpenAI-Api-Key", "abcdefg");
+        assert_auth_headers(builder, "Bearer oidc-token".into()).await;
+    }
+
+    #[tokio::test]
+    async fn test_auth_none_with_module_key() {
+        let builder = WeaviateClient::builder("")
+            .with_auth(Auth::None)
+            .with_api_key("X-OpenAI-Api-Key", "abcdefg");
+        assert_auth_headers(builder, mockito::Matcher::Missing).await;
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_auth_deprecated_auth_secret() {
+        let mut builder = WeaviateClient::builder("").with_api_key("X-OpenAI-Api-Key", "abcdefg");
+        builder.auth_secret = Some(AuthApiKey::new("test-key"));
+        assert_auth_headers(builder, "Bearer test-key".into()).await;
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_auth_deprecated_auth_secret_ignored_with_auth() {
+        let mut builder = WeaviateClient::builder("")
+            .with_bearer_token("oidc-token")
+            .with_api_key("X-OpenAI-Api-Key", "abcdefg");
+        builder.auth_secret = Some(AuthApiKey::new("test-key"));
+        assert_auth_headers(builder, "Bearer oidc-token".into()).await;
+    }
+
+    #[tokio::test]
+    async fn test_auth_new_with_auth_api_key() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::new(
+            &host,
+            Some(AuthApiKey::new("test-key")),
+            Some(vec![ApiKey::new("X-OpenAI-Api-Key", "abcdefg")]),
+        )
+        .unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .match_header("authorization", "Bearer test-key")
+            .match_header("x-openai-api-key", "abcdefg")
+            .with_status(200)
+            .create();
+        let res = client.is_live().await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_pool_configuration() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let builder = WeaviateClient::builder(&host)
+            .with_pool_max_idle_per_host(4)
+            .with_pool_idle_timeout(Duration::from_secs(30));
+        assert_eq!(Some(4), builder.pool_max_idle_per_host);
+        assert_eq!(Some(Duration::from_secs(30)), builder.pool_idle_timeout);
+        let client = builder.build().unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .with_status(200)
+            .expect(2)
+            .create();
+        assert!(client.is_live().await.unwrap());
+        assert!(client.is_live().await.unwrap());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_beacon_host() {
+        use collections::objects::{Reference, References};
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_beacon_host("weaviate-cluster")
+            .build()
+            .unwrap();
+        let from = uuid::Uuid::new_v4();
+        let to = uuid::Uuid::new_v4();
+        let reference = || Reference::new("Test", &from, "testProp", "Other", &to);
+        let object_mock = mock_server
+            .mock(
+                "POST",
+                format!("/v1/objects/Test/{}/references/testProp", from).as_str(),
+            )
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "beacon": format!("weaviate://weaviate-cluster/Other/{}", to),
+            })))
+            .with_status(200)
+            .create();
+        let batch_mock = mock_server
+            .mock("POST", "/v1/batch/references")
+            .match_body(mockito::Matcher::Json(serde_json::json!([{
+                "from": format!("weaviate://weaviate-cluster/Test/{}/testProp", from),
+                "to": format!("weaviate://weaviate-cluster/Other/{}", to),
+            }])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create();
+        assert!(client.objects.reference_add(reference()).await.unwrap());
+        let res = client
+            .batch
+            .references_batch_add(References::new(vec![reference()]), None, None)
+            .await;
+        assert!(res.is_ok());
+        object_mock.assert();
+        batch_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_default_consistency() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_default_consistency(ConsistencyLevel::QUORUM)
+            .build()
+            .unwrap();
+        let object = collections::objects::Object::builder("Test", serde_json::json!({})).build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let default_mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "consistency_level".into(),
+                "QUORUM".into(),
+            ))
+            .with_status(200)
+            .with_body(&object_str)
+            .create();
+        let res = client.objects.create(&object, None).await;
+        default_mock.assert();
+        assert!(res.is_ok());
+
+        let override_mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "consistency_level".into(),
+                "ONE".into(),
+            ))
+            .with_status(200)
+            .with_body(&object_str)
+            .create();
+        let res = client
+            .objects
+            .create(&object, Some(ConsistencyLevel::ONE))
+            .await;
+        override_mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_tls_configuration() {
+        let builder = WeaviateClient::builder("https://localhost:8443");
+        assert!(!builder.danger_accept_invalid_certs);
+        let builder = builder.with_danger_accept_invalid_certs(true);
+        assert!(builder.danger_accept_invalid_certs);
+        assert!(builder.build().is_ok());
+    }
+
+    /// A self-signed CA certificate, only used to check it can be loaded into the client.
+    const TEST_CA_PEM: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIBoTCCAUegAwIBAgIUaYKbbpwLW479oihvLyeoZT0/6wUwCgYIKoZIzj0EAwIw
+JTEjMCEGA1UEAwwad2VhdmlhdGUtY29tbXVuaXR5IHRlc3QgQ0EwIBcNMjYxMDE3
+MDQxMDQ3WhgPMjEyNjA5MjMwNDEwNDdaMCUxIzAhBgNVBAMMGndlYXZpYXRlLWNv
+bW11bml0eSB0ZXN0IENBMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEylXYb6Qa
+a+dif38ok0rfmV0nkCg7YwTCYdFeIQs/5wsHCQKGHy6Bf1Hrw2rTT5RbYz0FhAz1
+1b73NlVrVJOHg6NTMFEwHQYDVR0OBBYEFOyeE+8jY7bnfLKAu5utj3PC0UMLMB8G
+A1UdIwQYMBaAFOyeE+8jY7bnfLKAu5utj3PC0UMLMA8GA1UdEwEB/wQFMAMBAf8w
+CgYIKoZIzj0EAwIDSAAwRQIgCxN/ytqKeDN3VQjuNlije2caTQhtOWI/dN6MptJt
+WCMCIQD3lcUWAXkYUWtgPFN2qBICYV8zny7H2FeWN6oL7Qn8Aw==
+-----END CERTIFICATE-----";
+
+    #[test]
+    fn test_root_certificate() {
+        let certificate = reqwest::Certificate::from_pem(TEST_CA_PEM.as_bytes()).unwrap();
+        let builder =
+            WeaviateClient::builder("https://localhost:8443").with_root_certificate(certificate);
+        assert_eq!(1, builder.root_certificates.len());
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_grpc_url() {
+        let client = WeaviateClient::builder("http://localhost:8080/")
+            .build()
+            .unwrap();
+        assert_eq!("http://localhost:8080/", client.base_url.as_str());
+        assert_eq!("http://localhost:50051/", client.grpc_url.as_str());
+
+        let client = WeaviateClient::builder("https://weaviate.example.com")
+            .with_grpc_url("https://grpc-weaviate.example.com:443")
+            .build()
+            .unwrap();
+        assert_eq!("https://weaviate.example.com/", client.base_url.as_str());
+        assert_eq!(
+            "https://grpc-weaviate.example.com/",
+            client.grpc_url.as_str()
+        );
+
+        let res = WeaviateClient::builder("http://localhost:8080")
+            .with_grpc_url("localhost:50051")
+            .build();
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::InvalidRequest(_))
+        ));
+        assert!(WeaviateClient::builder("http://localhost:8080")
+            .with_grpc_url("not a url")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_invalid_auth_token() {
+        for builder in [
+            WeaviateClient::builder("http://localhost:8080").with_auth_secret("test\nkey"),
+            WeaviateClient::builder("http://localhost:8080").with_bearer_token("token\r\n"),
+        ] {
+            assert!(matches!(
+                builder.build().unwrap_err().downcast_ref::<WeaviateError>(),
+                Some(WeaviateError::InvalidRequest(_))
+            ));
+        }
+        assert!(WeaviateClient::builder("http://localhost:8080")
+            .with_auth_secret("test-key")
+            .build()
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn test_is_live_err() {
         let (mut mock_server, client) = get_test_harness().await;
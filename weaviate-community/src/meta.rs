@@ -3,6 +3,7 @@ use std::error::Error;
 use std::sync::Arc;
 
 use crate::collections::meta::Metadata;
+use crate::http::HttpClient;
 
 /// All meta related endpoints and functionality described in
 /// [Weaviate meta API documentation](https://weaviate.io/developers/weaviate/api/rest/meta)
@@ -11,13 +12,13 @@ pub struct Meta {
     /// The full URL to the Meta endpoint
     endpoint: Url,
     /// The sub-client which executes the requests - temporary
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
 }
 
 impl Meta {
     /// Create a new instance of the Meta endpoint struct. Should only be done by the parent
     /// client.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/meta/")?;
         Ok(Meta { endpoint, client })
     }
@@ -45,10 +46,105 @@ impl Meta {
     /// }
     /// ```
     pub async fn get_meta(&self) -> Result<Metadata, Box<dyn Error>> {
-        let res = self.client.get(self.endpoint.clone()).send().await?;
+        let res = self
+            .client
+            .send(self.client.get(self.endpoint.clone()))
+            .await?;
         let res: Metadata = res.json().await?;
         Ok(res)
     }
+
+    /// Check whether a module is enabled on the clients Weaviate instance.
+    ///
+    /// Useful to confirm a module such as `generative-openai` is available before issuing
+    /// queries that depend on it.
+    ///
+    /// # Parameters
+    /// - name: the name of the module
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     if client.meta.is_module_enabled("generative-openai").await? {
+    ///         // generative queries can be issued
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn is_module_enabled(&self, name: &str) -> Result<bool, Box<dyn Error>> {
+        Ok(self.get_meta().await?.is_module_enabled(name))
+    }
+
+    /// Get the configuration reported for a module on the clients Weaviate instance.
+    ///
+    /// Returns `None` if the module is not enabled.
+    ///
+    /// # Parameters
+    /// - name: the name of the module
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let config = client.meta.module_config("text2vec-cohere").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn module_config(
+        &self,
+        name: &str,
+    ) -> Result<Option<serde_json::Value>, Box<dyn Error>> {
+        Ok(self.get_meta().await?.module_config(name).cloned())
+    }
+
+    /// Get the names of the vectorizer modules enabled on the clients Weaviate instance.
+    ///
+    /// Useful to confirm the `vectorizer` of a class is available before creating it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     if !client.meta.vectorizers().await?.contains(&"text2vec-openai".to_string()) {
+    ///         // fall back to providing vectors manually
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn vectorizers(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(self.get_meta().await?.vectorizers())
+    }
+
+    /// Get the names of the generative modules enabled on the clients Weaviate instance.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let generators = client.meta.generators().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn generators(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(self.get_meta().await?.generators())
+    }
 }
 
 #[cfg(test)]
@@ -67,6 +163,10 @@ mod tests {
         let data: Metadata = serde_json::from_value(serde_json::json!({
             "hostname": "http://[::]:8080",
             "modules": {
+                "generative-openai": {
+                  "documentationHref": "https://platform.openai.com/docs/api-reference/completions",
+                  "name": "Generative Search - OpenAI"
+                },
                 "text2vec-contextionary": {
                   "version": "en0.16.0-v0.4.21",
                   "wordCount": 818072
@@ -112,4 +212,81 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_is_module_enabled_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let metadata_str = serde_json::to_string(&test_metadata()).unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/meta/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&metadata_str)
+            .expect(2)
+            .create();
+        let enabled = client.meta.is_module_enabled("generative-openai").await;
+        let missing = client.meta.is_module_enabled("text2vec-cohere").await;
+        mock.assert();
+        assert!(enabled.unwrap());
+        assert!(!missing.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_module_config_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let metadata_str = serde_json::to_string(&test_metadata()).unwrap();
+        let mock = mock_get(&mut mock_server, "/v1/meta/", 200, &metadata_str).await;
+        let res = client.meta.module_config("text2vec-contextionary").await;
+        mock.assert();
+        let config = res.unwrap().unwrap();
+        assert_eq!(818072, config["wordCount"]);
+    }
+
+    #[tokio::test]
+    async fn test_vectorizers_and_generators() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = serde_json::json!({
+            "hostname": "http://[::]:8080",
+            "modules": {
+                "generative-cohere": {
+                    "documentationHref": "https://docs.cohere.com/reference/generate",
+                    "name": "Generative Search - Cohere"
+                },
+                "generative-openai": {
+                    "name": "Generative Search - OpenAI"
+                },
+                "qna-openai": {
+                    "name": "OpenAI Question & Answering Module"
+                },
+                "ref2vec-centroid": {},
+                "reranker-cohere": {
+                    "documentationHref": "https://txt.cohere.com/rerank/",
+                    "name": "Reranker - Cohere"
+                },
+                "text2vec-cohere": {
+                    "documentationHref": "https://docs.cohere.ai/embedding-wiki/",
+                    "name": "Cohere Module"
+                },
+                "text2vec-openai": {
+                    "name": "OpenAI Module"
+                }
+            },
+            "version": "1.23.7"
+        });
+        let mock = mock_server
+            .mock("GET", "/v1/meta/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .expect(2)
+            .create();
+        let vectorizers = client.meta.vectorizers().await.unwrap();
+        let generators = client.meta.generators().await.unwrap();
+        mock.assert();
+        assert_eq!(
+            vec!["ref2vec-centroid", "text2vec-cohere", "text2vec-openai"],
+            vectorizers
+        );
+        assert_eq!(vec!["generative-cohere", "generative-openai"], generators);
+    }
 }
@@ -1,21 +1,22 @@
+use crate::collections::error::ModuleError;
+use crate::collections::modules::{ContextionaryConcept, ContextionaryExtension, SpellcheckResult};
+use crate::http::HttpClient;
 use reqwest::Url;
 use std::error::Error;
 use std::sync::Arc;
-use crate::collections::error::ModuleError;
-use crate::collections::modules::{ContextionaryConcept, ContextionaryExtension};
 
 /// All contextionary module related endpoints and functionality described in
 /// [Weaviate contextionary API documentation](https://weaviate.io/developers/weaviate/modules/retriever-vectorizer-modules/text2vec-contextionary)
 #[derive(Debug)]
 pub struct Modules {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
 }
 
 impl Modules {
     /// Create a new Modules object. The modules object is intended to like inside the 
     /// WeaviateClient and be called through the WeaviateClient.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/modules/")?;
         Ok(Modules { endpoint, client })
     }
@@ -44,7 +45,7 @@ impl Modules {
         let mut endpoint = String::from("text2vec-contextionary/concepts/");
         endpoint.push_str(concept);
         let endpoint = self.endpoint.join(&endpoint)?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
 
         match res.status() {
             reqwest::StatusCode::OK => {
@@ -76,24 +77,60 @@ impl Modules {
     /// ```
     pub async fn contextionary_extend(
         &self,
-        concept: ContextionaryExtension
+        concept: ContextionaryExtension,
     ) -> Result<ContextionaryExtension, Box<dyn Error>> {
         let endpoint = self.endpoint.join("text2vec-contextionary/extensions")?;
         let res = self
             .client
-            .post(endpoint)
-            .json(&concept)
-            .send()
+            .send(self.client.post(endpoint).json(&concept))
             .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: ContextionaryExtension = res.json().await?;
                 Ok(res)
-            },
+            }
             _ => Err(self.get_err_msg("text2vec-contextionary extend", res).await),
         }
     }
 
+    /// Spellcheck text using the text-spellcheck module.
+    ///
+    /// The text-spellcheck module must be enabled in Weaviate.
+    ///
+    /// # Parameter
+    /// - text: the text to spellcheck
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let res = client.modules.spellcheck("what is the capital of Franse?").await?;
+    ///     if res.did_change {
+    ///         println!("did you mean: {}", res.corrected);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn spellcheck(&self, text: &str) -> Result<SpellcheckResult, Box<dyn Error>> {
+        let endpoint = self.endpoint.join("text-spellcheck/spellcheck")?;
+        let payload = serde_json::json!({ "text": text });
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: SpellcheckResult = res.json().await?;
+                Ok(res)
+            }
+            _ => Err(self.get_err_msg("text-spellcheck spellcheck", res).await),
+        }
+    }
+
     /// Get the error message for the endpoint
     ///
     /// Made to reduce the boilerplate error message building
@@ -126,11 +163,9 @@ impl Modules {
 #[cfg(test)]
 mod tests {
     use crate::{
+        collections::error::ModuleError,
+        collections::modules::{ContextionaryConcept, ContextionaryExtension, IndividualWords},
         WeaviateClient,
-        collections::modules::{
-            ContextionaryExtension,
-            ContextionaryConcept, IndividualWords
-        }
     };
 
     async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
@@ -241,4 +276,47 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_spellcheck_ok() {
+        let res_str = serde_json::json!({
+            "original": "what is the capital of Franse?",
+            "didChange": true,
+            "corrected": "what is the capital of France?"
+        })
+        .to_string();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/modules/text-spellcheck/spellcheck")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "text": "what is the capital of Franse?"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client
+            .modules
+            .spellcheck("what is the capital of Franse?")
+            .await;
+        mock.assert();
+        let res = res.unwrap();
+        assert!(res.did_change);
+        assert_eq!("what is the capital of France?", res.corrected);
+    }
+
+    #[tokio::test]
+    async fn test_spellcheck_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(
+            &mut mock_server,
+            "/v1/modules/text-spellcheck/spellcheck",
+            404,
+            "",
+        )
+        .await;
+        let res = client.modules.spellcheck("test").await;
+        mock.assert();
+        assert!(res.unwrap_err().downcast_ref::<ModuleError>().is_some());
+    }
 }
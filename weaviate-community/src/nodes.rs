@@ -1,5 +1,6 @@
 use crate::collections::error::NodesError;
 use crate::collections::nodes::MultiNodes;
+use crate::http::HttpClient;
 use reqwest::Url;
 use std::error::Error;
 use std::sync::Arc;
@@ -11,13 +12,13 @@ pub struct Nodes {
     /// The full URL to the Meta endpoint
     endpoint: Url,
     /// The sub-client which executes the requests - temporary
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
 }
 
 impl Nodes {
     /// Create a new instance of the Nodes endpoint struct. Should only be done by the parent
     /// client.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/nodes/")?;
         Ok(Nodes { endpoint, client })
     }
@@ -36,7 +37,10 @@ impl Nodes {
     /// }
     /// ```
     pub async fn get_nodes_status(&self) -> Result<MultiNodes, Box<dyn Error>> {
-        let res = self.client.get(self.endpoint.clone()).send().await?;
+        let res = self
+            .client
+            .send(self.client.get(self.endpoint.clone()))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: MultiNodes = res.json().await?;
@@ -48,6 +52,23 @@ impl Nodes {
             )))),
         }
     }
+
+    /// Get the number of nodes in the Weaviate instance reporting a `HEALTHY` status.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>>{
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let healthy = client.nodes.healthy_count().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn healthy_count(&self) -> Result<usize, Box<dyn Error>> {
+        Ok(self.get_nodes_status().await?.healthy_count())
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +214,15 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_healthy_count_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mut nodes = serde_json::to_value(test_nodes()).unwrap();
+        nodes["nodes"][1]["status"] = "UNHEALTHY".into();
+        let mock = mock_get(&mut mock_server, "/v1/nodes/", 200, &nodes.to_string()).await;
+        let res = client.nodes.healthy_count().await;
+        mock.assert();
+        assert_eq!(2, res.unwrap());
+    }
 }
@@ -1,30 +1,76 @@
-use crate::collections::error::QueryError;
+use crate::collections::batch::{
+    BatchDeleteRequest, BatchDeleteResponse, BatchDeleteResult, MatchConfig,
+};
+use crate::collections::error::{QueryError, WeaviateError};
 use crate::collections::objects::{
-    ConsistencyLevel, MultiObjects, Object, ObjectListParameters, Reference,
+    beacon, parse_beacon, ConsistencyLevel, CreateOutcome, ListObjectsResponse, Object,
+    ObjectListParameters, Reference, DEFAULT_BEACON_HOST,
+};
+use crate::collections::query::{
+    check_page_depth, AggregateQuery, AggregateResponse, GraphQLResponse, WhereFilter,
+    QUERY_MAXIMUM_RESULTS,
 };
+use crate::collections::schema::Class;
+use crate::http::HttpClient;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use reqwest::Url;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{error::Error, sync::Arc};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 use uuid::Uuid;
 
+/// The maximum number of concurrent requests issued by `Objects::get_many`.
+const GET_MANY_CONCURRENCY: usize = 8;
+
+/// The number of objects requested per page by `ObjectCursor` when no limit is set.
+const CURSOR_PAGE_SIZE: u64 = 100;
+
+/// The number of objects requested per page by `Objects::export`.
+const EXPORT_PAGE_SIZE: u64 = 100;
+
 /// All objects endpoints and functionality described in
 /// [Weaviate objects API documentation](https://weaviate.io/developers/weaviate/api/rest/objects)
 #[derive(Debug)]
 pub struct Objects {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    beacon_host: String,
+    query_maximum_results: u64,
 }
 
 impl Objects {
     /// Create a new Objects endpoint orchestrator for the client.
     ///
     /// Should not be done manually.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/objects/")?;
-        Ok(Objects { endpoint, client })
+        Ok(Objects {
+            endpoint,
+            client,
+            beacon_host: DEFAULT_BEACON_HOST.into(),
+            query_maximum_results: QUERY_MAXIMUM_RESULTS,
+        })
+    }
+
+    /// Set the `QUERY_MAXIMUM_RESULTS` configured on the Weaviate instance, used to reject
+    /// `list` requests that page too deep.
+    pub(super) fn set_query_maximum_results(&mut self, query_maximum_results: u64) {
+        self.query_maximum_results = query_maximum_results;
+    }
+
+    /// Set the host used within the beacons of references.
+    pub(super) fn set_beacon_host(&mut self, beacon_host: &str) {
+        self.beacon_host = beacon_host.into();
     }
 
     /// List the data objects.
     ///
+    /// The response includes `total_results`, the number of objects matching the parameters,
+    /// which can be used to page through the objects with the `limit` and `offset` parameters.
+    ///
+    /// Requests where `offset + limit` exceeds the `QUERY_MAXIMUM_RESULTS` of the client are
+    /// rejected before being sent. Use the `after` cursor to page through deeper results.
+    ///
     /// # Parameters
     /// - parameters: the ObjectListParameters to use in the request.
     ///
@@ -46,7 +92,60 @@ impl Objects {
     pub async fn list(
         &self,
         parameters: ObjectListParameters,
-    ) -> Result<MultiObjects, Box<dyn Error>> {
+    ) -> Result<ListObjectsResponse, Box<dyn Error>> {
+        self.list_as(parameters).await
+    }
+
+    /// Page through the data objects of a class with the `after` cursor.
+    ///
+    /// The cursor starts at the `after` value of the parameters, or at the first object when it
+    /// is None, and requests `limit` objects per page (100 when the limit is None). The objects
+    /// are returned as the raw JSON of the list response, so no fields are lost.
+    ///
+    /// # Parameters
+    /// - parameters: the ObjectListParameters to use in each request. The `class_name` must be
+    ///   set, and the `offset` and `sort` must be None.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::ObjectListParameters;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let params = ObjectListParameters::builder()
+    ///         .with_class_name("Article")
+    ///         .with_include("vector")
+    ///         .build();
+    ///     let mut cursor = client.objects.cursor(params);
+    ///     while let Some(page) = cursor.next_page().await? {
+    ///         println!("{} objects", page.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn cursor(&self, parameters: ObjectListParameters) -> ObjectCursor<'_> {
+        ObjectCursor {
+            objects: self,
+            after: parameters.after.clone(),
+            parameters,
+            done: false,
+        }
+    }
+
+    /// Send a list objects request, and deserialize the response into `T`.
+    async fn list_as<T: DeserializeOwned>(
+        &self,
+        parameters: ObjectListParameters,
+    ) -> Result<T, Box<dyn Error>> {
+        parameters.validate()?;
+        check_page_depth(
+            parameters.offset,
+            parameters.limit,
+            self.query_maximum_results,
+        )
+        .map_err(QueryError::new)?;
         let mut endpoint = self.endpoint.clone();
 
         // Add the query params when they are present
@@ -62,31 +161,9 @@ impl Objects {
             endpoint
                 .query_pairs_mut()
                 .append_pair("offset", &o.to_string());
-            // Raise an err if after is some
-            if parameters.after.is_some() {
-                return Err(Box::new(QueryError(
-                    "'after' must be None when 'offset' is Some".into(),
-                )));
-            }
         }
         if let Some(a) = &parameters.after {
             endpoint.query_pairs_mut().append_pair("after", &a);
-            if parameters.after.is_none() {
-                return Err(Box::new(QueryError(
-                    "'class' must be Some when 'after' is Some".into(),
-                )));
-            }
-            // raise an error if offset or sort are some
-            if parameters.offset.is_some() {
-                return Err(Box::new(QueryError(
-                    "'offset' must be None when 'after' is Some".into(),
-                )));
-            }
-            if parameters.sort.is_some() {
-                return Err(Box::new(QueryError(
-                    "'sort' must be None when 'after' is Some".into(),
-                )));
-            }
         }
         if let Some(i) = parameters.include {
             endpoint.query_pairs_mut().append_pair("include", &i);
@@ -99,19 +176,82 @@ impl Objects {
             let values = o.join(",");
             endpoint.query_pairs_mut().append_pair("order", &values);
         }
+        if let Some(t) = parameters.tenant {
+            // multi tenancy must be enabled first
+            endpoint.query_pairs_mut().append_pair("tenant", &t);
+        }
+        if let Some(n) = parameters.node_name {
+            endpoint.query_pairs_mut().append_pair("node_name", &n);
+        }
 
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
-                let res: MultiObjects = res.json().await?;
+                let res: T = res.json().await?;
                 Ok(res)
             }
             _ => Err(self.get_err_msg("list objects", res).await),
         }
     }
 
+    /// Export every data object of a class as newline-delimited JSON.
+    ///
+    /// The objects are paged through with `cursor`, and each page is written to the writer as
+    /// soon as it is received, so memory use stays flat regardless of the class size. The objects
+    /// are written as returned by Weaviate, including their vectors and timestamps, so the output
+    /// can be read back with `Batch::import_jsonl`.
+    ///
+    /// Returns the number of objects written.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class to export
+    /// - tenant: the name of the tenant to export the objects of
+    /// - writer: where to write the objects, one JSON object per line
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let file = tokio::fs::File::create("articles.jsonl").await?;
+    ///     let count = client.objects.export("Article", None, file).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn export<W: AsyncWrite + Unpin>(
+        &self,
+        class_name: &str,
+        tenant: Option<&str>,
+        mut writer: W,
+    ) -> Result<u64, Box<dyn Error>> {
+        let mut builder = ObjectListParameters::builder()
+            .with_class_name(class_name)
+            .with_limit(EXPORT_PAGE_SIZE)
+            .with_include("vector");
+        if let Some(tenant) = tenant {
+            builder = builder.with_tenant(tenant);
+        }
+        let mut cursor = self.cursor(builder.build());
+        let mut count = 0;
+        while let Some(page) = cursor.next_page().await? {
+            for object in &page {
+                let mut line = serde_json::to_vec(object)?;
+                line.push(b'\n');
+                writer.write_all(&line).await?;
+                count += 1;
+            }
+        }
+        writer.flush().await?;
+        Ok(count)
+    }
+
     /// Create a new data object. The provided meta-data and schema values are validated.
     ///
+    /// If the server rejects the object, the field-level messages it returns are surfaced as a
+    /// `WeaviateError::ValidationError`.
+    ///
     /// When inserting a large number of objects, it is more efficient to use the `batch` insert
     /// methods.
     ///
@@ -144,23 +284,175 @@ impl Objects {
         consistency_level: Option<ConsistencyLevel>,
     ) -> Result<Object, Box<dyn Error>> {
         let mut endpoint = self.endpoint.clone();
-        if let Some(x) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", x.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
         let payload = serde_json::to_value(&new_object)?;
 
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Object = res.json().await?;
                 Ok(res)
             }
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let body = res.text().await?;
+                if body.contains("already exists") {
+                    return Err(Box::new(WeaviateError::AlreadyExists {
+                        class: new_object.class.clone(),
+                    }));
+                }
+                let messages = validation_messages(&body);
+                if !messages.is_empty() {
+                    return Err(Box::new(WeaviateError::ValidationError { messages }));
+                }
+                let status = reqwest::StatusCode::UNPROCESSABLE_ENTITY;
+                let message = format!(
+                    "Status code `{}` received when calling create object endpoint. Response: {}",
+                    status, body,
+                );
+                Err(Box::new(QueryError::with_status(status, message)))
+            }
             _ => Err(self.get_err_msg("create object", res).await)
         }
     }
 
+    /// Create a new data object, unless an object with the same id already exists.
+    ///
+    /// The existence of the id is checked first, and the object is only created when it is
+    /// absent. This is not atomic: another writer can create the same id between the check and
+    /// the create. When that happens the create is rejected as already existing, and the object
+    /// written by the other writer is fetched and returned as `CreateOutcome::Existing`, so the
+    /// call stays idempotent.
+    ///
+    /// The object must have an id set, otherwise a `WeaviateError::InvalidRequest` is returned.
+    ///
+    /// # Parameters
+    /// - new_object: the object to create
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the tenant to create the object in, overriding the tenant of `new_object`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Object;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let properties = serde_json::json!({
+    ///         "name": "Jodi Kantor",
+    ///     });
+    ///     let new = Object::builder("Publication", properties).with_id(uuid).build();
+    ///     let res = client.objects.create_if_absent(&new, None, None).await?;
+    ///     println!("created: {}", res.created());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_if_absent(
+        &self,
+        new_object: &Object,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<CreateOutcome, Box<dyn Error>> {
+        let id = match &new_object.id {
+            Some(id) => *id,
+            None => {
+                return Err(Box::new(WeaviateError::InvalidRequest(
+                    "create_if_absent requires the object to have an id".into(),
+                )))
+            }
+        };
+        let mut new_object = new_object.clone();
+        if let Some(tenant_name) = tenant_name {
+            new_object.tenant = Some(tenant_name.into());
+        }
+        let tenant_name = new_object.tenant.as_deref();
+
+        if let Some(existing) = self
+            .find(&new_object.class, &id, None, consistency_level, tenant_name)
+            .await?
+        {
+            return Ok(CreateOutcome::Existing(existing));
+        }
+        let err = match self.create(&new_object, consistency_level).await {
+            Ok(object) => return Ok(CreateOutcome::Created(object)),
+            Err(e) => e,
+        };
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::AlreadyExists { .. }) => {
+                let existing = self
+                    .get(&new_object.class, &id, None, consistency_level, tenant_name)
+                    .await?;
+                Ok(CreateOutcome::Existing(existing))
+            }
+            _ => Err(err),
+        }
+    }
+
+    /// Create a new data object, or replace it if an object with the same id already exists.
+    ///
+    /// If the object does not have an id set, this behaves the same as `create`.
+    ///
+    /// # Parameters
+    /// - new_object: the object to create or replace
+    /// - consistency_level: the consistency_level of the object
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Object;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let properties = serde_json::json!({
+    ///         "name": "Jodi Kantor",
+    ///     });
+    ///     let new = Object::builder("Publication", properties).with_id(uuid).build();
+    ///     let res = client.objects.upsert(&new, None).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upsert(
+        &self,
+        new_object: &Object,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Object, Box<dyn Error>> {
+        let err = match self.create(new_object, consistency_level).await {
+            Ok(object) => return Ok(object),
+            Err(e) => e,
+        };
+        let id = match (err.downcast_ref::<WeaviateError>(), &new_object.id) {
+            (Some(WeaviateError::AlreadyExists { .. }), Some(id)) => id,
+            _ => return Err(err),
+        };
+
+        let mut endpoint: String = new_object.class.clone();
+        endpoint.push('/');
+        endpoint.push_str(&id.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(new_object))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Object = res.json().await?;
+                Ok(res)
+            }
+            _ => Err(self.get_err_msg("upsert object", res).await),
+        }
+    }
+
     /// Collect an individual data object given it's UUID.
     ///
     /// # Parameters
@@ -197,11 +489,8 @@ impl Objects {
         endpoint.push_str("/");
         endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
         if let Some(t) = tenant_key {
             // multi tenancy must be enabled first
             endpoint.query_pairs_mut().append_pair("tenant", t);
@@ -211,7 +500,7 @@ impl Objects {
             endpoint.query_pairs_mut().append_pair("include", i);
         }
 
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Object = res.json().await?;
@@ -221,6 +510,93 @@ impl Objects {
         }
     }
 
+    /// Collect an individual data object given it's UUID, returning `None` if the object does not
+    /// exist.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class that the object belongs to
+    /// - id: the uuid of the object
+    /// - include: extra fields to include (classification, vector)
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_key: the tenant that the object is associated with
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let res = client
+    ///         .objects
+    ///         .find("TestListObject", &uuid, None, None, None).await?;
+    ///     assert!(res.is_none());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn find(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        include: Option<&str>,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_key: Option<&str>,
+    ) -> Result<Option<Object>, Box<dyn Error>> {
+        match self
+            .get(class_name, id, include, consistency_level, tenant_key)
+            .await
+        {
+            Ok(object) => Ok(Some(object)),
+            Err(err) if is_not_found(err.as_ref()) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Collect several data objects of the same class given their UUIDs.
+    ///
+    /// The objects are requested concurrently. The returned vector is in the same order as the
+    /// provided ids, with `None` in place of any object that does not exist.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class that the objects belong to
+    /// - ids: the uuids of the objects
+    /// - include: extra fields to include (classification, vector)
+    /// - consistency_level: the consistency_level of the objects
+    /// - tenant_key: the tenant that the objects are associated with
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let uuid_2 = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd304").unwrap();
+    ///     let res = client
+    ///         .objects
+    ///         .get_many("TestListObject", &[uuid, uuid_2], None, None, None).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_many(
+        &self,
+        class_name: &str,
+        ids: &[Uuid],
+        include: Option<&str>,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_key: Option<&str>,
+    ) -> Result<Vec<Option<Object>>, Box<dyn Error>> {
+        stream::iter(ids)
+            .map(|id| self.find(class_name, id, include, consistency_level, tenant_key))
+            .buffered(GET_MANY_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
     /// Check if a data object exists without returning the object itself.
     ///
     /// This works the same as the `get` method, but uses `HEAD` HTTP method.
@@ -257,17 +633,14 @@ impl Objects {
         endpoint.push_str("/");
         endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
         if let Some(t) = tenant_name {
             // multi tenancy must be enabled first
             endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
-        let res = self.client.head(endpoint).send().await?;
+        let res = self.client.send(self.client.head(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::NO_CONTENT => Ok(true),
             _ => Err(self.get_err_msg("object exists", res).await),
@@ -316,33 +689,42 @@ impl Objects {
         endpoint.push_str("/");
         endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
-        let res = self.client.patch(endpoint).json(&properties).send().await?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        let res = self
+            .client
+            .send(self.client.patch(endpoint).json(&properties))
+            .await?;
         match res.status() {
             reqwest::StatusCode::NO_CONTENT => Ok(true),
             _ => Err(self.get_err_msg("update object properties", res).await),
         }
     }
 
-    /// Replaces all property values of the data object.
+    /// Updates the given property values of the data object, if it has not changed since it was
+    /// last read.
     ///
-    /// Use the `update` method if only modifying some properties.
+    /// The current object is fetched first, and the update is only sent if its
+    /// `last_update_time_unix` matches the expected value. Otherwise, a `WeaviateError::Conflict`
+    /// is returned and the object is left untouched.
     ///
-    /// Note that if the class is configured with a vectorizer, Weaviate will only compute a new
-    /// vector for an updated object if the update changes the underlying text to be vectorized.
+    /// This check is made by the client rather than by Weaviate, so a write from another client
+    /// landing between the fetch and the update will still be overwritten. It narrows, but does
+    /// not close, the window for lost updates.
+    ///
+    /// As with `update_typed`, the properties are sent under `properties` in the request body,
+    /// along with the tenant when one is given.
     ///
     /// # Parameters
-    /// - properties: the properties to replace with
+    /// - properties: the properties to update the object with
     /// - class_name: the name of the class the object belongs to
-    /// - id: the uuid of the object to replace
+    /// - id: the uuid of the object
+    /// - expected_last_update_time_unix: the `last_update_time_unix` of the object when last read
     /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the name of the tenant the object is associated to
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
     ///
@@ -350,726 +732,3286 @@ impl Objects {
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
     ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let object = client.objects.get("Article", &uuid, None, None, None).await?;
     ///     let properties = serde_json::json!({
-    ///         "properties": {
-    ///             "name": "Jodi Kantor",
-    ///         }
+    ///         "name": "new name",
     ///     });
     ///     let res = client
     ///         .objects
-    ///         .replace(&properties, "Publication", &uuid, None).await;
+    ///         .update_if_unchanged(
+    ///             &properties,
+    ///             "Article",
+    ///             &uuid,
+    ///             object.last_update_time_unix.unwrap(),
+    ///             None,
+    ///             None,
+    ///         )
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn replace(
+    pub async fn update_if_unchanged(
         &self,
         properties: &serde_json::Value,
         class_name: &str,
         id: &Uuid,
+        expected_last_update_time_unix: u64,
         consistency_level: Option<ConsistencyLevel>,
-    ) -> Result<Object, Box<dyn Error>> {
-        let payload = serde_json::json!({
-            "class": class_name,
-            "id": id,
-            "properties": properties
-        });
-        let mut endpoint: String = class_name.into();
-        endpoint.push_str("/");
-        endpoint.push_str(&id.to_string());
-        let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
-
-        let res = self.client.put(endpoint).json(&payload).send().await?;
-        match res.status() {
-            reqwest::StatusCode::OK => {
-                let res: Object = res.json().await?;
-                Ok(res)
-            }
-            _ => Err(self.get_err_msg("replace object properties", res).await),
-        }
+        tenant_name: Option<&str>,
+    ) -> Result<bool, Box<dyn Error>> {
+        self.ensure_unchanged(
+            class_name,
+            id,
+            expected_last_update_time_unix,
+            consistency_level,
+            tenant_name,
+        )
+        .await?;
+        self.update_typed(class_name, id, properties, consistency_level, tenant_name)
+            .await
     }
 
-    /// Delete an individual data object from Weaviate.
+    /// Updates the given property values of the data object with a typed partial value.
+    ///
+    /// This is the typed variant of `update`. Only the fields that `T` serializes are sent and
+    /// merged into the object, so optional fields should be marked with
+    /// `#[serde(skip_serializing_if = "Option::is_none")]` to leave the properties they map to
+    /// untouched when `None`.
     ///
     /// # Parameters
     /// - class_name: the name of the class the object belongs to
-    /// - id: the uuid of the object to delete
+    /// - id: the uuid of the object
+    /// - partial: the properties to update the object with
     /// - consistency_level: the consistency_level of the object
     /// - tenant_name: the name of the tenant the object is associated to
     ///
     /// # Example
-    /// ```
+    /// ```no_run
+    /// use serde::Serialize;
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
     ///
+    /// #[derive(Serialize)]
+    /// struct PublicationUpdate {
+    ///     #[serde(skip_serializing_if = "Option::is_none")]
+    ///     name: Option<String>,
+    ///     #[serde(skip_serializing_if = "Option::is_none")]
+    ///     founded: Option<i64>,
+    /// }
+    ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
     ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let update = PublicationUpdate {
+    ///         name: Some("The New York Times".into()),
+    ///         founded: None,
+    ///     };
     ///     let res = client
     ///         .objects
-    ///         .delete("Article", &uuid, None, None)
-    ///         .await;
+    ///         .update_typed("Publication", &uuid, &update, None, None)
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn delete(
+    pub async fn update_typed<T: Serialize>(
         &self,
         class_name: &str,
         id: &Uuid,
+        partial: &T,
         consistency_level: Option<ConsistencyLevel>,
         tenant_name: Option<&str>,
     ) -> Result<bool, Box<dyn Error>> {
+        let mut payload = serde_json::json!({
+            "class": class_name,
+            "id": id,
+            "properties": partial
+        });
+        if let Some(t) = tenant_name {
+            // the tenant of a patched object is read from the body rather than the query
+            payload["tenant"] = t.into();
+        }
         let mut endpoint: String = class_name.into();
-        endpoint.push_str("/");
+        endpoint.push('/');
         endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
-        if let Some(t) = tenant_name {
-            // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", t);
-        }
-
-        let res = self.client.delete(endpoint).send().await?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        let res = self
+            .client
+            .send(self.client.patch(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::NO_CONTENT => Ok(true),
-            _ => Err(self.get_err_msg("delete object", res).await),
+            _ => Err(self.get_err_msg("update object properties", res).await),
         }
     }
 
-    /// Validate an object's schema and metadata without creating it.
+    /// Updates only the vector of the data object, leaving its properties untouched.
+    ///
+    /// This is useful for classes using `vectorizer: none`, where vectors are computed by an
+    /// external embedding service and need to be refreshed without resending the properties.
     ///
     /// # Parameters
-    /// - class_name: the name of the class you want to validate against
-    /// - properties: the properties you want to validate
-    /// - id: the uuid you want to set the new object
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object
+    /// - vector: the new vector of the object
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the name of the tenant the object is associated to
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let properties = serde_json::json!({
-    ///         "name": "New York Times"
-    ///     });
-    ///     let uuid = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
-    ///     let res = client.objects.validate("Publication", &properties, &uuid).await;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let res = client
+    ///         .objects
+    ///         .update_vector("Article", &uuid, vec![0.1, 0.2, 0.3], None, None)
+    ///         .await;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn validate(
+    pub async fn update_vector(
         &self,
         class_name: &str,
-        properties: &serde_json::Value,
         id: &Uuid,
+        vector: Vec<f32>,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
     ) -> Result<bool, Box<dyn Error>> {
-        let payload = serde_json::json!({
-            "class": class_name,
-            "id": id.to_string(),
-            "properties": properties
-        });
-        let endpoint = self.endpoint.join("validate")?;
-
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let mut endpoint: String = class_name.into();
+        endpoint.push('/');
+        endpoint.push_str(&id.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        let mut payload = serde_json::json!({ "vector": vector });
+        if let Some(t) = tenant_name {
+            // the tenant of a patched object is read from the body rather than the query
+            payload["tenant"] = t.into();
+        }
+        let res = self
+            .client
+            .send(self.client.patch(endpoint).json(&payload))
+            .await?;
         match res.status() {
-            reqwest::StatusCode::OK => Ok(true),
-            _ => Err(self.get_err_msg("validate object", res).await),
+            reqwest::StatusCode::NO_CONTENT => Ok(true),
+            reqwest::StatusCode::NOT_FOUND => Err(Box::new(WeaviateError::NotFound(format!(
+                "object {}/{} does not exist",
+                class_name, id
+            )))),
+            _ => Err(self.get_err_msg("update object vector", res).await),
         }
     }
 
-    /// Add a reference to the array of cross-references of the given property in the source object
-    /// specified by its class name and id.
+    /// Replaces all property values of the data object.
     ///
-    /// More on cross-references can be found [here](https://weaviate.io/developers/weaviate/config-refs/datatypes#datatype-cross-reference)
+    /// Use the `update` method if only modifying some properties.
+    ///
+    /// Note that if the class is configured with a vectorizer, Weaviate will only compute a new
+    /// vector for an updated object if the update changes the underlying text to be vectorized.
     ///
     /// # Parameters
-    /// - from_class_name: the class to add the beacon to
-    /// - from_uuid: the uuid of the object to add the beacon to
-    /// - from_property_name: the name of the property the beacon should be added to
-    /// - to_class_name: the name of the class to beacon to
-    /// - to_uuid: the uuid of the object you want to create a beacon to
-    /// - consistency_level: the consistency level to set
-    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    /// - properties: the properties to replace with
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object to replace
+    /// - consistency_level: the consistency_level of the object
     ///
     /// # Example
     /// ```
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
-    /// use weaviate_community::collections::objects::Reference;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012")?;
-    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef")?;
-    ///
-    ///     let reference = Reference::new(
-    ///         "JeopardyQuestion",
-    ///         &uuid1,
-    ///         "hasCategory",
-    ///         "JeopardyCategory",
-    ///         &uuid2,
-    ///     );
-    ///
-    ///     let res = client.objects.reference_add(reference).await;
-    ///
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let properties = serde_json::json!({
+    ///         "properties": {
+    ///             "name": "Jodi Kantor",
+    ///         }
+    ///     });
+    ///     let res = client
+    ///         .objects
+    ///         .replace(&properties, "Publication", &uuid, None).await;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn reference_add(&self, reference: Reference) -> Result<bool, Box<dyn Error>> {
-        let payload = serde_json::json!({
-            "beacon": format!("weaviate://localhost/{}/{}", reference.to_class_name, reference.to_uuid),
+    pub async fn replace(
+        &self,
+        properties: &serde_json::Value,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Object, Box<dyn Error>> {
+        self.replace_in_tenant(properties, class_name, id, consistency_level, None)
+            .await
+    }
+
+    /// Replaces all property values of the data object, associated with the tenant if one is
+    /// given.
+    async fn replace_in_tenant(
+        &self,
+        properties: &serde_json::Value,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<Object, Box<dyn Error>> {
+        let mut payload = serde_json::json!({
+            "class": class_name,
+            "id": id,
+            "properties": properties
         });
-        let mut endpoint: String = reference.from_class_name.into();
+        if let Some(t) = tenant_name {
+            payload["tenant"] = t.into();
+        }
+        let mut endpoint: String = class_name.into();
         endpoint.push_str("/");
-        endpoint.push_str(&reference.from_uuid.to_string());
-        endpoint.push_str("/references/");
-        endpoint.push_str(&reference.from_property_name.to_string());
+        endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = reference.consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
-        if let Some(t) = reference.tenant_name {
-            // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", &t);
-        }
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
 
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
         match res.status() {
-            reqwest::StatusCode::OK => Ok(true),
-            _ => Err(self.get_err_msg("add object reference", res).await),
+            reqwest::StatusCode::OK => {
+                let res: Object = res.json().await?;
+                Ok(res)
+            }
+            reqwest::StatusCode::NOT_FOUND => Err(Box::new(WeaviateError::NotFound(format!(
+                "object {}/{} does not exist",
+                class_name, id
+            )))),
+            _ => Err(self.get_err_msg("replace object properties", res).await),
         }
     }
 
-    /// Update all references in a specified property of an object specified by its class name and
-    /// id.
+    /// Replaces all property values of the data object, if it has not changed since it was last
+    /// read.
     ///
-    /// Requires the same length of to_class_names as to_uuids as input.
+    /// The current object is fetched first, and the replacement is only sent if its
+    /// `last_update_time_unix` matches the expected value. Otherwise, a `WeaviateError::Conflict`
+    /// is returned and the object is left untouched.
+    ///
+    /// As with `update_if_unchanged`, the check is made by the client, so a write landing between
+    /// the fetch and the replacement will still be overwritten.
     ///
     /// # Parameters
-    /// - from_class_name: the class that has the beacons
-    /// - from_uuid: the uuid of the object to update the beacons of
-    /// - from_property_name: the name of the property containing the beacons
-    /// - to_class_names: the names of the classes to beacon to
-    /// - to_uuids: the uuids of the objects you want to update the beacons to
-    /// - consistency_level: the consistency level to set
-    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    /// - properties: the properties to replace with
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object to replace
+    /// - expected_last_update_time_unix: the `last_update_time_unix` of the object when last read
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the name of the tenant the object is associated to
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
-    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
-    ///
-    ///     let res = client.objects.reference_update(
-    ///         "JeopardyQuestion",
-    ///         &uuid1,
-    ///         "hasCategory",
-    ///         vec!["JeopardyCategory"],
-    ///         vec![&uuid2],
-    ///         None,
-    ///         None
-    ///     ).await;
-    ///
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let object = client.objects.get("Publication", &uuid, None, None, None).await?;
+    ///     let properties = serde_json::json!({
+    ///         "name": "Jodi Kantor",
+    ///     });
+    ///     let res = client
+    ///         .objects
+    ///         .replace_if_unchanged(
+    ///             &properties,
+    ///             "Publication",
+    ///             &uuid,
+    ///             object.last_update_time_unix.unwrap(),
+    ///             None,
+    ///             None,
+    ///         )
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn reference_update(
+    pub async fn replace_if_unchanged(
         &self,
-        from_class_name: &str,
-        from_uuid: &Uuid,
-        from_property_name: &str,
-        to_class_names: Vec<&str>,
-        to_uuids: Vec<&Uuid>,
+        properties: &serde_json::Value,
+        class_name: &str,
+        id: &Uuid,
+        expected_last_update_time_unix: u64,
         consistency_level: Option<ConsistencyLevel>,
         tenant_name: Option<&str>,
     ) -> Result<Object, Box<dyn Error>> {
-        if to_class_names.len() != to_uuids.len() {
-            return Err(Box::new(QueryError(
-                "to_class_names.len() must equal to_uuids.len().".into(),
-            )));
-        }
-
-        // Match the class names to the id's in the beacon format
-        let mut beacons = Vec::new();
-        for (class_name, id) in to_class_names.iter().zip(to_uuids.iter()) {
-            beacons.push(serde_json::json!({
-                "beacon": format!("weaviate://localhost/{}/{}", class_name, id)
-            }));
-        }
-        let payload = serde_json::json!(beacons);
-
-        let mut endpoint: String = from_class_name.into();
-        endpoint.push_str("/");
-        endpoint.push_str(&from_uuid.to_string());
-        endpoint.push_str("/references/");
-        endpoint.push_str(&from_property_name.to_string());
-        let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
-        }
-        if let Some(t) = tenant_name {
-            // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", t);
-        }
+        self.ensure_unchanged(
+            class_name,
+            id,
+            expected_last_update_time_unix,
+            consistency_level,
+            tenant_name,
+        )
+        .await?;
+        self.replace_in_tenant(properties, class_name, id, consistency_level, tenant_name)
+            .await
+    }
 
-        let res = self.client.put(endpoint).json(&payload).send().await?;
-        match res.status() {
-            reqwest::StatusCode::OK => {
-                let res: Object = res.json().await?;
-                Ok(res)
-            }
-            _ => Err(self.get_err_msg("update object reference", res).await),
+    /// Check that an object has not been updated since the expected `last_update_time_unix`,
+    /// returning a `WeaviateError::Conflict` if it has.
+    async fn ensure_unchanged(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        expected_last_update_time_unix: u64,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let current = self
+            .get(class_name, id, None, consistency_level, tenant_name)
+            .await?;
+        match current.last_update_time_unix {
+            Some(actual) if actual == expected_last_update_time_unix => Ok(()),
+            actual => Err(Box::new(WeaviateError::Conflict(format!(
+                "object {}/{} was last updated at {:?}, expected {}",
+                class_name, id, actual, expected_last_update_time_unix
+            )))),
         }
     }
 
-    /// Delete the single reference that is given in the body from the list of references that the
-    /// specified property of a given object has, if it exists in the list. Will return true both
-    /// when the reference existed, and when it didn't.
+    /// Replaces all property values of the data object, creating the object if it doesn't exist.
+    ///
+    /// Depending on the Weaviate version, a PUT on a missing object responds with a 404. When
+    /// that happens, the object is created with the given id instead.
     ///
     /// # Parameters
-    /// - from_class_name: the class that has the beacons
-    /// - from_uuid: the uuid of the object to update the beacons of
-    /// - from_property_name: the name of the property containing the beacons
-    /// - to_class_name: the names of the class to remove beacon to
-    /// - to_uuid: the uuid of the object you want to remove the beacon to
-    /// - consistency_level: the consistency level to set
-    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    /// - properties: the properties to replace with
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object to replace or create
+    /// - consistency_level: the consistency_level of the object
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use uuid::Uuid;
     /// use weaviate_community::WeaviateClient;
-    /// use weaviate_community::collections::objects::Reference;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
-    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
-    ///
-    ///     let reference = Reference::new(
-    ///         "JeopardyQuestion",
-    ///         &uuid1,
-    ///         "hasCategory",
-    ///         "JeopardyCategory",
-    ///         &uuid2,
-    ///     );
-    ///
-    ///     let res = client.objects.reference_delete(reference).await;
-    ///
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let properties = serde_json::json!({
+    ///         "name": "Jodi Kantor",
+    ///     });
+    ///     let res = client
+    ///         .objects
+    ///         .put_or_create(&properties, "Publication", &uuid, None).await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn reference_delete(&self, reference: Reference) -> Result<bool, Box<dyn Error>> {
-        let payload = serde_json::json!({
-            "beacon": format!("weaviate://localhost/{}/{}", reference.to_class_name, reference.to_uuid),
-        });
-        let mut endpoint: String = reference.from_class_name.into();
-        endpoint.push_str("/");
-        endpoint.push_str(&reference.from_uuid.to_string());
-        endpoint.push_str("/references/");
-        endpoint.push_str(&reference.from_property_name.to_string());
+    pub async fn put_or_create(
+        &self,
+        properties: &serde_json::Value,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Object, Box<dyn Error>> {
+        let err = match self
+            .replace(properties, class_name, id, consistency_level)
+            .await
+        {
+            Ok(object) => return Ok(object),
+            Err(e) => e,
+        };
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::NotFound(_)) => {
+                let new_object = Object::builder(class_name, properties.clone())
+                    .with_id(*id)
+                    .build();
+                self.create(&new_object, consistency_level).await
+            }
+            _ => Err(err),
+        }
+    }
+
+    /// Replaces all property values of the data object with a typed value.
+    ///
+    /// This is the typed variant of `replace`: the properties are serialized from `T`, and the
+    /// properties of the replaced object returned by Weaviate are deserialized back into `T`.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object to replace
+    /// - properties: the properties to replace with
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the name of the tenant the object is associated to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use serde::{Deserialize, Serialize};
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Publication {
+    ///     name: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let publication = Publication { name: "The New York Times".into() };
+    ///     let res: Publication = client
+    ///         .objects
+    ///         .replace_typed("Publication", &uuid, &publication, None, None)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn replace_typed<T: Serialize + DeserializeOwned>(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        properties: &T,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<T, Box<dyn Error>> {
+        let mut payload = serde_json::json!({
+            "class": class_name,
+            "id": id,
+            "properties": properties
+        });
+        if let Some(t) = tenant_name {
+            payload["tenant"] = t.into();
+        }
+        let mut endpoint: String = class_name.into();
+        endpoint.push('/');
+        endpoint.push_str(&id.to_string());
         let mut endpoint = self.endpoint.join(&endpoint)?;
-        if let Some(cl) = reference.consistency_level {
-            endpoint
-                .query_pairs_mut()
-                .append_pair("consistency_level", &cl.value());
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Object = res.json().await?;
+                Ok(serde_json::from_value(res.properties)?)
+            }
+            reqwest::StatusCode::NOT_FOUND => Err(Box::new(WeaviateError::NotFound(format!(
+                "object {}/{} does not exist",
+                class_name, id
+            )))),
+            _ => Err(self.get_err_msg("replace object properties", res).await),
         }
-        if let Some(t) = reference.tenant_name {
+    }
+
+    /// Delete an individual data object from Weaviate.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class the object belongs to
+    /// - id: the uuid of the object to delete
+    /// - consistency_level: the consistency_level of the object
+    /// - tenant_name: the name of the tenant the object is associated to
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///     let res = client
+    ///         .objects
+    ///         .delete("Article", &uuid, None, None)
+    ///         .await;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn delete(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let mut endpoint: String = class_name.into();
+        endpoint.push_str("/");
+        endpoint.push_str(&id.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        if let Some(t) = tenant_name {
             // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", &t);
+            endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
-        let res = self.client.delete(endpoint).json(&payload).send().await?;
+        let res = self.client.send(self.client.delete(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::NO_CONTENT => Ok(true),
-            _ => Err(self.get_err_msg("delete object reference", res).await),
+            _ => Err(self.get_err_msg("delete object", res).await),
         }
     }
 
-    /// Get the error message for the endpoint
+    /// Delete multiple data objects from Weaviate in a single batch delete request.
     ///
-    /// Made to reduce the boilerplate error message building
-    async fn get_err_msg(&self, endpoint: &str, res: reqwest::Response) -> Box<QueryError> {
-        let status_code = res.status();
-        let msg: Result<serde_json::Value, reqwest::Error> = res.json().await;
-        let r_str: String;
-        if let Ok(json) = msg {
-            r_str = format!(
-                "Status code `{}` received when calling {} endpoint. Response: {}",
-                status_code,
-                endpoint,
-                json,
-            );
-        } else {
-            r_str = format!(
-                "Status code `{}` received when calling {} endpoint.",
-                status_code,
-                endpoint
-            );
+    /// The objects are matched using a `ContainsAny` filter on their `id`. An empty `ids` slice
+    /// returns an empty response without making a request.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class the objects belong to
+    /// - ids: the uuids of the objects to delete
+    /// - consistency_level: the consistency_level of the objects
+    /// - tenant_name: the name of the tenant the objects are associated to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let ids = vec![
+    ///         Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")?,
+    ///         Uuid::parse_str("6bb06a43-e7f0-393e-9ecf-3c0f4e129064")?,
+    ///     ];
+    ///     let res = client
+    ///         .objects
+    ///         .delete_many("Article", &ids, None, None)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn delete_many(
+        &self,
+        class_name: &str,
+        ids: &[Uuid],
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<BatchDeleteResponse, Box<dyn Error>> {
+        let request = BatchDeleteRequest::builder(MatchConfig::by_ids(class_name, ids)).build();
+        if ids.is_empty() {
+            return Ok(BatchDeleteResponse {
+                matches: request.matches,
+                output: None,
+                dry_run: None,
+                results: BatchDeleteResult {
+                    matches: 0,
+                    limit: 0,
+                    successful: 0,
+                    failed: 0,
+                    objects: None,
+                },
+            });
         }
-        Box::new(QueryError(r_str))
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use uuid::Uuid;
+        let mut endpoint = self.endpoint.join("/v1/batch/objects")?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        if let Some(t) = tenant_name {
+            endpoint.query_pairs_mut().append_pair("tenant", t);
+        }
 
-    use crate::{
-        collections::objects::{MultiObjects, Object, ObjectListParameters, Reference},
-        WeaviateClient,
-    };
+        let res = self
+            .client
+            .send(self.client.delete(endpoint).json(&request))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: BatchDeleteResponse = res.json().await?;
+                Ok(res)
+            }
+            _ => Err(self.get_err_msg("delete many objects", res).await),
+        }
+    }
 
-    fn test_object(class_name: &str) -> Object {
-        let properties = serde_json::json!({
-            "name": "test",
-            "number": 123,
+    /// Validate an object's schema and metadata without creating it.
+    ///
+    /// If the object is invalid, the messages returned by the server are surfaced as a
+    /// `WeaviateError::ValidationError`.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class you want to validate against
+    /// - properties: the properties you want to validate
+    /// - id: the uuid you want to set the new object
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let properties = serde_json::json!({
+    ///         "name": "New York Times"
+    ///     });
+    ///     let uuid = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    ///     let res = client.objects.validate("Publication", &properties, &uuid).await;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn validate(
+        &self,
+        class_name: &str,
+        properties: &serde_json::Value,
+        id: &Uuid,
+    ) -> Result<bool, Box<dyn Error>> {
+        let payload = serde_json::json!({
+            "class": class_name,
+            "id": id.to_string(),
+            "properties": properties
         });
-        Object::builder(class_name, properties).build()
+        let endpoint = self.endpoint.join("validate")?;
+
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => Ok(true),
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let body = res.text().await?;
+                let messages = validation_messages(&body);
+                if !messages.is_empty() {
+                    return Err(Box::new(WeaviateError::ValidationError { messages }));
+                }
+                let status = reqwest::StatusCode::UNPROCESSABLE_ENTITY;
+                let message = format!(
+                    "Status code `{}` received when calling validate object endpoint. Response: {}",
+                    status, body,
+                );
+                Err(Box::new(QueryError::with_status(status, message)))
+            }
+            _ => Err(self.get_err_msg("validate object", res).await),
+        }
+    }
+
+    /// Validate a typed object's properties and metadata without creating it.
+    ///
+    /// The properties are serialized from `properties` and validated as in `validate`, so an
+    /// invalid object surfaces the messages returned by the server as a
+    /// `WeaviateError::ValidationError`.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class you want to validate against
+    /// - properties: the typed properties you want to validate
+    /// - id: the uuid you want to set the new object
+    ///
+    /// # Example
+    /// ```no_run
+    /// use serde::Serialize;
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Publication {
+    ///     name: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let publication = Publication { name: "New York Times".into() };
+    ///     let uuid = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    ///     let res = client.objects.validate_typed("Publication", &publication, &uuid).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn validate_typed<T: Serialize>(
+        &self,
+        class_name: &str,
+        properties: &T,
+        id: &Uuid,
+    ) -> Result<bool, Box<dyn Error>> {
+        let properties = serde_json::to_value(properties)?;
+        self.validate(class_name, &properties, id).await
+    }
+
+    /// Get the cross-references held in a property of an object.
+    ///
+    /// If the object does not have the property set, an empty list is returned.
+    ///
+    /// # Parameters
+    /// - class_name: the class that has the beacons
+    /// - id: the uuid of the object to get the beacons of
+    /// - property_name: the name of the property containing the beacons
+    /// - tenant_name: the name of the tenant the object belongs to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid = Uuid::parse_str("12345678-1234-1234-1234-123456789012")?;
+    ///     let res = client
+    ///         .objects
+    ///         .get_references("JeopardyQuestion", &uuid, "hasCategory", None)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_references(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        property_name: &str,
+        tenant_name: Option<&str>,
+    ) -> Result<Vec<Reference>, Box<dyn Error>> {
+        let object = self.get(class_name, id, None, None, tenant_name).await?;
+        let beacons = match object.properties[property_name].as_array() {
+            Some(beacons) => beacons,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut references = Vec::new();
+        for item in beacons {
+            let (to_class_name, to_uuid) = match item["beacon"].as_str().and_then(parse_beacon) {
+                Some(parsed) => parsed,
+                None => {
+                    return Err(Box::new(QueryError::new(format!(
+                        "unable to parse beacon in property {}: {}",
+                        property_name, item
+                    ))))
+                }
+            };
+            let mut reference = Reference::new(
+                class_name,
+                id,
+                property_name,
+                &to_class_name.unwrap_or_default(),
+                &to_uuid,
+            );
+            reference.tenant_name = tenant_name.map(|t| t.into());
+            references.push(reference);
+        }
+        Ok(references)
+    }
+
+    /// Count the objects in a class, optionally restricted by a `where` filter.
+    ///
+    /// This issues an `Aggregate { Class { meta { count } } }` GraphQL query.
+    ///
+    /// # Parameters
+    /// - class_name: the class to count the objects of
+    /// - where_filter: the filter the counted objects must match
+    /// - tenant_name: the name of the tenant to count the objects of
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::query::{FilterOperator, WhereFilter};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let total = client.objects.count("JeopardyQuestion", None, None).await?;
+    ///
+    ///     let filter = WhereFilter::builder(vec!["points"], FilterOperator::GREATERTHAN)
+    ///         .with_value_int(200)
+    ///         .build();
+    ///     let high_value = client
+    ///         .objects
+    ///         .count("JeopardyQuestion", Some(filter), None)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn count(
+        &self,
+        class_name: &str,
+        where_filter: Option<WhereFilter>,
+        tenant_name: Option<&str>,
+    ) -> Result<u64, Box<dyn Error>> {
+        let mut query = AggregateQuery::builder(class_name).with_meta_count();
+        if let Some(filter) = where_filter {
+            query = query.with_where(&filter.to_string());
+        }
+        if let Some(tenant) = tenant_name {
+            query = query.with_tenant(tenant);
+        }
+
+        let endpoint = self.endpoint.join("/v1/graphql")?;
+        let payload = serde_json::to_value(query.build())?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: GraphQLResponse = res.json().await?;
+                match AggregateResponse::from_graphql(&res)?.meta_count() {
+                    Some(count) => Ok(count),
+                    None => Err(Box::new(QueryError::new(format!(
+                        "no meta count received for {}",
+                        class_name
+                    )))),
+                }
+            }
+            _ => Err(self.get_err_msg("count objects", res).await),
+        }
+    }
+
+    /// Add a reference to the array of cross-references of the given property in the source object
+    /// specified by its class name and id.
+    ///
+    /// If the reference has additional targets, the property is first checked to be configured
+    /// for every target class, and a beacon is then added for each target.
+    ///
+    /// Each beacon is added with a separate request, so adding a multi-target reference is not
+    /// atomic. If one of the requests fails, the beacons added before it are kept, and are listed
+    /// in the message of the returned `QueryError`.
+    ///
+    /// Weaviate does not reject a beacon that the property already contains, so adding an
+    /// existing reference again stores it twice and still returns true. Use
+    /// `reference_add_if_absent` to find out whether the reference was already there.
+    ///
+    /// More on cross-references can be found [here](https://weaviate.io/developers/weaviate/config-refs/datatypes#datatype-cross-reference)
+    ///
+    /// # Parameters
+    /// - from_class_name: the class to add the beacon to
+    /// - from_uuid: the uuid of the object to add the beacon to
+    /// - from_property_name: the name of the property the beacon should be added to
+    /// - to_class_name: the name of the class to beacon to
+    /// - to_uuid: the uuid of the object you want to create a beacon to
+    /// - consistency_level: the consistency level to set
+    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Reference;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012")?;
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef")?;
+    ///
+    ///     let reference = Reference::new(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         "JeopardyCategory",
+    ///         &uuid2,
+    ///     );
+    ///
+    ///     let res = client.objects.reference_add(reference).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_add(&self, reference: Reference) -> Result<bool, Box<dyn Error>> {
+        if !reference.additional_targets.is_empty() {
+            check_reference_targets(&self.client, &self.endpoint, &reference).await?;
+        }
+        let mut endpoint: String = reference.from_class_name.clone();
+        endpoint.push_str("/");
+        endpoint.push_str(&reference.from_uuid.to_string());
+        endpoint.push_str("/references/");
+        endpoint.push_str(&reference.from_property_name.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, reference.consistency_level);
+        if let Some(t) = &reference.tenant_name {
+            // multi tenancy must be enabled first
+            endpoint.query_pairs_mut().append_pair("tenant", t);
+        }
+
+        let mut added: Vec<String> = Vec::new();
+        for (to_class_name, to_uuid) in reference.targets() {
+            let target = beacon(&self.beacon_host, to_class_name, to_uuid);
+            let payload = serde_json::json!({ "beacon": target });
+            let res = self
+                .client
+                .send(self.client.post(endpoint.clone()).json(&payload))
+                .await?;
+            if res.status() != reqwest::StatusCode::OK {
+                let mut err = self.get_err_msg("add object reference", res).await;
+                if !added.is_empty() {
+                    err.message
+                        .push_str(&format!(" Beacons already added: {}", added.join(", ")));
+                }
+                return Err(err);
+            }
+            added.push(target);
+        }
+        Ok(true)
+    }
+
+    /// Add a reference to the given property in the source object, unless the property already
+    /// references every target.
+    ///
+    /// The current references of the property are fetched first, and only the targets that are
+    /// not yet referenced are added. Returns true if any beacon was added, and false if the call
+    /// was a no-op because every target was already referenced.
+    ///
+    /// The check is made by the client, so a concurrent `reference_add` landing between the fetch
+    /// and the add can still produce a duplicate.
+    ///
+    /// # Parameters
+    /// - reference: the reference to add
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Reference;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012")?;
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef")?;
+    ///
+    ///     let reference = Reference::new(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         "JeopardyCategory",
+    ///         &uuid2,
+    ///     );
+    ///
+    ///     if !client.objects.reference_add_if_absent(reference).await? {
+    ///         println!("the question already has this category");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_add_if_absent(
+        &self,
+        mut reference: Reference,
+    ) -> Result<bool, Box<dyn Error>> {
+        let existing = self
+            .get_references(
+                &reference.from_class_name,
+                &reference.from_uuid,
+                &reference.from_property_name,
+                reference.tenant_name.as_deref(),
+            )
+            .await?;
+        let mut missing: Vec<(String, Uuid)> = reference
+            .targets()
+            .into_iter()
+            .filter(|(class_name, id)| !references_target(&existing, class_name, id))
+            .map(|(class_name, id)| (class_name.to_string(), *id))
+            .collect();
+        if missing.is_empty() {
+            return Ok(false);
+        }
+        let (to_class_name, to_uuid) = missing.remove(0);
+        reference.to_class_name = to_class_name;
+        reference.to_uuid = to_uuid;
+        reference.additional_targets = missing;
+        self.reference_add(reference).await
+    }
+
+    /// Update all references in a specified property of an object specified by its class name and
+    /// id.
+    ///
+    /// Requires the same length of to_class_names as to_uuids as input.
+    ///
+    /// # Parameters
+    /// - from_class_name: the class that has the beacons
+    /// - from_uuid: the uuid of the object to update the beacons of
+    /// - from_property_name: the name of the property containing the beacons
+    /// - to_class_names: the names of the classes to beacon to
+    /// - to_uuids: the uuids of the objects you want to update the beacons to
+    /// - consistency_level: the consistency level to set
+    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    ///
+    ///     let res = client.objects.reference_update(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         vec!["JeopardyCategory"],
+    ///         vec![&uuid2],
+    ///         None,
+    ///         None
+    ///     ).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_update(
+        &self,
+        from_class_name: &str,
+        from_uuid: &Uuid,
+        from_property_name: &str,
+        to_class_names: Vec<&str>,
+        to_uuids: Vec<&Uuid>,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<Object, Box<dyn Error>> {
+        if to_class_names.len() != to_uuids.len() {
+            return Err(Box::new(QueryError::new(
+                "to_class_names.len() must equal to_uuids.len().",
+            )));
+        }
+        let to = to_class_names.into_iter().zip(to_uuids).collect();
+        self.reference_replace(
+            from_class_name,
+            from_uuid,
+            from_property_name,
+            to,
+            consistency_level,
+            tenant_name,
+        )
+        .await
+    }
+
+    /// Replace all references in a specified property of an object with references to exactly
+    /// the given objects.
+    ///
+    /// # Parameters
+    /// - from_class_name: the class that has the beacons
+    /// - from_uuid: the uuid of the object to replace the beacons of
+    /// - from_property_name: the name of the property containing the beacons
+    /// - to: the class name and uuid of each object to reference
+    /// - consistency_level: the consistency level to set
+    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    ///     let uuid3 = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    ///
+    ///     let res = client.objects.reference_replace(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         vec![("JeopardyCategory", &uuid2), ("JeopardyCategory", &uuid3)],
+    ///         None,
+    ///         None
+    ///     ).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_replace(
+        &self,
+        from_class_name: &str,
+        from_uuid: &Uuid,
+        from_property_name: &str,
+        to: Vec<(&str, &Uuid)>,
+        consistency_level: Option<ConsistencyLevel>,
+        tenant_name: Option<&str>,
+    ) -> Result<Object, Box<dyn Error>> {
+        let beacons: Vec<serde_json::Value> = to
+            .iter()
+            .map(|(class_name, id)| {
+                serde_json::json!({
+                    "beacon": beacon(&self.beacon_host, class_name, id)
+                })
+            })
+            .collect();
+        let payload = serde_json::json!(beacons);
+
+        let mut endpoint: String = from_class_name.into();
+        endpoint.push_str("/");
+        endpoint.push_str(&from_uuid.to_string());
+        endpoint.push_str("/references/");
+        endpoint.push_str(&from_property_name.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        if let Some(t) = tenant_name {
+            // multi tenancy must be enabled first
+            endpoint.query_pairs_mut().append_pair("tenant", t);
+        }
+
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Object = res.json().await?;
+                Ok(res)
+            }
+            _ => Err(self.get_err_msg("update object reference", res).await),
+        }
+    }
+
+    /// Delete the single reference that is given in the body from the list of references that the
+    /// specified property of a given object has, if it exists in the list. Will return true both
+    /// when the reference existed, and when it didn't, as Weaviate responds the same way to both.
+    /// Use `reference_delete_if_present` to find out whether the reference existed.
+    ///
+    /// If the reference has additional targets, the beacon of each target is deleted with a
+    /// separate request. As with `reference_add`, this is not atomic: if one of the requests
+    /// fails, the beacons deleted before it stay deleted, and are listed in the message of the
+    /// returned `QueryError`.
+    ///
+    /// # Parameters
+    /// - from_class_name: the class that has the beacons
+    /// - from_uuid: the uuid of the object to update the beacons of
+    /// - from_property_name: the name of the property containing the beacons
+    /// - to_class_name: the names of the class to remove beacon to
+    /// - to_uuid: the uuid of the object you want to remove the beacon to
+    /// - consistency_level: the consistency level to set
+    /// - tenant_name: the name of the tenant the `from_uuid` belongs to
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Reference;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    ///
+    ///     let reference = Reference::new(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         "JeopardyCategory",
+    ///         &uuid2,
+    ///     );
+    ///
+    ///     let res = client.objects.reference_delete(reference).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_delete(&self, reference: Reference) -> Result<bool, Box<dyn Error>> {
+        let mut endpoint: String = reference.from_class_name.clone();
+        endpoint.push_str("/");
+        endpoint.push_str(&reference.from_uuid.to_string());
+        endpoint.push_str("/references/");
+        endpoint.push_str(&reference.from_property_name.to_string());
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, reference.consistency_level);
+        if let Some(t) = &reference.tenant_name {
+            // multi tenancy must be enabled first
+            endpoint.query_pairs_mut().append_pair("tenant", t);
+        }
+
+        let mut deleted: Vec<String> = Vec::new();
+        for (to_class_name, to_uuid) in reference.targets() {
+            let target = beacon(&self.beacon_host, to_class_name, to_uuid);
+            let payload = serde_json::json!({ "beacon": target });
+            let res = self
+                .client
+                .send(self.client.delete(endpoint.clone()).json(&payload))
+                .await?;
+            if res.status() != reqwest::StatusCode::NO_CONTENT {
+                let mut err = self.get_err_msg("delete object reference", res).await;
+                if !deleted.is_empty() {
+                    err.message
+                        .push_str(&format!(" Beacons already deleted: {}", deleted.join(", ")));
+                }
+                return Err(err);
+            }
+            deleted.push(target);
+        }
+        Ok(true)
+    }
+
+    /// Delete a reference from the given property in the source object, if the property
+    /// references it.
+    ///
+    /// The current references of the property are fetched first, and only the targets that are
+    /// referenced are deleted. Returns true if any beacon was deleted, and false if the call was
+    /// a no-op because none of the targets were referenced.
+    ///
+    /// # Parameters
+    /// - reference: the reference to delete
+    ///
+    /// # Example
+    /// ```no_run
+    /// use uuid::Uuid;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::objects::Reference;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012")?;
+    ///     let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef")?;
+    ///
+    ///     let reference = Reference::new(
+    ///         "JeopardyQuestion",
+    ///         &uuid1,
+    ///         "hasCategory",
+    ///         "JeopardyCategory",
+    ///         &uuid2,
+    ///     );
+    ///
+    ///     if !client.objects.reference_delete_if_present(reference).await? {
+    ///         println!("the question did not have this category");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reference_delete_if_present(
+        &self,
+        mut reference: Reference,
+    ) -> Result<bool, Box<dyn Error>> {
+        let existing = self
+            .get_references(
+                &reference.from_class_name,
+                &reference.from_uuid,
+                &reference.from_property_name,
+                reference.tenant_name.as_deref(),
+            )
+            .await?;
+        let mut present: Vec<(String, Uuid)> = reference
+            .targets()
+            .into_iter()
+            .filter(|(class_name, id)| references_target(&existing, class_name, id))
+            .map(|(class_name, id)| (class_name.to_string(), *id))
+            .collect();
+        if present.is_empty() {
+            return Ok(false);
+        }
+        let (to_class_name, to_uuid) = present.remove(0);
+        reference.to_class_name = to_class_name;
+        reference.to_uuid = to_uuid;
+        reference.additional_targets = present;
+        self.reference_delete(reference).await
+    }
+
+    /// Get the error message for the endpoint
+    ///
+    /// Made to reduce the boilerplate error message building
+    async fn get_err_msg(&self, endpoint: &str, res: reqwest::Response) -> Box<QueryError> {
+        let status_code = res.status();
+        let msg: Result<serde_json::Value, reqwest::Error> = res.json().await;
+        let r_str: String;
+        if let Ok(json) = msg {
+            r_str = format!(
+                "Status code `{}` received when calling {} endpoint. Response: {}",
+                status_code,
+                endpoint,
+                json,
+            );
+        } else {
+            r_str = format!(
+                "Status code `{}` received when calling {} endpoint.",
+                status_code,
+                endpoint
+            );
+        }
+        Box::new(QueryError::with_status(status_code, r_str))
+    }
+}
+
+/// Check whether an error returned by `Objects::get` was caused by the object not existing.
+fn is_not_found(err: &(dyn Error + 'static)) -> bool {
+    matches!(
+        err.downcast_ref::<QueryError>(),
+        Some(QueryError {
+            status: Some(reqwest::StatusCode::NOT_FOUND),
+            ..
+        })
+    )
+}
+
+/// Extract the validation messages from a 422 response body.
+///
+/// Weaviate returns these as `{"error": [{"message": "..."}]}`. Any other body yields no
+/// messages.
+fn validation_messages(body: &str) -> Vec<String> {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|json| json.get("error").and_then(|e| e.as_array()).cloned())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|error| error.get("message").and_then(|m| m.as_str()))
+        .map(String::from)
+        .collect()
+}
+
+/// Check whether any of the references points to the given target.
+///
+/// Older beacons do not contain the class name, in which case only the id is compared.
+fn references_target(references: &[Reference], class_name: &str, id: &Uuid) -> bool {
+    references.iter().any(|reference| {
+        reference.to_uuid == *id
+            && (reference.to_class_name.is_empty() || reference.to_class_name == class_name)
+    })
+}
+
+/// Check the source property of a multi-target reference is configured with every target class.
+///
+/// The schema of the source class is fetched from `url`, which is resolved against the base url
+/// of the Weaviate instance.
+pub(crate) async fn check_reference_targets(
+    client: &HttpClient,
+    url: &Url,
+    reference: &Reference,
+) -> Result<(), Box<dyn Error>> {
+    let mut endpoint = String::from("/v1/schema/");
+    endpoint.push_str(&reference.from_class_name);
+    let res = client.send(client.get(url.join(&endpoint)?)).await?;
+    let class: Class = match res.status() {
+        reqwest::StatusCode::OK => res.json().await?,
+        reqwest::StatusCode::NOT_FOUND => {
+            return Err(Box::new(WeaviateError::NotFound(format!(
+                "class {} does not exist",
+                reference.from_class_name
+            ))))
+        }
+        status => {
+            return Err(Box::new(QueryError::with_status(
+                status,
+                format!(
+                    "Status code `{}` received when fetching the schema of class {}.",
+                    status, reference.from_class_name
+                ),
+            )))
+        }
+    };
+    let property = class
+        .properties
+        .iter()
+        .flat_map(|properties| properties.0.iter())
+        .find(|property| property.name == reference.from_property_name)
+        .ok_or_else(|| {
+            WeaviateError::NotFound(format!(
+                "property {}.{} does not exist",
+                reference.from_class_name, reference.from_property_name
+            ))
+        })?;
+    let missing: Vec<&str> = reference
+        .targets()
+        .into_iter()
+        .map(|(class_name, _)| class_name)
+        .filter(|class_name| !property.data_type.iter().any(|t| t == class_name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(Box::new(WeaviateError::InvalidRequest(format!(
+            "property {}.{} is not configured to reference: {}",
+            reference.from_class_name,
+            reference.from_property_name,
+            missing.join(", ")
+        ))));
+    }
+    Ok(())
+}
+
+/// A cursor over the data objects of a class, created with `Objects::cursor`.
+///
+/// Each call to `next_page` requests the objects after the last object of the previous page,
+/// until a page shorter than the limit is returned.
+#[derive(Debug)]
+pub struct ObjectCursor<'a> {
+    objects: &'a Objects,
+    parameters: ObjectListParameters,
+    after: Option<String>,
+    done: bool,
+}
+
+impl ObjectCursor<'_> {
+    /// Fetch the next page of objects, or None once every object has been returned.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<serde_json::Value>>, Box<dyn Error>> {
+        if self.done {
+            return Ok(None);
+        }
+        let limit = self.parameters.limit.unwrap_or(CURSOR_PAGE_SIZE);
+        let mut parameters = self.parameters.clone();
+        parameters.limit = Some(limit);
+        parameters.after = self.after.clone();
+        let page: RawObjectsPage = self.objects.list_as(parameters).await?;
+        let last_id = page
+            .objects
+            .last()
+            .and_then(|object| object.get("id"))
+            .and_then(|id| id.as_str());
+        match last_id {
+            Some(id) if page.objects.len() as u64 == limit => self.after = Some(id.into()),
+            _ => self.done = true,
+        }
+        if page.objects.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(page.objects))
+    }
+}
+
+/// A list objects response, with the objects kept as the raw JSON returned by Weaviate.
+#[derive(Deserialize)]
+struct RawObjectsPage {
+    #[serde(default)]
+    objects: Vec<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        collections::error::{QueryError, WeaviateError},
+        collections::objects::{
+            ConsistencyLevel, GeoCoordinates, MultiObjects, Object, ObjectListParameters,
+            PhoneNumber, Reference,
+        },
+        collections::query::{FilterOperator, WhereFilter},
+        WeaviateClient,
+    };
+
+    fn test_object(class_name: &str) -> Object {
+        let properties = serde_json::json!({
+            "name": "test",
+            "number": 123,
+        });
+        Object::builder(class_name, properties).build()
+    }
+
+    fn test_objects(class_name: &str) -> MultiObjects {
+        MultiObjects::new(vec![test_object(class_name), test_object(class_name)])
+    }
+
+    fn test_reference(uuid: &Uuid, uuid_2: &Uuid) -> Reference {
+        Reference::new("Test", uuid, "testProperty", "TestTwo", uuid_2)
+    }
+
+    fn assert_query_error_status<T: std::fmt::Debug>(
+        res: Result<T, Box<dyn std::error::Error>>,
+        status: u16,
+    ) {
+        let err = res.unwrap_err();
+        match err.downcast_ref::<QueryError>() {
+            Some(e) => assert_eq!(Some(status), e.status.map(|s| s.as_u16())),
+            None => panic!("expected a QueryError, got {}", err),
+        }
+    }
+
+    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        (mock_server, client)
+    }
+
+    async fn mock_post(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("POST", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_put(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("PUT", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_patch(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("PATCH", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_head(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("HEAD", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_get(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("GET", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_delete(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+    ) -> mockito::Mock {
+        server
+            .mock("DELETE", endpoint)
+            .with_status(status_code)
+            .create()
+    }
+
+    #[tokio::test]
+    async fn test_list_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let objects = test_objects("Test");
+        let objects_str = serde_json::to_string(&objects).unwrap();
+        let mock = mock_get(&mut mock_server, "/v1/objects/", 200, &objects_str).await;
+        let res = client.objects.list(ObjectListParameters::new()).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(objects.objects[0].class, res.unwrap().objects[0].class);
+    }
+
+    #[tokio::test]
+    async fn test_list_node_name() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let objects_str = serde_json::to_string(&test_objects("Test")).unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::Exact(
+                "class=Test&node_name=weaviate-0".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&objects_str)
+            .create();
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_node_name("weaviate-0")
+            .build();
+        let res = client.objects.list(params).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_export() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let objects: Vec<Object> = (0..101)
+            .map(|i| {
+                Object::builder("Test", serde_json::json!({"number": i}))
+                    .with_id(Uuid::new_v4())
+                    .build()
+            })
+            .collect();
+        let first_page = serde_json::json!({"objects": &objects[..100]});
+        let second_page = serde_json::json!({"objects": &objects[100..]});
+        let after = objects[99].id.unwrap().to_string();
+        let first_mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::Exact(
+                "class=Test&limit=100&include=vector&tenant=TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_body(first_page.to_string())
+            .create();
+        let second_mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("after".into(), after),
+                mockito::Matcher::UrlEncoded("tenant".into(), "TENANT_A".into()),
+            ]))
+            .with_status(200)
+            .with_body(second_page.to_string())
+            .create();
+        let mut buffer: Vec<u8> = Vec::new();
+        let res = client
+            .objects
+            .export("Test", Some("TENANT_A"), &mut buffer)
+            .await;
+        first_mock.assert();
+        second_mock.assert();
+        assert_eq!(101, res.unwrap());
+        let lines: Vec<&str> = std::str::from_utf8(&buffer).unwrap().lines().collect();
+        assert_eq!(101, lines.len());
+        let last: Object = serde_json::from_str(lines[100]).unwrap();
+        assert_eq!(objects[100].id, last.id);
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let objects = serde_json::json!([
+            {
+                "class": "Test",
+                "id": "ee22d1b8-3b95-4e94-96d5-9a2b60fbd303",
+                "properties": {"name": "first"},
+                "vector": [0.1, 0.2, 0.3],
+                "creationTimeUnix": 1700000000000u64,
+                "lastUpdateTimeUnix": 1700000000500u64,
+            },
+            {
+                "class": "Test",
+                "id": "ee22d1b8-3b95-4e94-96d5-9a2b60fbd304",
+                "properties": {"name": "second"},
+                "vector": [0.4, 0.5, 0.6],
+                "creationTimeUnix": 1700000001000u64,
+                "lastUpdateTimeUnix": 1700000001500u64,
+            },
+        ]);
+        let list_mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "include".into(),
+                "vector".into(),
+            ))
+            .with_status(200)
+            .with_body(serde_json::json!({ "objects": &objects }).to_string())
+            .create();
+        let batch_mock = mock_server
+            .mock("POST", "/v1/batch/objects")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "objects": [
+                    {"id": objects[0]["id"], "vector": objects[0]["vector"]},
+                    {"id": objects[1]["id"], "vector": objects[1]["vector"]},
+                ]
+            })))
+            .with_status(200)
+            .with_body(
+                serde_json::json!([
+                    {"class": "Test", "properties": {}, "result": {"status": "SUCCESS"}},
+                    {"class": "Test", "properties": {}, "result": {"status": "SUCCESS"}},
+                ])
+                .to_string(),
+            )
+            .create();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let count = client
+            .objects
+            .export("Test", None, &mut buffer)
+            .await
+            .unwrap();
+        assert_eq!(2, count);
+        let lines: Vec<&str> = std::str::from_utf8(&buffer).unwrap().lines().collect();
+        for (line, expected) in lines.iter().zip(objects.as_array().unwrap()) {
+            let exported: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(expected["vector"], exported["vector"]);
+            assert_eq!(expected["creationTimeUnix"], exported["creationTimeUnix"]);
+            assert_eq!(
+                expected["lastUpdateTimeUnix"],
+                exported["lastUpdateTimeUnix"]
+            );
+            let object: Object = serde_json::from_str(line).unwrap();
+            assert_eq!(
+                expected["creationTimeUnix"].as_u64(),
+                object.creation_time_unix
+            );
+            assert_eq!(
+                expected["lastUpdateTimeUnix"].as_u64(),
+                object.last_update_time_unix
+            );
+        }
+
+        let report = client
+            .batch
+            .import_jsonl(buffer.as_slice(), "Test", 100, None)
+            .await
+            .unwrap();
+        list_mock.assert();
+        batch_mock.assert();
+        assert_eq!(2, report.imported);
+        assert!(report.malformed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cursor() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let first_mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::Exact("class=Test&limit=2".into()))
+            .with_status(200)
+            .with_body(
+                serde_json::json!({"objects": [
+                    {"class": "Test", "id": "ee22d1b8-3b95-4e94-96d5-9a2b60fbd303"},
+                    {"class": "Test", "id": "ee22d1b8-3b95-4e94-96d5-9a2b60fbd304"},
+                ]})
+                .to_string(),
+            )
+            .create();
+        let second_mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "after".into(),
+                "ee22d1b8-3b95-4e94-96d5-9a2b60fbd304".into(),
+            ))
+            .with_status(200)
+            .with_body(serde_json::json!({"objects": []}).to_string())
+            .create();
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_limit(2)
+            .build();
+        let mut cursor = client.objects.cursor(params);
+        assert_eq!(2, cursor.next_page().await.unwrap().unwrap().len());
+        assert!(cursor.next_page().await.unwrap().is_none());
+        assert!(cursor.next_page().await.unwrap().is_none());
+        first_mock.assert();
+        second_mock.assert();
+    }
+
+    #[test]
+    fn test_list_parameters_after_requires_class_name() {
+        let params = ObjectListParameters::builder()
+            .with_after("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .build();
+        let err = params.validate().unwrap_err();
+        assert!(err.status.is_none());
+        assert!(err.message.contains("'class_name' must be Some"));
+    }
+
+    #[test]
+    fn test_list_parameters_after_excludes_offset() {
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_after("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .with_offset(10)
+            .build();
+        let err = params.validate().unwrap_err();
+        assert!(err.status.is_none());
+        assert!(err.message.contains("'offset' must be None"));
+    }
+
+    #[test]
+    fn test_list_parameters_after_excludes_sort() {
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_after("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .with_sort(vec!["name"])
+            .build();
+        let err = params.validate().unwrap_err();
+        assert!(err.status.is_none());
+        assert!(err.message.contains("'sort' must be None"));
+    }
+
+    #[test]
+    fn test_list_parameters_valid() {
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_after("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303")
+            .with_limit(10)
+            .build();
+        assert!(params.validate().is_ok());
+        let params = ObjectListParameters::builder()
+            .with_offset(10)
+            .with_sort(vec!["name"])
+            .build();
+        assert!(params.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_page_depth() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", mockito::Matcher::Any)
+            .expect(0)
+            .create();
+        let params = ObjectListParameters::builder()
+            .with_offset(9_990)
+            .with_limit(25)
+            .build();
+        let res = client.objects.list(params).await;
+        mock.assert();
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains("QUERY_MAXIMUM_RESULTS"));
+    }
+
+    #[tokio::test]
+    async fn test_list_total_results() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mut objects = serde_json::to_value(test_objects("Test")).unwrap();
+        objects["totalResults"] = 42.into();
+        objects["deprecations"] = serde_json::json!([{"id": "rest-meta-prop"}]);
+        let mock = mock_get(&mut mock_server, "/v1/objects/", 200, &objects.to_string()).await;
+        let res = client
+            .objects
+            .list(ObjectListParameters::new())
+            .await
+            .unwrap();
+        mock.assert();
+        assert_eq!(2, res.objects.len());
+        assert_eq!(Some(42), res.total_results);
+        assert_eq!(1, res.deprecations.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_list_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/objects/", 422, "").await;
+        let res = client.objects.list(ObjectListParameters::new()).await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_create_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_post(&mut mock_server, "/v1/objects/", 200, &object_str).await;
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(object.class, res.unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_create_json_headers() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_header("accept", "application/json")
+            .match_header("content-type", "application/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_if_absent_created() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let object = Object::builder("Test", serde_json::json!({"name": "test"}))
+            .with_id(uuid)
+            .build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let get_mock = mock_get(&mut mock_server, &url, 404, "").await;
+        let create_mock = mock_post(&mut mock_server, "/v1/objects/", 200, &object_str).await;
+        let res = client.objects.create_if_absent(&object, None, None).await;
+        get_mock.assert();
+        create_mock.assert();
+        let res = res.unwrap();
+        assert!(res.created());
+        assert_eq!(Some(uuid), res.into_object().id);
+    }
+
+    #[tokio::test]
+    async fn test_create_if_absent_existing() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let object = Object::builder("Test", serde_json::json!({"name": "test"}))
+            .with_id(uuid)
+            .build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let get_mock = mock_server
+            .mock("GET", url.as_str())
+            .match_query(mockito::Matcher::Exact("tenant=TENANT_A".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let create_mock = mock_server.mock("POST", "/v1/objects/").expect(0).create();
+        let res = client
+            .objects
+            .create_if_absent(&object, None, Some("TENANT_A"))
+            .await;
+        get_mock.assert();
+        create_mock.assert();
+        assert!(!res.unwrap().created());
+    }
+
+    #[tokio::test]
+    async fn test_create_if_absent_requires_id() {
+        let (_, client) = get_test_harness().await;
+        let res = client
+            .objects
+            .create_if_absent(&test_object("Test"), None, None)
+            .await;
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let mock = mock_post(&mut mock_server, "/v1/objects/", 422, "").await;
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_create_validation_error() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let body = serde_json::json!({"error": [
+            {"message": "invalid text property 'name' on class 'Test': not a string"},
+            {"message": "invalid object: no such prop with name 'nmae' found in class 'Test'"}
+        ]});
+        let mock = mock_post(&mut mock_server, "/v1/objects/", 422, &body.to_string()).await;
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::ValidationError { messages }) => {
+                assert_eq!(2, messages.len());
+                assert!(messages[0].contains("'name'"));
+                assert!(messages[1].contains("'nmae'"));
+            }
+            _ => panic!("expected a ValidationError, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_skips_timestamps() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mut object = test_object("Test");
+        object.creation_time_unix = Some(1);
+        object.last_update_time_unix = Some(2);
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "properties": {
+                    "name": "test",
+                    "number": 123,
+                },
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_get_non_ascii_properties() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let properties = serde_json::json!({
+            "name": "東京タワー 🗼",
+            "description": "Crème brûlée, 한국어, Ελληνικά",
+        });
+        let object = Object::builder("Test", properties.clone()).build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "properties": properties,
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json; charset=utf-8")
+            .with_body(&object_str)
+            .create();
+        let mock_two = mock_get(
+            &mut mock_server,
+            &format!("/v1/objects/Test/{}", uuid),
+            200,
+            &object_str,
+        )
+        .await;
+        let created = client.objects.create(&object, None).await.unwrap();
+        let fetched = client
+            .objects
+            .get("Test", &uuid, None, None, None)
+            .await
+            .unwrap();
+        mock.assert();
+        mock_two.assert();
+        assert_eq!(properties, created.properties);
+        assert_eq!(properties, fetched.properties);
+    }
+
+    #[tokio::test]
+    async fn test_create_geo_and_phone_properties() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let properties = serde_json::json!({
+            "location": GeoCoordinates::new(52.5, 4.5),
+            "phone": PhoneNumber::new("020 1234567").with_default_country("nl"),
+        });
+        let object = Object::builder("Test", properties).build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "properties": {
+                    "location": {"latitude": 52.5, "longitude": 4.5},
+                    "phone": {"input": "020 1234567", "defaultCountry": "nl"},
+                },
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_already_exists() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let mock = mock_post(
+            &mut mock_server,
+            "/v1/objects/",
+            422,
+            r#"{"error":[{"message":"id 'ee22d1b8-3b95-4e94-96d5-9a2b60fbd303' already exists"}]}"#,
+        )
+        .await;
+        let res = client.objects.create(&object, None).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::AlreadyExists { class }) if class == "Test"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_create_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_post(&mut mock_server, "/v1/objects/", 200, &object_str).await;
+        let res = client.objects.upsert(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_conflict_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let object = Object::builder("Test", serde_json::json!({"name": "test"}))
+            .with_id(uuid)
+            .build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let create_mock = mock_post(
+            &mut mock_server,
+            "/v1/objects/",
+            422,
+            r#"{"error":[{"message":"id already exists"}]}"#,
+        )
+        .await;
+        let replace_mock = mock_put(&mut mock_server, &url, 200, &object_str).await;
+        let res = client.objects.upsert(&object, None).await;
+        create_mock.assert();
+        replace_mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let mock = mock_post(&mut mock_server, "/v1/objects/", 500, "").await;
+        let res = client.objects.upsert(&object, None).await;
+        mock.assert();
+        assert_query_error_status(res, 500);
+    }
+
+    #[tokio::test]
+    async fn test_get_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let res = client.objects.get("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(object.class, res.unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_get_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_get(&mut mock_server, &url, 422, "").await;
+        let res = client.objects.get("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_get_not_found() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let mock = mock_get(&mut mock_server, &url, 404, "").await;
+        let res = client.objects.get("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 404);
+    }
+
+    #[tokio::test]
+    async fn test_find_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let res = client.objects.find("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert_eq!(object.class, res.unwrap().unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_find_not_found() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let mock = mock_get(&mut mock_server, &url, 404, "").await;
+        let res = client.objects.find("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert!(res.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let mock = mock_get(&mut mock_server, &url, 500, "").await;
+        let res = client.objects.find("Test", &uuid, None, None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 500);
+    }
+
+    #[tokio::test]
+    async fn test_get_many_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mut url_2 = String::from("/v1/objects/Test/");
+        url_2.push_str(&uuid_2.to_string());
+        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let mock_2 = mock_get(&mut mock_server, &url_2, 404, "").await;
+        let res = client
+            .objects
+            .get_many("Test", &[uuid_2, uuid], None, None, None)
+            .await;
+        mock.assert();
+        mock_2.assert();
+        let res = res.unwrap();
+        assert_eq!(2, res.len());
+        assert!(res[0].is_none());
+        assert!(res[1].is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_many_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_get(&mut mock_server, &url, 500, "").await;
+        let res = client
+            .objects
+            .get_many("Test", &[uuid], None, None, None)
+            .await;
+        mock.assert();
+        assert_query_error_status(res, 500);
+    }
+
+    #[tokio::test]
+    async fn test_exists_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_head(&mut mock_server, &url, 204, "").await;
+        let res = client.objects.exists("Test", &uuid, None, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_exists_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_head(&mut mock_server, &url, 422, "").await;
+        let res = client.objects.exists("Test", &uuid, None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_update_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_patch(&mut mock_server, &url, 204, "").await;
+        let res = client
+            .objects
+            .update(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_patch(&mut mock_server, &url, 422, "").await;
+        let res = client
+            .objects
+            .update(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_update_vector_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_server
+            .mock("PATCH", url.as_str())
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "vector": [0.5, 0.25]
+            })))
+            .with_status(204)
+            .create();
+        let res = client
+            .objects
+            .update_vector("Test", &uuid, vec![0.5, 0.25], None, None)
+            .await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_vector_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_patch(&mut mock_server, &url, 422, "").await;
+        let res = client
+            .objects
+            .update_vector("Test", &uuid, vec![0.5], None, Some("TENANT_A"))
+            .await;
+        mock.assert();
+        assert_query_error_status(res, 422);
+    }
+
+    #[tokio::test]
+    async fn test_update_vector_not_found() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_patch(&mut mock_server, &url, 404, "").await;
+        let res = client
+            .objects
+            .update_vector("Test", &uuid, vec![0.5], None, None)
+            .await;
+        mock.assert();
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replace_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_put(&mut mock_server, &url, 200, &object_str).await;
+        let res = client
+            .objects
+            .replace(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    fn test_updated_object(last_update_time_unix: u64) -> String {
+        // last_update_time_unix is never serialized by Object, so build the response directly
+        serde_json::json!({
+            "class": "Test",
+            "properties": {"name": "test", "number": 123},
+            "lastUpdateTimeUnix": last_update_time_unix
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_update_if_unchanged_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000000000);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &current).await;
+        let patch_mock = mock_patch(&mut mock_server, &url, 204, "").await;
+        let res = client
+            .objects
+            .update_if_unchanged(
+                &serde_json::json![{}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                None,
+            )
+            .await;
+        get_mock.assert();
+        patch_mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_if_unchanged_conflict() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000005000);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &current).await;
+        let patch_mock = mock_server.mock("PATCH", url.as_str()).expect(0).create();
+        let res = client
+            .objects
+            .update_if_unchanged(
+                &serde_json::json![{}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                None,
+            )
+            .await;
+        get_mock.assert();
+        patch_mock.assert();
+        match res.unwrap_err().downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::Conflict(_)) => {}
+            other => panic!("expected a Conflict, got {:?}", other),
+        }
     }
 
-    fn test_objects(class_name: &str) -> MultiObjects {
-        MultiObjects::new(vec![test_object(class_name), test_object(class_name)])
+    #[tokio::test]
+    async fn test_update_if_unchanged_tenant() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000000000);
+        let get_mock = mock_server
+            .mock("GET", url.as_str())
+            .match_query(mockito::Matcher::UrlEncoded(
+                "tenant".into(),
+                "TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_body(&current)
+            .create();
+        let patch_mock = mock_server
+            .mock("PATCH", url.as_str())
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "tenant": "TENANT_A",
+                "properties": {"name": "new name"}
+            })))
+            .with_status(204)
+            .create();
+        let res = client
+            .objects
+            .update_if_unchanged(
+                &serde_json::json![{"name": "new name"}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                Some("TENANT_A"),
+            )
+            .await;
+        get_mock.assert();
+        patch_mock.assert();
+        assert!(res.unwrap());
     }
 
-    fn test_reference(uuid: &Uuid, uuid_2: &Uuid) -> Reference {
-        Reference::new("Test", uuid, "testProperty", "TestTwo", uuid_2)
+    #[tokio::test]
+    async fn test_replace_if_unchanged_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000000000);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &current).await;
+        let put_mock = mock_put(&mut mock_server, &url, 200, &current).await;
+        let res = client
+            .objects
+            .replace_if_unchanged(
+                &serde_json::json![{}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                None,
+            )
+            .await;
+        get_mock.assert();
+        put_mock.assert();
+        assert!(res.is_ok());
     }
 
-    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
-        let mock_server = mockito::Server::new_async().await;
-        let mut host = "http://".to_string();
-        host.push_str(&mock_server.host_with_port());
-        let client = WeaviateClient::builder(&host).build().unwrap();
-        (mock_server, client)
+    #[tokio::test]
+    async fn test_replace_if_unchanged_conflict() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000005000);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &current).await;
+        let put_mock = mock_server.mock("PUT", url.as_str()).expect(0).create();
+        let res = client
+            .objects
+            .replace_if_unchanged(
+                &serde_json::json![{}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                None,
+            )
+            .await;
+        get_mock.assert();
+        put_mock.assert();
+        match res.unwrap_err().downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::Conflict(_)) => {}
+            other => panic!("expected a Conflict, got {:?}", other),
+        }
     }
 
-    async fn mock_post(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("POST", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[tokio::test]
+    async fn test_replace_if_unchanged_tenant() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let current = test_updated_object(1700000000000);
+        let get_mock = mock_server
+            .mock("GET", url.as_str())
+            .match_query(mockito::Matcher::UrlEncoded(
+                "tenant".into(),
+                "TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_body(&current)
+            .create();
+        let put_mock = mock_server
+            .mock("PUT", url.as_str())
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"tenant": "TENANT_A"}),
+            ))
+            .with_status(200)
+            .with_body(&current)
+            .create();
+        let res = client
+            .objects
+            .replace_if_unchanged(
+                &serde_json::json![{}],
+                "Test",
+                &uuid,
+                1700000000000,
+                None,
+                Some("TENANT_A"),
+            )
+            .await;
+        get_mock.assert();
+        put_mock.assert();
+        assert!(res.is_ok());
     }
 
-    async fn mock_put(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("PUT", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct TestProperties {
+        name: String,
+        number: i64,
     }
 
-    async fn mock_patch(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("PATCH", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[derive(serde::Serialize)]
+    struct TestPropertiesUpdate {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        number: Option<i64>,
     }
 
-    async fn mock_head(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("HEAD", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[tokio::test]
+    async fn test_update_typed_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let update = TestPropertiesUpdate {
+            name: None,
+            number: Some(7),
+        };
+        let mock = mock_server
+            .mock("PATCH", url.as_str())
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "id": uuid,
+                "properties": {"number": 7},
+                "tenant": "TENANT_A"
+            })))
+            .with_status(204)
+            .create();
+        let res = client
+            .objects
+            .update_typed("Test", &uuid, &update, None, Some("TENANT_A"))
+            .await;
+        mock.assert();
+        assert!(res.unwrap());
     }
 
-    async fn mock_get(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("GET", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[tokio::test]
+    async fn test_update_typed_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let update = TestPropertiesUpdate {
+            name: Some("test".into()),
+            number: None,
+        };
+        let mock = mock_patch(&mut mock_server, &url, 404, "").await;
+        let res = client
+            .objects
+            .update_typed("Test", &uuid, &update, None, None)
+            .await;
+        mock.assert();
+        assert_query_error_status(res, 404);
     }
 
-    async fn mock_delete(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-    ) -> mockito::Mock {
-        server
-            .mock("DELETE", endpoint)
-            .with_status(status_code)
-            .create()
+    #[tokio::test]
+    async fn test_replace_typed_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object_str = serde_json::to_string(&test_object("Test")).unwrap();
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let properties = TestProperties {
+            name: "test".into(),
+            number: 123,
+        };
+        let mock = mock_server
+            .mock("PUT", url.as_str())
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "id": uuid,
+                "properties": {"name": "test", "number": 123},
+                "tenant": "TENANT_A"
+            })))
+            .with_status(200)
+            .with_body(&object_str)
+            .create();
+        let res = client
+            .objects
+            .replace_typed("Test", &uuid, &properties, None, Some("TENANT_A"))
+            .await;
+        mock.assert();
+        assert_eq!(properties, res.unwrap());
     }
 
     #[tokio::test]
-    async fn test_list_ok() {
+    async fn test_replace_err() {
         let (mut mock_server, client) = get_test_harness().await;
-        let objects = test_objects("Test");
-        let objects_str = serde_json::to_string(&objects).unwrap();
-        let mock = mock_get(&mut mock_server, "/v1/objects/", 200, &objects_str).await;
-        let res = client.objects.list(ObjectListParameters::new()).await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_put(&mut mock_server, &url, 422, "").await;
+        let res = client
+            .objects
+            .replace(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
         mock.assert();
-        assert!(res.is_ok());
-        assert_eq!(objects.objects[0].class, res.unwrap().objects[0].class);
+        assert_query_error_status(res, 422);
     }
 
     #[tokio::test]
-    async fn test_list_err() {
+    async fn test_replace_not_found() {
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/v1/objects/", 422, "").await;
-        let res = client.objects.list(ObjectListParameters::new()).await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_put(&mut mock_server, &url, 404, "").await;
+        let res = client
+            .objects
+            .replace(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
         mock.assert();
-        assert!(res.is_err());
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::NotFound(_))
+        ));
     }
 
     #[tokio::test]
-    async fn test_create_ok() {
+    async fn test_put_or_create_existing_ok() {
         let (mut mock_server, client) = get_test_harness().await;
         let object = test_object("Test");
         let object_str = serde_json::to_string(&object).unwrap();
-        let mock = mock_post(&mut mock_server, "/v1/objects/", 200, &object_str).await;
-        let res = client.objects.create(&object, None).await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let replace_mock = mock_put(&mut mock_server, &url, 200, &object_str).await;
+        let create_mock = mock_server.mock("POST", "/v1/objects/").expect(0).create();
+        let res = client
+            .objects
+            .put_or_create(&serde_json::json![{}], "Test", &uuid, None)
+            .await;
+        replace_mock.assert();
+        create_mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_put_or_create_missing_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let object = Object::builder("Test", serde_json::json!({"name": "test"}))
+            .with_id(uuid)
+            .build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let replace_mock = mock_put(&mut mock_server, &url, 404, "").await;
+        let create_mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "class": "Test",
+                "id": uuid,
+                "properties": {"name": "test"}
+            })))
+            .with_status(200)
+            .with_body(&object_str)
+            .create();
+        let res = client
+            .objects
+            .put_or_create(&serde_json::json!({"name": "test"}), "Test", &uuid, None)
+            .await;
+        replace_mock.assert();
+        create_mock.assert();
+        assert_eq!(Some(uuid), res.unwrap().id);
+    }
+
+    #[tokio::test]
+    async fn test_delete_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_delete(&mut mock_server, &url, 204).await;
+        let res = client.objects.delete("Test", &uuid, None, None).await;
         mock.assert();
         assert!(res.is_ok());
-        assert_eq!(object.class, res.unwrap().class);
     }
 
     #[tokio::test]
-    async fn test_create_err() {
+    async fn test_consistency_level_query_string() {
         let (mut mock_server, client) = get_test_harness().await;
         let object = test_object("Test");
-        let mock = mock_post(&mut mock_server, "/v1/objects/", 422, "").await;
-        let res = client.objects.create(&object, None).await;
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let get_mock = mock_server
+            .mock("GET", url.as_str())
+            .match_query(mockito::Matcher::Exact(
+                "consistency_level=QUORUM&tenant=TENANT_A".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let delete_mock = mock_server
+            .mock("DELETE", url.as_str())
+            .match_query(mockito::Matcher::Exact("consistency_level=ALL".into()))
+            .with_status(204)
+            .create();
+        let reference_mock = mock_server
+            .mock("POST", format!("{}/references/testProperty", url).as_str())
+            .match_query(mockito::Matcher::Exact("consistency_level=ONE".into()))
+            .with_status(200)
+            .create();
+        let res = client
+            .objects
+            .get(
+                "Test",
+                &uuid,
+                None,
+                Some(ConsistencyLevel::QUORUM),
+                Some("TENANT_A"),
+            )
+            .await;
+        assert!(res.is_ok());
+        let res = client
+            .objects
+            .delete("Test", &uuid, Some(ConsistencyLevel::ALL), None)
+            .await;
+        assert!(res.is_ok());
+        let mut reference = test_reference(&uuid, &Uuid::new_v4());
+        reference.consistency_level = Some(ConsistencyLevel::ONE);
+        let res = client.objects.reference_add(reference).await;
+        assert!(res.is_ok());
+        get_mock.assert();
+        delete_mock.assert();
+        reference_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mock = mock_delete(&mut mock_server, &url, 404).await;
+        let res = client.objects.delete("Test", &uuid, None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 404);
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+        let uuid2 = Uuid::parse_str("6bb06a43-e7f0-393e-9ecf-3c0f4e129064").unwrap();
+        let matches = serde_json::json!({
+            "class": "Test",
+            "where": {
+                "operator": "ContainsAny",
+                "path": ["id"],
+                "valueTextArray": [uuid.to_string(), uuid2.to_string()]
+            }
+        });
+        let res_str = serde_json::json!({
+            "match": matches,
+            "results": {"matches": 2, "limit": 10000, "successful": 2, "failed": 0}
+        })
+        .to_string();
+        let mock = mock_server
+            .mock("DELETE", "/v1/batch/objects")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "tenant".into(),
+                "TENANT_A".into(),
+            ))
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"match": matches}),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .expect(1)
+            .create();
+        let res = client
+            .objects
+            .delete_many("Test", &[uuid, uuid2], None, Some("TENANT_A"))
+            .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_eq!(2, res.unwrap().results.successful);
     }
 
     #[tokio::test]
-    async fn test_get_ok() {
+    async fn test_delete_many_empty_ids() {
         let (mut mock_server, client) = get_test_harness().await;
-        let object = test_object("Test");
-        let object_str = serde_json::to_string(&object).unwrap();
-        let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
-        let res = client.objects.get("Test", &uuid, None, None, None).await;
+        let mock = mock_server
+            .mock("DELETE", "/v1/batch/objects")
+            .expect(0)
+            .create();
+        let res = client
+            .objects
+            .delete_many("Test", &[], None, None)
+            .await
+            .unwrap();
         mock.assert();
-        assert!(res.is_ok());
-        assert_eq!(object.class, res.unwrap().class);
+        assert_eq!(0, res.results.matches);
+        assert_eq!(0, res.results.successful);
     }
 
     #[tokio::test]
-    async fn test_get_err() {
+    async fn test_delete_many_err() {
         let (mut mock_server, client) = get_test_harness().await;
-        let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_get(&mut mock_server, &url, 422, "").await;
-        let res = client.objects.get("Test", &uuid, None, None, None).await;
+        let mock = mock_delete(&mut mock_server, "/v1/batch/objects", 422).await;
+        let res = client
+            .objects
+            .delete_many("Test", &[Uuid::new_v4()], None, None)
+            .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_query_error_status(res, 422);
     }
 
     #[tokio::test]
-    async fn test_exists_ok() {
+    async fn test_validate_validation_error() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_head(&mut mock_server, &url, 204, "").await;
-        let res = client.objects.exists("Test", &uuid, None, None).await;
+        let body = serde_json::json!({"error": [
+            {"message": "invalid object: no such prop with name 'nmae' found in class 'Test'"}
+        ]});
+        let mock = mock_post(
+            &mut mock_server,
+            "/v1/objects/validate",
+            422,
+            &body.to_string(),
+        )
+        .await;
+        let res = client
+            .objects
+            .validate("Test", &serde_json::json![{"nmae": "test"}], &uuid)
+            .await;
         mock.assert();
-        assert!(res.is_ok());
-        assert!(res.unwrap());
+        let err = res.unwrap_err();
+        assert!(err.to_string().contains("no such prop with name 'nmae'"));
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::ValidationError { .. })
+        ));
     }
 
     #[tokio::test]
-    async fn test_exists_err() {
+    async fn test_validate_typed_validation_error() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_head(&mut mock_server, &url, 422, "").await;
-        let res = client.objects.exists("Test", &uuid, None, None).await;
+        let properties = TestProperties {
+            name: "test".into(),
+            number: -1,
+        };
+        let body = serde_json::json!({"error": [
+            {"message": "invalid object: invalid integer property 'number' on class 'Test'"},
+            {"message": "invalid object: no such prop with name 'name' found in class 'Test'"}
+        ]});
+        let mock = mock_server
+            .mock("POST", "/v1/objects/validate")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "class": "Test",
+                "id": uuid,
+                "properties": {"name": "test", "number": -1}
+            })))
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create();
+        let res = client
+            .objects
+            .validate_typed("Test", &properties, &uuid)
+            .await;
         mock.assert();
-        assert!(res.is_err());
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::ValidationError { messages }) => {
+                assert_eq!(2, messages.len());
+                assert!(messages[0].contains("invalid integer property 'number'"));
+            }
+            _ => panic!("expected a ValidationError, got {}", err),
+        }
     }
 
     #[tokio::test]
-    async fn test_update_ok() {
+    async fn test_validate_ok() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_patch(&mut mock_server, &url, 204, "").await;
+        let mock = mock_post(&mut mock_server, "/v1/objects/validate", 200, "").await;
         let res = client
             .objects
-            .update(&serde_json::json![{}], "Test", &uuid, None)
+            .validate("Test", &serde_json::json![{}], &uuid)
             .await;
         mock.assert();
         assert!(res.is_ok());
     }
 
     #[tokio::test]
-    async fn test_update_err() {
+    async fn test_validate_err() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mut url = String::from("/v1/objects/Test/");
-        url.push_str(&uuid.to_string());
-        let mock = mock_patch(&mut mock_server, &url, 422, "").await;
+        let mock = mock_post(&mut mock_server, "/v1/objects/validate", 404, "").await;
         let res = client
             .objects
-            .update(&serde_json::json![{}], "Test", &uuid, None)
+            .validate("Test", &serde_json::json![{}], &uuid)
             .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_query_error_status(res, 404);
     }
 
     #[tokio::test]
-    async fn test_replace_ok() {
+    async fn test_reference_add_ok() {
         let (mut mock_server, client) = get_test_harness().await;
-        let object = test_object("Test");
-        let object_str = serde_json::to_string(&object).unwrap();
         let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
-        let mock = mock_put(&mut mock_server, &url, 200, &object_str).await;
+        url.push_str("/references/testProperty");
+        let mock = mock_post(&mut mock_server, &url, 200, "").await;
         let res = client
             .objects
-            .replace(&serde_json::json![{}], "Test", &uuid, None)
+            .reference_add(test_reference(&uuid, &uuid_2))
             .await;
         mock.assert();
         assert!(res.is_ok());
+        assert!(res.unwrap());
     }
 
     #[tokio::test]
-    async fn test_replace_err() {
+    async fn test_reference_beacons() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
-        let mock = mock_put(&mut mock_server, &url, 422, "").await;
+        url.push_str("/references/testProperty");
+        let mut beacon = String::from("weaviate://localhost/TestTwo/");
+        beacon.push_str(&uuid_2.to_string());
+
+        let add_mock = mock_server
+            .mock("POST", url.as_str())
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"beacon": beacon}),
+            ))
+            .with_status(200)
+            .create();
+        let update_mock = mock_server
+            .mock("PUT", url.as_str())
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!([{"beacon": beacon}]),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&test_object("Test")).unwrap())
+            .create();
+        let delete_mock = mock_server
+            .mock("DELETE", url.as_str())
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"beacon": beacon}),
+            ))
+            .with_status(204)
+            .create();
+
         let res = client
             .objects
-            .replace(&serde_json::json![{}], "Test", &uuid, None)
+            .reference_add(test_reference(&uuid, &uuid_2))
             .await;
-        mock.assert();
-        assert!(res.is_err());
+        assert!(res.is_ok());
+        let res = client
+            .objects
+            .reference_update(
+                "Test",
+                &uuid,
+                "testProperty",
+                vec!["TestTwo"],
+                vec![&uuid_2],
+                None,
+                None,
+            )
+            .await;
+        assert!(res.is_ok());
+        let res = client
+            .objects
+            .reference_delete(test_reference(&uuid, &uuid_2))
+            .await;
+        assert!(res.is_ok());
+        add_mock.assert();
+        update_mock.assert();
+        delete_mock.assert();
+    }
+
+    fn test_multi_target_schema() -> String {
+        serde_json::json!({
+            "class": "Test",
+            "properties": [
+                {"name": "testProperty", "dataType": ["TestTwo", "TestThree"]}
+            ]
+        })
+        .to_string()
     }
 
     #[tokio::test]
-    async fn test_delete_ok() {
+    async fn test_reference_add_multi_target() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let uuid_3 = Uuid::new_v4();
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
-        let mock = mock_delete(&mut mock_server, &url, 204).await;
-        let res = client.objects.delete("Test", &uuid, None, None).await;
-        mock.assert();
-        assert!(res.is_ok());
+        url.push_str("/references/testProperty");
+        let schema = test_multi_target_schema();
+        let schema_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &schema).await;
+        let mut mocks = Vec::new();
+        for (class_name, id) in [("TestTwo", &uuid_2), ("TestThree", &uuid_3)] {
+            let beacon = format!("weaviate://localhost/{}/{}", class_name, id);
+            mocks.push(
+                mock_server
+                    .mock("POST", url.as_str())
+                    .match_body(mockito::Matcher::Json(
+                        serde_json::json!({"beacon": beacon}),
+                    ))
+                    .with_status(200)
+                    .create(),
+            );
+        }
+        let reference = Reference::builder("Test", &uuid, "testProperty", "TestTwo", &uuid_2)
+            .with_additional_target("TestThree", &uuid_3)
+            .build();
+        let res = client.objects.reference_add(reference).await;
+        schema_mock.assert();
+        for mock in mocks {
+            mock.assert();
+        }
+        assert!(res.unwrap());
     }
 
     #[tokio::test]
-    async fn test_delete_err() {
+    async fn test_reference_add_multi_target_partial_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let uuid_3 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}/references/testProperty", uuid);
+        let schema = test_multi_target_schema();
+        let schema_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &schema).await;
+        let added = format!("weaviate://localhost/TestTwo/{}", uuid_2);
+        let added_mock = mock_server
+            .mock("POST", url.as_str())
+            .match_body(mockito::Matcher::Json(serde_json::json!({"beacon": added})))
+            .with_status(200)
+            .create();
+        let failed = format!("weaviate://localhost/TestThree/{}", uuid_3);
+        let failed_mock = mock_server
+            .mock("POST", url.as_str())
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"beacon": failed}),
+            ))
+            .with_status(500)
+            .create();
+        let reference = Reference::builder("Test", &uuid, "testProperty", "TestTwo", &uuid_2)
+            .with_additional_target("TestThree", &uuid_3)
+            .build();
+        let res = client.objects.reference_add(reference).await;
+        schema_mock.assert();
+        added_mock.assert();
+        failed_mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<QueryError>() {
+            Some(e) => {
+                assert_eq!(Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR), e.status);
+                assert!(e.message.ends_with(&added));
+            }
+            None => panic!("expected a QueryError, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reference_add_multi_target_not_configured() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
-        let mock = mock_delete(&mut mock_server, &url, 404).await;
-        let res = client.objects.delete("Test", &uuid, None, None).await;
-        mock.assert();
-        assert!(res.is_err());
+        url.push_str("/references/testProperty");
+        let schema = test_multi_target_schema();
+        let schema_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &schema).await;
+        let add_mock = mock_server.mock("POST", url.as_str()).expect(0).create();
+        let uuid_2 = Uuid::new_v4();
+        let reference = Reference::builder("Test", &uuid, "testProperty", "TestTwo", &uuid_2)
+            .with_additional_target("Other", &Uuid::new_v4())
+            .build();
+        let res = client.objects.reference_add(reference).await;
+        schema_mock.assert();
+        add_mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::InvalidRequest(msg)) => assert!(msg.ends_with("Other")),
+            _ => panic!("expected an InvalidRequest error, got {}", err),
+        }
     }
 
     #[tokio::test]
-    async fn test_validate_ok() {
+    async fn test_get_references_ok() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mock = mock_post(&mut mock_server, "/v1/objects/validate", 200, "").await;
+        let uuid_2 = Uuid::new_v4();
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let mut beacon = String::from("weaviate://localhost/TestTwo/");
+        beacon.push_str(&uuid_2.to_string());
+        let object = Object::builder(
+            "Test",
+            serde_json::json!({
+                "name": "test",
+                "testProperty": [{"beacon": beacon}],
+            }),
+        )
+        .build();
+        let object_str = serde_json::to_string(&object).unwrap();
+        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
         let res = client
             .objects
-            .validate("Test", &serde_json::json![{}], &uuid)
-            .await;
+            .get_references("Test", &uuid, "testProperty", None)
+            .await
+            .unwrap();
         mock.assert();
-        assert!(res.is_ok());
+        assert_eq!(1, res.len());
+        assert_eq!("TestTwo", res[0].to_class_name);
+        assert_eq!(uuid_2, res[0].to_uuid);
+        assert_eq!("testProperty", res[0].from_property_name);
     }
 
     #[tokio::test]
-    async fn test_validate_err() {
+    async fn test_get_references_missing_property() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let mock = mock_post(&mut mock_server, "/v1/objects/validate", 404, "").await;
+        let mut url = String::from("/v1/objects/Test/");
+        url.push_str(&uuid.to_string());
+        let object_str = serde_json::to_string(&test_object("Test")).unwrap();
+        let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
         let res = client
             .objects
-            .validate("Test", &serde_json::json![{}], &uuid)
-            .await;
+            .get_references("Test", &uuid, "testProperty", None)
+            .await
+            .unwrap();
         mock.assert();
-        assert!(res.is_err());
+        assert!(res.is_empty());
     }
 
     #[tokio::test]
-    async fn test_reference_add_ok() {
+    async fn test_get_references_err() {
         let (mut mock_server, client) = get_test_harness().await;
         let uuid = Uuid::new_v4();
-        let uuid_2 = Uuid::new_v4();
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
-        url.push_str("/references/testProperty");
-        let mock = mock_post(&mut mock_server, &url, 200, "").await;
+        let mock = mock_get(&mut mock_server, &url, 404, "").await;
         let res = client
             .objects
-            .reference_add(test_reference(&uuid, &uuid_2))
+            .get_references("Test", &uuid, "testProperty", None)
             .await;
         mock.assert();
-        assert!(res.is_ok());
-        assert!(res.unwrap());
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_count_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let res_str = serde_json::json!({
+            "data": {"Aggregate": {"Test": [{"meta": {"count": 42}}]}}
+        })
+        .to_string();
+        let mock = mock_server
+            .mock("POST", "/v1/graphql")
+            .match_body(mockito::Matcher::Regex("meta\\{count\\}".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let res = client.objects.count("Test", None, None).await;
+        mock.assert();
+        assert_eq!(42, res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_count_where_filter() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let res_str = serde_json::json!({
+            "data": {"Aggregate": {"Test": [{"meta": {"count": 3}}]}}
+        })
+        .to_string();
+        let mock = mock_server
+            .mock("POST", "/v1/graphql")
+            .match_body(mockito::Matcher::Regex("where: \\{".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&res_str)
+            .create();
+        let filter = WhereFilter::builder(vec!["number"], FilterOperator::GREATERTHAN)
+            .with_value_int(100)
+            .build();
+        let res = client.objects.count("Test", Some(filter), None).await;
+        mock.assert();
+        assert_eq!(3, res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_count_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/graphql", 422, "").await;
+        let res = client.objects.count("Test", None, None).await;
+        mock.assert();
+        assert_query_error_status(res, 422);
     }
 
     #[tokio::test]
@@ -1086,7 +4028,7 @@ mod tests {
             .reference_add(test_reference(&uuid, &uuid_2))
             .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_query_error_status(res, 404);
     }
 
     #[tokio::test]
@@ -1116,6 +4058,41 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_reference_replace_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let uuid_3 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}/references/testProperty", uuid);
+        let mock = mock_server
+            .mock("PUT", url.as_str())
+            .match_query(mockito::Matcher::Exact("tenant=TENANT_A".into()))
+            .match_body(mockito::Matcher::Json(serde_json::json!([
+                {"beacon": format!("weaviate://localhost/TestTwo/{}", uuid_2)},
+                {"beacon": format!("weaviate://localhost/TestThree/{}", uuid_3)},
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let res = client
+            .objects
+            .reference_replace(
+                "Test",
+                &uuid,
+                "testProperty",
+                vec![("TestTwo", &uuid_2), ("TestThree", &uuid_3)],
+                None,
+                Some("TENANT_A"),
+            )
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_reference_update_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -1138,7 +4115,7 @@ mod tests {
             )
             .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_query_error_status(res, 404);
     }
 
     #[tokio::test]
@@ -1159,6 +4136,171 @@ mod tests {
         assert!(res.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_reference_delete_multi_target() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let uuid_3 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}/references/testProperty", uuid);
+        let mut mocks = Vec::new();
+        for (class_name, id) in [("TestTwo", &uuid_2), ("TestThree", &uuid_3)] {
+            let beacon = format!("weaviate://localhost/{}/{}", class_name, id);
+            mocks.push(
+                mock_server
+                    .mock("DELETE", url.as_str())
+                    .match_body(mockito::Matcher::Json(
+                        serde_json::json!({"beacon": beacon}),
+                    ))
+                    .with_status(204)
+                    .create(),
+            );
+        }
+        let reference = Reference::builder("Test", &uuid, "testProperty", "TestTwo", &uuid_2)
+            .with_additional_target("TestThree", &uuid_3)
+            .build();
+        let res = client.objects.reference_delete(reference).await;
+        for mock in mocks {
+            mock.assert();
+        }
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reference_delete_if_present_multi_target() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let uuid_3 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let present = format!("weaviate://localhost/TestThree/{}", uuid_3);
+        let object_str = test_referencing_object(vec![present.clone()]);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let delete_url = format!("{}/references/testProperty", url);
+        let delete_mock = mock_server
+            .mock("DELETE", delete_url.as_str())
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"beacon": present}),
+            ))
+            .with_status(204)
+            .expect(1)
+            .create();
+        let reference = Reference::builder("Test", &uuid, "testProperty", "TestTwo", &uuid_2)
+            .with_additional_target("TestThree", &uuid_3)
+            .build();
+        let res = client.objects.reference_delete_if_present(reference).await;
+        get_mock.assert();
+        delete_mock.assert();
+        assert!(res.unwrap());
+    }
+
+    fn test_referencing_object(beacons: Vec<String>) -> String {
+        let beacons: Vec<_> = beacons
+            .iter()
+            .map(|beacon| serde_json::json!({"beacon": beacon}))
+            .collect();
+        let object = Object::builder(
+            "Test",
+            serde_json::json!({
+                "name": "test",
+                "testProperty": beacons,
+            }),
+        )
+        .build();
+        serde_json::to_string(&object).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reference_add_if_absent_existing() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let object_str =
+            test_referencing_object(vec![format!("weaviate://localhost/TestTwo/{}", uuid_2)]);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let post_url = format!("{}/references/testProperty", url);
+        let post_mock = mock_server
+            .mock("POST", post_url.as_str())
+            .expect(0)
+            .create();
+        let res = client
+            .objects
+            .reference_add_if_absent(test_reference(&uuid, &uuid_2))
+            .await;
+        get_mock.assert();
+        post_mock.assert();
+        assert!(!res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reference_add_if_absent_missing() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let object_str =
+            test_referencing_object(vec![format!("weaviate://localhost/TestTwo/{}", uuid)]);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let post_url = format!("{}/references/testProperty", url);
+        let post_mock = mock_server
+            .mock("POST", post_url.as_str())
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "beacon": format!("weaviate://localhost/TestTwo/{}", uuid_2)
+            })))
+            .with_status(200)
+            .create();
+        let res = client
+            .objects
+            .reference_add_if_absent(test_reference(&uuid, &uuid_2))
+            .await;
+        get_mock.assert();
+        post_mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reference_delete_if_present_existing() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        // older beacons do not contain the class name
+        let object_str = test_referencing_object(vec![format!("weaviate://localhost/{}", uuid_2)]);
+        let get_mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let delete_url = format!("{}/references/testProperty", url);
+        let delete_mock = mock_delete(&mut mock_server, &delete_url, 204).await;
+        let res = client
+            .objects
+            .reference_delete_if_present(test_reference(&uuid, &uuid_2))
+            .await;
+        get_mock.assert();
+        delete_mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reference_delete_if_present_missing() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let uuid = Uuid::new_v4();
+        let uuid_2 = Uuid::new_v4();
+        let url = format!("/v1/objects/Test/{}", uuid);
+        let object_str = serde_json::to_string(&test_object("Test")).unwrap();
+        let get_mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
+        let delete_url = format!("{}/references/testProperty", url);
+        let delete_mock = mock_server
+            .mock("DELETE", delete_url.as_str())
+            .expect(0)
+            .create();
+        let res = client
+            .objects
+            .reference_delete_if_present(test_reference(&uuid, &uuid_2))
+            .await;
+        get_mock.assert();
+        delete_mock.assert();
+        assert!(!res.unwrap());
+    }
+
     #[tokio::test]
     async fn test_reference_delete_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -1173,6 +4315,6 @@ mod tests {
             .reference_delete(test_reference(&uuid, &uuid_2))
             .await;
         mock.assert();
-        assert!(res.is_err());
+        assert_query_error_status(res, 404);
     }
 }
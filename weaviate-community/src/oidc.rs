@@ -1,21 +1,37 @@
 /// https://weaviate.io/developers/weaviate/api/rest/well-known
 use reqwest::Url;
+use serde::de::DeserializeOwned;
 use std::error::Error;
 use std::sync::Arc;
 
 use crate::collections::error::NotConfiguredError;
-use crate::collections::oidc::OidcResponse;
+use crate::collections::oidc::{
+    OidcConfig, OidcProviderMetadata, OidcResponse, OidcScopedResponse,
+};
+use crate::http::HttpClient;
 
 #[derive(Debug)]
 pub struct Oidc {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    /// A client with the TLS, timeout and pool settings of the `WeaviateClient`, but without its
+    /// authentication, headers and hooks, used for requests to the token issuer so the Weaviate
+    /// credentials are never sent to it.
+    issuer_client: reqwest::Client,
 }
 
 impl Oidc {
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
-        let endpoint = url.join("/v1/.well-known")?;
-        Ok(Oidc { endpoint, client })
+    pub(super) fn new(
+        url: &Url,
+        client: Arc<HttpClient>,
+        issuer_client: reqwest::Client,
+    ) -> Result<Self, Box<dyn Error>> {
+        let endpoint = url.join("/v1/.well-known/")?;
+        Ok(Oidc {
+            endpoint,
+            client,
+            issuer_client,
+        })
     }
 
     /// Get OIDC information if OpenID Connect (OIDC) authentication is enabled. The endpoint
@@ -31,11 +47,16 @@ impl Oidc {
     /// ```
     /// ```
     pub async fn get_open_id_configuration(&self) -> Result<OidcResponse, Box<dyn Error>> {
-        let endpoint = self.endpoint.join("/openid-configuration")?;
-        let resp = self.client.get(endpoint).send().await?;
+        self.open_id_configuration().await
+    }
+
+    /// Collect the OIDC information from Weaviate, deserialized into `T`.
+    async fn open_id_configuration<T: DeserializeOwned>(&self) -> Result<T, Box<dyn Error>> {
+        let endpoint = self.endpoint.join("openid-configuration")?;
+        let resp = self.client.send(self.client.get(endpoint)).await?;
         match resp.status() {
             reqwest::StatusCode::OK => {
-                let parsed: OidcResponse = resp.json::<OidcResponse>().await?;
+                let parsed: T = resp.json::<T>().await?;
                 Ok(parsed)
             }
             _ => Err(Box::new(NotConfiguredError(
@@ -43,11 +64,49 @@ impl Oidc {
             ))),
         }
     }
+
+    /// Get the typed OIDC configuration needed to retrieve a token.
+    ///
+    /// This first collects the OIDC information from Weaviate, then the OpenID provider metadata
+    /// of the token issuer referenced by its `href`. The issuer is a third party, so it is
+    /// requested without the authentication, api key headers or hooks set on the client, though
+    /// with the same TLS, timeout and connection pool settings.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let config = client.oidc.get_config().await?;
+    ///     println!("request a token from {}", config.token_endpoint);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_config(&self) -> Result<OidcConfig, Box<dyn Error>> {
+        let response: OidcScopedResponse = self.open_id_configuration().await?;
+        let endpoint = Url::parse(&response.response.href)?;
+        let resp = self.issuer_client.get(endpoint).send().await?;
+        match resp.status() {
+            reqwest::StatusCode::OK => {
+                let metadata: OidcProviderMetadata = resp.json().await?;
+                Ok(OidcConfig::new(response, metadata))
+            }
+            status => Err(Box::new(NotConfiguredError(format!(
+                "status code {} received when collecting the OpenID provider metadata from {}",
+                status, response.response.href
+            )))),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{collections::oidc::OidcResponse, WeaviateClient};
+    use crate::{
+        collections::oidc::{OidcProviderMetadata, OidcResponse},
+        RequestHook, WeaviateClient,
+    };
 
     async fn test_oidc_response() -> OidcResponse {
         let response: OidcResponse = serde_json::from_value(
@@ -86,7 +145,13 @@ mod tests {
         let resp = test_oidc_response().await;
         let resp_str = serde_json::to_string(&resp).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/openid-configuration", 200, &resp_str).await;
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/.well-known/openid-configuration",
+            200,
+            &resp_str,
+        )
+        .await;
         let res = client.oidc.get_open_id_configuration().await;
         mock.assert();
         assert!(res.is_ok());
@@ -96,9 +161,160 @@ mod tests {
     #[tokio::test]
     async fn test_get_open_id_configuration_err() {
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/openid-configuration", 404, "").await;
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/.well-known/openid-configuration",
+            404,
+            "",
+        )
+        .await;
         let res = client.oidc.get_open_id_configuration().await;
         mock.assert();
         assert!(res.is_err());
     }
+
+    fn test_provider_metadata() -> String {
+        // captured from a keycloak realm, trimmed to the relevant fields
+        serde_json::json!({
+            "issuer": "https://auth.wcs.api.weaviate.io/auth/realms/SeMI",
+            "authorization_endpoint": "https://auth.wcs.api.weaviate.io/auth/realms/SeMI/protocol/openid-connect/auth",
+            "token_endpoint": "https://auth.wcs.api.weaviate.io/auth/realms/SeMI/protocol/openid-connect/token",
+            "jwks_uri": "https://auth.wcs.api.weaviate.io/auth/realms/SeMI/protocol/openid-connect/certs",
+            "grant_types_supported": ["authorization_code", "refresh_token", "password"],
+            "response_types_supported": ["code", "none", "id_token", "token"],
+            "scopes_supported": ["openid", "email", "profile", "offline_access"]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_parse_provider_metadata() {
+        let metadata: OidcProviderMetadata =
+            serde_json::from_str(&test_provider_metadata()).unwrap();
+        assert_eq!(
+            "https://auth.wcs.api.weaviate.io/auth/realms/SeMI",
+            metadata.issuer
+        );
+        assert!(metadata.token_endpoint.ends_with("/openid-connect/token"));
+        assert!(metadata
+            .authorization_endpoint
+            .ends_with("/openid-connect/auth"));
+        assert_eq!(4, metadata.scopes_supported.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_get_config_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mut href = "http://".to_string();
+        href.push_str(&mock_server.host_with_port());
+        href.push_str("/auth/realms/SeMI/.well-known/openid-configuration");
+        let resp = serde_json::json!({
+            "clientId": "wcs",
+            "href": href,
+            "scopes": ["openid", "email"]
+        });
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/.well-known/openid-configuration",
+            200,
+            &resp.to_string(),
+        )
+        .await;
+        let metadata_mock = mock_get(
+            &mut mock_server,
+            "/auth/realms/SeMI/.well-known/openid-configuration",
+            200,
+            &test_provider_metadata(),
+        )
+        .await;
+        let res = client.oidc.get_config().await;
+        mock.assert();
+        metadata_mock.assert();
+        let config = res.unwrap();
+        assert_eq!("wcs", config.client_id);
+        assert_eq!(
+            "https://auth.wcs.api.weaviate.io/auth/realms/SeMI",
+            config.issuer
+        );
+        assert!(config.token_endpoint.ends_with("/openid-connect/token"));
+        assert_eq!(vec!["openid", "email"], config.scopes);
+    }
+
+    #[derive(Debug)]
+    struct TraceHeader;
+
+    impl RequestHook for TraceHeader {
+        fn on_request(&self, request: &mut reqwest::Request) {
+            request.headers_mut().insert(
+                "x-trace-id",
+                reqwest::header::HeaderValue::from_static("abc123"),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_config_issuer_without_credentials() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_auth_secret("test-key")
+            .with_api_key("X-OpenAI-Api-Key", "openai-key")
+            .with_hook(Box::new(TraceHeader))
+            .build()
+            .unwrap();
+        let href = format!("{}/auth/realms/SeMI/.well-known/openid-configuration", host);
+        let resp = serde_json::json!({"clientId": "wcs", "href": href});
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/openid-configuration")
+            .match_header("authorization", "Bearer test-key")
+            .match_header("x-openai-api-key", "openai-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(resp.to_string())
+            .create();
+        let metadata_mock = mock_server
+            .mock("GET", "/auth/realms/SeMI/.well-known/openid-configuration")
+            .match_header("authorization", mockito::Matcher::Missing)
+            .match_header("x-openai-api-key", mockito::Matcher::Missing)
+            .match_header("x-trace-id", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(test_provider_metadata())
+            .create();
+        let res = client.oidc.get_config().await;
+        mock.assert();
+        metadata_mock.assert();
+        assert_eq!("wcs", res.unwrap().client_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_config_issuer_timeout() {
+        // an issuer which accepts connections but never responds
+        let issuer = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer_addr = issuer.local_addr().unwrap();
+        let accept = tokio::spawn(async move {
+            let (_stream, _) = issuer.accept().await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+        });
+        let mut mock_server = mockito::Server::new_async().await;
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_timeout(std::time::Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let href = format!("http://{}/.well-known/openid-configuration", issuer_addr);
+        let resp = serde_json::json!({"clientId": "wcs", "href": href});
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/.well-known/openid-configuration",
+            200,
+            &resp.to_string(),
+        )
+        .await;
+        let res = client.oidc.get_config().await;
+        mock.assert();
+        accept.abort();
+        let err = res.unwrap_err();
+        assert!(err.downcast_ref::<reqwest::Error>().unwrap().is_timeout());
+    }
 }
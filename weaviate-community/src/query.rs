@@ -1,7 +1,11 @@
 use crate::collections::{
-    error::GraphQLError,
-    query::{AggregateQuery, ExploreQuery, GetQuery, RawQuery},
+    error::{GraphQLError, WeaviateError},
+    query::{
+        AggregateQuery, AggregateResponse, ExploreQuery, GetBuilder, GetQuery, GraphQLResponse,
+        RawQuery, QUERY_MAXIMUM_RESULTS,
+    },
 };
+use crate::http::HttpClient;
 use reqwest::Url;
 use std::error::Error;
 use std::sync::Arc;
@@ -11,15 +15,59 @@ use std::sync::Arc;
 #[derive(Debug)]
 pub struct Query {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    query_maximum_results: u64,
 }
 
 impl Query {
     /// Create a new Query object. The query object is intended to like inside the WeaviateClient
     /// and be called through the WeaviateClient.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/graphql")?;
-        Ok(Query { endpoint, client })
+        Ok(Query {
+            endpoint,
+            client,
+            query_maximum_results: QUERY_MAXIMUM_RESULTS,
+        })
+    }
+
+    /// Set the `QUERY_MAXIMUM_RESULTS` that Get query builders are checked against.
+    pub(super) fn set_query_maximum_results(&mut self, query_maximum_results: u64) {
+        self.query_maximum_results = query_maximum_results;
+    }
+
+    /// Create a new `GetBuilder` for the GraphQL Get query, checked against the
+    /// `QUERY_MAXIMUM_RESULTS` configured with `WeaviateClientBuilder::with_query_maximum_results`
+    /// when it is built.
+    ///
+    /// This is the same as `GetBuilder::new()`, other than the maximum.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// let client = WeaviateClient::builder("http://localhost:8080")
+    ///     .with_query_maximum_results(20_000)
+    ///     .build()
+    ///     .unwrap();
+    /// let query = client
+    ///     .query
+    ///     .get_builder("JeopardyQuestion", vec!["question"])
+    ///     .with_offset(15_000)
+    ///     .with_limit(100)
+    ///     .build();
+    /// assert!(query.is_ok());
+    /// ```
+    pub fn get_builder(&self, class_name: &str, properties: Vec<&str>) -> GetBuilder {
+        self.with_query_maximum_results(GetBuilder::new(class_name, properties))
+    }
+
+    /// Default the `QUERY_MAXIMUM_RESULTS` of a `GetBuilder` to the one configured on the client.
+    pub(crate) fn with_query_maximum_results(&self, query: GetBuilder) -> GetBuilder {
+        match query.query_maximum_results {
+            Some(_) => query,
+            None => query.with_query_maximum_results(self.query_maximum_results),
+        }
     }
 
     /// Execute the Get{} GraphQL query
@@ -45,26 +93,21 @@ impl Query {
     ///         ])
     ///         .with_limit(1)
     ///         .with_additional(vec!["id"])
-    ///         .build();
+    ///         .build()?;
     ///     let res = client.query.get(query).await;
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get(&self, query: GetQuery) -> Result<serde_json::Value, Box<dyn Error>> {
+    pub async fn get(&self, query: GetQuery) -> Result<GraphQLResponse, Box<dyn Error>> {
         let payload = serde_json::to_value(query).unwrap();
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&payload)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&payload))
             .await?;
         match res.status() {
-            reqwest::StatusCode::OK => {
-                let res = res.json::<serde_json::Value>().await?;
-                Ok(res)
-            }
-            _ => Err(Box::new(GraphQLError(format!(
+            reqwest::StatusCode::OK => check_errors(res.json().await?),
+            _ => Err(Box::new(GraphQLError::new(format!(
                 "status code {} received when executing GraphQL Get.",
                 res.status()
             )))),
@@ -96,20 +139,18 @@ impl Query {
     pub async fn aggregate(
         &self,
         query: AggregateQuery,
-    ) -> Result<serde_json::Value, Box<dyn Error>> {
+    ) -> Result<AggregateResponse, Box<dyn Error>> {
         let payload = serde_json::to_value(query).unwrap();
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&payload)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&payload))
             .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
-                let res = res.json::<serde_json::Value>().await?;
-                Ok(res)
+                let res = check_errors(res.json().await?)?;
+                AggregateResponse::from_graphql(&res)
             }
-            _ => Err(Box::new(GraphQLError(format!(
+            _ => Err(Box::new(GraphQLError::new(format!(
                 "status code {} received when executing GraphQL Aggregate.",
                 res.status()
             )))),
@@ -133,25 +174,20 @@ impl Query {
     ///         .with_limit(1)
     ///         .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
     ///         .with_fields(vec!["className"])
-    ///         .build();
+    ///         .build()?;
     ///     let res = client.query.explore(query).await;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn explore(&self, query: ExploreQuery) -> Result<serde_json::Value, Box<dyn Error>> {
+    pub async fn explore(&self, query: ExploreQuery) -> Result<GraphQLResponse, Box<dyn Error>> {
         let payload = serde_json::to_value(query).unwrap();
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&payload)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&payload))
             .await?;
         match res.status() {
-            reqwest::StatusCode::OK => {
-                let res = res.json::<serde_json::Value>().await?;
-                Ok(res)
-            }
-            _ => Err(Box::new(GraphQLError(format!(
+            reqwest::StatusCode::OK => check_errors(res.json().await?),
+            _ => Err(Box::new(GraphQLError::new(format!(
                 "status code {} received when executing GraphQL Explore.",
                 res.status()
             )))),
@@ -166,6 +202,9 @@ impl Query {
     /// If there is a query that you think should be added, please open up a new feature request on
     /// GitHub.
     ///
+    /// If any errors occurred while executing the query, they are returned as a
+    /// `WeaviateError::GraphQL`.
+    ///
     /// # Parameters
     /// - query: the query to execute
     ///
@@ -183,20 +222,15 @@ impl Query {
     ///
     /// }
     /// ```
-    pub async fn raw(&self, query: RawQuery) -> Result<serde_json::Value, Box<dyn Error>> {
+    pub async fn raw(&self, query: RawQuery) -> Result<GraphQLResponse, Box<dyn Error>> {
         let payload = serde_json::to_value(query).unwrap();
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&payload)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&payload))
             .await?;
         match res.status() {
-            reqwest::StatusCode::OK => {
-                let res = res.json::<serde_json::Value>().await?;
-                Ok(res)
-            }
-            _ => Err(Box::new(GraphQLError(format!(
+            reqwest::StatusCode::OK => check_errors(res.json().await?),
+            _ => Err(Box::new(GraphQLError::new(format!(
                 "status code {} received when executing GraphQL raw query.",
                 res.status()
             )))),
@@ -204,8 +238,20 @@ impl Query {
     }
 }
 
+/// Return the errors of a GraphQL response as a `WeaviateError::GraphQL`.
+///
+/// Weaviate responds with a 200 status code when a query fails to execute, so the `errors` need
+/// checking even for a successful request.
+fn check_errors(res: GraphQLResponse) -> Result<GraphQLResponse, Box<dyn Error>> {
+    match res.errors {
+        Some(errors) if !errors.is_empty() => Err(Box::new(WeaviateError::GraphQL(errors))),
+        _ => Ok(res),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::collections::error::{Location, WeaviateError};
     use crate::collections::query::RawQuery;
     use crate::collections::query::{AggregateBuilder, ExploreBuilder, GetBuilder};
     use crate::WeaviateClient;
@@ -318,17 +364,69 @@ mod tests {
         )
         .with_limit(1)
         .with_additional(vec!["id"])
-        .build();
+        .build()
+        .unwrap();
         let res = client.query.get(query).await;
         mock.assert();
         assert!(res.is_ok());
-        assert_eq!(
-            res.unwrap()["data"]["Get"]["JeopardyQuestion"]
-                .as_array()
-                .unwrap()
-                .len(),
-            1
-        );
+        let hits = res
+            .unwrap()
+            .get_hits::<serde_json::Value>("JeopardyQuestion")
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_query_maximum_results() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let builder = || {
+            GetBuilder::new("JeopardyQuestion", vec!["question"])
+                .with_offset(15_000)
+                .with_limit(100)
+        };
+        let mock = mock_server.mock("POST", "/v1/graphql").expect(0).create();
+        let res = client.for_tenant("tenantA").query.get(builder()).await;
+        mock.assert();
+        assert!(matches!(
+            res.unwrap_err().downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::InvalidRequest(_))
+        ));
+        mock.remove();
+        let res = client
+            .query
+            .get_builder("JeopardyQuestion", vec!["question"])
+            .with_offset(15_000)
+            .with_limit(100)
+            .build();
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_query_maximum_results(20_000)
+            .build()
+            .unwrap();
+        let mock = mock_server
+            .mock("POST", "/v1/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(test_get_response().await)
+            .expect(2)
+            .create();
+        let query = client
+            .query
+            .get_builder("JeopardyQuestion", vec!["question"])
+            .with_offset(15_000)
+            .with_limit(100)
+            .build()
+            .unwrap();
+        assert!(client.query.get(query).await.is_ok());
+        assert!(client
+            .for_tenant("tenantA")
+            .query
+            .get(builder())
+            .await
+            .is_ok());
+        mock.assert();
     }
 
     #[tokio::test]
@@ -346,7 +444,8 @@ mod tests {
         )
         .with_limit(1)
         .with_additional(vec!["id"])
-        .build();
+        .build()
+        .unwrap();
         let res = client.query.get(query).await;
         mock.assert();
         assert!(res.is_err());
@@ -370,13 +469,23 @@ mod tests {
         let res = client.query.aggregate(query).await;
         mock.assert();
         assert!(res.is_ok());
-        assert_eq!(
-            res.unwrap()["data"]["Aggregate"]["Article"]
-                .as_array()
-                .unwrap()
-                .len(),
-            1
-        );
+        let res = res.unwrap();
+        assert_eq!(res.groups.len(), 1);
+        assert_eq!(res.meta_count(), Some(4403));
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_query_no_data_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = serde_json::json!({
+            "data": {"Aggregate": null},
+            "errors": [{"message": "Cannot query field \"Article\" on type \"AggregateObjectsObj\"."}]
+        });
+        let mock = mock_post(&mut mock_server, "/v1/graphql", 200, &body.to_string()).await;
+        let query = AggregateBuilder::new("Article").with_meta_count().build();
+        let res = client.query.aggregate(query).await;
+        mock.assert();
+        assert!(res.is_err());
     }
 
     #[tokio::test]
@@ -403,17 +512,28 @@ mod tests {
             .with_limit(1)
             .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
             .with_fields(vec!["className"])
-            .build();
+            .build()
+            .unwrap();
         let res = client.query.explore(query).await;
         mock.assert();
         assert!(res.is_ok());
+        assert_eq!(
+            res.unwrap().data.unwrap()["Explore"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
     }
 
     #[tokio::test]
     async fn test_explore_query_err() {
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_post(&mut mock_server, "/v1/graphql", 422, "").await;
-        let query = ExploreBuilder::new().build();
+        let query = ExploreBuilder::new()
+            .with_near_text("{concepts: [\"test\"]}")
+            .build()
+            .unwrap();
         let res = client.query.explore(query).await;
         mock.assert();
         assert!(res.is_err());
@@ -428,8 +548,10 @@ mod tests {
         let res = client.query.raw(query).await;
         mock.assert();
         assert!(res.is_ok());
+        let res = res.unwrap();
+        assert!(res.errors.is_none());
         assert_eq!(
-            res.unwrap()["data"]["Get"]["JeopardyQuestion"]
+            res.data.unwrap()["Get"]["JeopardyQuestion"]
                 .as_array()
                 .unwrap()
                 .len(),
@@ -437,6 +559,71 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_raw_query_errors() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = serde_json::json!({
+            "data": {"Get": {"JeopardyQuestion": null}},
+            "errors": [{"message": "Cannot query field \"missing\" on type \"JeopardyQuestion\"."}]
+        });
+        let mock = mock_post(&mut mock_server, "/v1/graphql", 200, &body.to_string()).await;
+        let query = RawQuery::new("{ Get { JeopardyQuestion { missing } } }");
+        let res = client.query.raw(query).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::GraphQL(errors)) => assert_eq!(1, errors.len()),
+            _ => panic!("expected a GraphQL error, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_query_graphql_errors() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = serde_json::json!({
+            "data": {"Get": {"JeopardyQuestion": null}},
+            "errors": [
+                {
+                    "locations": [{"column": 6, "line": 1}],
+                    "message": "explorer: list class: search: object vector search at index \
+                                jeopardyquestion: shard jeopardyquestion_aBcD: vector search: \
+                                dimensions mismatch",
+                    "path": ["Get", "JeopardyQuestion"]
+                },
+                {"message": "Cannot query field \"missing\" on type \"JeopardyQuestion\"."}
+            ]
+        });
+        let mock = mock_post(&mut mock_server, "/v1/graphql", 200, &body.to_string()).await;
+        let query = GetBuilder::new("JeopardyQuestion", vec!["question"])
+            .build()
+            .unwrap();
+        let res = client.query.get(query).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        let errors = match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::GraphQL(errors)) => errors,
+            _ => panic!("expected a GraphQL error, got {}", err),
+        };
+        assert_eq!(2, errors.len());
+        assert!(errors[0].message.ends_with("dimensions mismatch"));
+        assert_eq!(
+            Some(vec![
+                serde_json::json!("Get"),
+                serde_json::json!("JeopardyQuestion")
+            ]),
+            errors[0].path
+        );
+        assert_eq!(
+            Some(vec![Location { line: 1, column: 6 }]),
+            errors[0].locations
+        );
+        assert!(errors[0]
+            .to_string()
+            .ends_with("(path: Get.JeopardyQuestion) (line 1, column 6)"));
+        assert!(errors[1].path.is_none());
+        assert!(errors[1].locations.is_none());
+    }
+
     #[tokio::test]
     async fn test_raw_query_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -1,29 +1,89 @@
-use crate::collections::error::SchemaError;
+use crate::collections::error::{SchemaError, WeaviateError};
+use crate::collections::objects::ConsistencyLevel;
 use crate::collections::schema::{
-    Class, Classes, Property, Shard, ShardStatus, Shards, Tenant, Tenants,
+    is_subset, Class, Classes, InvertedIndexConfig, Property, SchemaDiff, Shard, ShardStatus,
+    Shards, Tenant, Tenants,
 };
+use crate::http::HttpClient;
+use futures::future::try_join_all;
 use reqwest::Url;
+use std::collections::HashMap;
 use std::error::Error;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// All schema related endpoints and functionality described in
 /// [Weaviate schema API documentation](https://weaviate.io/developers/weaviate/api/rest/schema)
 #[derive(Debug)]
 pub struct Schema {
     endpoint: Url,
-    client: Arc<reqwest::Client>,
+    client: Arc<HttpClient>,
+    cache: Option<SchemaCache>,
+}
+
+/// The responses of `get_class` and `get`, each kept until `ttl` has passed since it was fetched.
+#[derive(Debug)]
+struct SchemaCache {
+    ttl: Duration,
+    classes: Mutex<HashMap<String, (Instant, Class)>>,
+    schema: Mutex<Option<(Instant, Classes)>>,
 }
 
 impl Schema {
     /// Create a new Schema object. The schema object is intended to like inside the WeaviateClient
     /// and be called through the WeaviateClient.
-    pub(super) fn new(url: &Url, client: Arc<reqwest::Client>) -> Result<Self, Box<dyn Error>> {
+    pub(super) fn new(url: &Url, client: Arc<HttpClient>) -> Result<Self, Box<dyn Error>> {
         let endpoint = url.join("/v1/schema/")?;
-        Ok(Schema { endpoint, client })
+        Ok(Schema {
+            endpoint,
+            client,
+            cache: None,
+        })
+    }
+
+    /// Enable the schema cache, keeping the responses of `get_class` and `get` for `ttl`.
+    pub(super) fn set_cache_ttl(&mut self, ttl: Duration) {
+        self.cache = Some(SchemaCache {
+            ttl,
+            classes: Mutex::new(HashMap::new()),
+            schema: Mutex::new(None),
+        });
+    }
+
+    /// Drop every response held by the schema cache, so the next `get_class` or `get` is fetched
+    /// from Weaviate.
+    ///
+    /// The cache is dropped automatically when the schema is changed through this client, so this
+    /// is only needed when the schema may have been changed elsewhere. Does nothing when the
+    /// cache is not enabled with `WeaviateClientBuilder::with_schema_cache`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080")
+    ///         .with_schema_cache(Duration::from_secs(60))
+    ///         .build()?;
+    ///     let class = client.schema.get_class("Library").await?;
+    ///     client.schema.invalidate_cache();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.classes.lock().unwrap().clear();
+            *cache.schema.lock().unwrap() = None;
+        }
     }
 
     /// Facilitates the retrieval of the configuration for a single class in the schema.
     ///
+    /// When the schema cache is enabled, a class fetched within the cache ttl is returned without
+    /// a request.
+    ///
     /// GET /v1/schema/{class_name}
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
@@ -37,8 +97,28 @@ impl Schema {
     /// }
     /// ```
     pub async fn get_class(&self, class_name: &str) -> Result<Class, Box<dyn Error>> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return self.fetch_class(class_name).await,
+        };
+        if let Some((fetched, class)) = cache.classes.lock().unwrap().get(class_name) {
+            if fetched.elapsed() < cache.ttl {
+                return Ok(class.clone());
+            }
+        }
+        let class = self.fetch_class(class_name).await?;
+        cache
+            .classes
+            .lock()
+            .unwrap()
+            .insert(class_name.into(), (Instant::now(), class.clone()));
+        Ok(class)
+    }
+
+    /// Fetch the configuration for a single class from Weaviate, bypassing the schema cache.
+    async fn fetch_class(&self, class_name: &str) -> Result<Class, Box<dyn Error>> {
         let endpoint = self.endpoint.join(class_name)?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
 
         match res.status() {
             reqwest::StatusCode::OK => {
@@ -49,8 +129,67 @@ impl Schema {
         }
     }
 
+    /// Facilitates the retrieval of the configuration for a single class in the schema, returning
+    /// `None` if the class does not exist.
+    ///
+    /// GET /v1/schema/{class_name}
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let response = client.schema.find_class("Library").await?;
+    ///     assert!(response.is_none());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn find_class(&self, class_name: &str) -> Result<Option<Class>, Box<dyn Error>> {
+        let endpoint = self.endpoint.join(class_name)?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
+
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Class = res.json().await?;
+                Ok(Some(res))
+            }
+            reqwest::StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(self.get_err_msg("find class", res).await),
+        }
+    }
+
+    /// Retrieve the definition of a single property of a class, returning `None` if the class does
+    /// not have the property.
+    ///
+    /// GET /v1/schema/{class_name}
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let property = client.schema.get_property("Library", "title").await?;
+    ///     println!("{:#?}", &property);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_property(
+        &self,
+        class_name: &str,
+        property_name: &str,
+    ) -> Result<Option<Property>, Box<dyn Error>> {
+        let class = self.get_class(class_name).await?;
+        let property = class
+            .properties
+            .and_then(|properties| properties.0.into_iter().find(|p| p.name == property_name));
+        Ok(property)
+    }
+
     /// Facilitates the retrieval of the full Weaviate schema.
     ///
+    /// When the schema cache is enabled, a schema fetched within the cache ttl is returned without
+    /// a request.
+    ///
     /// GET /v1/schema
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
@@ -64,7 +203,26 @@ impl Schema {
     /// }
     /// ```
     pub async fn get(&self) -> Result<Classes, Box<dyn Error>> {
-        let res = self.client.get(self.endpoint.clone()).send().await?;
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return self.fetch_schema().await,
+        };
+        if let Some((fetched, schema)) = cache.schema.lock().unwrap().as_ref() {
+            if fetched.elapsed() < cache.ttl {
+                return Ok(schema.clone());
+            }
+        }
+        let schema = self.fetch_schema().await?;
+        *cache.schema.lock().unwrap() = Some((Instant::now(), schema.clone()));
+        Ok(schema)
+    }
+
+    /// Fetch the full schema from Weaviate, bypassing the schema cache.
+    async fn fetch_schema(&self) -> Result<Classes, Box<dyn Error>> {
+        let res = self
+            .client
+            .send(self.client.get(self.endpoint.clone()))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Classes = res.json().await?;
@@ -74,12 +232,35 @@ impl Schema {
         }
     }
 
+    /// Retrieve the configuration for only the named classes in the schema.
+    ///
+    /// The classes are fetched concurrently, and any class which does not exist is skipped.
+    ///
+    /// GET /v1/schema/{class_name}
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let classes = client.schema.get_classes(&["Library", "Book"]).await?;
+    ///     println!("{:#?}", &classes);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_classes(&self, class_names: &[&str]) -> Result<Classes, Box<dyn Error>> {
+        let classes = try_join_all(class_names.iter().map(|name| self.find_class(name))).await?;
+        Ok(Classes::new(classes.into_iter().flatten().collect()))
+    }
+
     /// Create a new data object class in the schema.
     ///
     /// Note that from 1.5.0, creating a schema is optional, as Auto Schema is available. See for
     /// more info:
     /// [Weaviate auto-schema documentation](https://weaviate.io/developers/weaviate/config-refs/schema#auto-schema)
     ///
+    /// If the class already exists, a `WeaviateError::AlreadyExists` is returned.
+    ///
     /// POST /v1/schema
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
@@ -98,19 +279,65 @@ impl Schema {
         let payload = serde_json::to_value(&class).unwrap();
         let res = self
             .client
-            .post(self.endpoint.clone())
-            .json(&payload)
-            .send()
+            .send(self.client.post(self.endpoint.clone()).json(&payload))
             .await?;
+        self.invalidate_cache();
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Class = res.json().await?;
                 Ok(res)
             }
+            reqwest::StatusCode::CONFLICT | reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let status = res.status();
+                let body = res.text().await?;
+                if body.contains("already exists") {
+                    return Err(Box::new(WeaviateError::AlreadyExists {
+                        class: class.class.clone(),
+                    }));
+                }
+                Err(Box::new(SchemaError(format!(
+                    "Status code `{}` received when calling create class endpoint. Response: {}",
+                    status, body,
+                ))))
+            }
             _ => Err(self.get_err_msg("create class", res).await),
         }
     }
 
+    /// Create a new data object class in the schema, unless a class with the same name already
+    /// exists.
+    ///
+    /// When the class already exists, the existing class is returned unchanged, even if its
+    /// configuration differs from `class`. Use `diff` to compare the two.
+    ///
+    /// # Parameters
+    /// - class: the class to create
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::Class;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let class = Class::builder("Library").build();
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let res = client.schema.create_class_if_absent(&class).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_class_if_absent(&self, class: &Class) -> Result<Class, Box<dyn Error>> {
+        let err = match self.create_class(class).await {
+            Ok(class) => return Ok(class),
+            Err(e) => e,
+        };
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::AlreadyExists { .. }) => self.get_class(&class.class).await,
+            _ => Err(err),
+        }
+    }
+
     ///
     /// Remove a class (and all data in the instances) from the schema.
     ///
@@ -129,7 +356,8 @@ impl Schema {
     ///
     pub async fn delete(&self, class_name: &str) -> Result<bool, Box<dyn Error>> {
         let endpoint = self.endpoint.join(class_name)?;
-        let res = self.client.delete(endpoint).send().await?;
+        let res = self.client.send(self.client.delete(endpoint)).await?;
+        self.invalidate_cache();
         match res.status() {
             reqwest::StatusCode::OK => Ok(true),
             _ => Err(self.get_err_msg("delete class", res).await),
@@ -149,10 +377,104 @@ impl Schema {
     /// some fields may be immutable.
     ///
     /// You should attach a body to this PUT request with the entire new configuration of the class
+    ///
+    /// Before sending the update, the live class is fetched and compared against the submitted
+    /// class. Fields left unset on the submitted class are not compared. The following fields
+    /// are immutable, and changing any of them returns a `WeaviateError::ImmutableField`:
+    /// - vectorizer
+    /// - vectorIndexType
+    /// - moduleConfig
+    /// - shardingConfig
+    /// - multiTenancyConfig.enabled
+    /// - vectorIndexConfig.distance
+    /// - invertedIndexConfig.indexTimestamps
+    /// - invertedIndexConfig.indexNullState
+    /// - invertedIndexConfig.indexPropertyLength
+    ///
+    /// All other class level fields, such as the description, the remaining vectorIndexConfig
+    /// and invertedIndexConfig settings, and the replicationConfig, are mutable.
+    ///
+    /// # Parameters
+    /// - class: the full new configuration of the class
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let mut class = client.schema.get_class("Library").await?;
+    ///     class.description = Some("Updated description".into());
+    ///     let res = client.schema.update(&class).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub async fn update(&self, class: &Class) -> Result<Class, Box<dyn Error>> {
+        let live = self.fetch_class(&class.class).await?;
+        if let Some(field) = immutable_field_change(class, &live)? {
+            return Err(Box::new(WeaviateError::ImmutableField { field }));
+        }
+        self.put_class(class).await
+    }
+
+    /// Update the inverted index configuration of an existing class.
+    ///
+    /// The live class is fetched, the fields set on `config` are merged over its current
+    /// inverted index configuration, and the class is sent back. Fields left unset on `config`
+    /// keep their current values, so BM25 or stopwords can be tuned without rebuilding the
+    /// class.
+    ///
+    /// `indexTimestamps`, `indexNullState` and `indexPropertyLength` cannot be changed after the
+    /// class is created; setting them to a new value returns a `WeaviateError::ImmutableField`.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class to update
+    /// - config: the inverted index fields to change
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::{Bm25, InvertedIndexConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let config = InvertedIndexConfig::builder()
+    ///         .with_bm25(Bm25::new(0.75, 1.2))
+    ///         .build();
+    ///     let res = client.schema.update_inverted_index("Library", config).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn update_inverted_index(
+        &self,
+        class_name: &str,
+        config: InvertedIndexConfig,
+    ) -> Result<Class, Box<dyn Error>> {
+        let live = self.fetch_class(class_name).await?;
+        let mut class = live.clone();
+        class.inverted_index_config = Some(merge_inverted_index(
+            live.inverted_index_config.clone(),
+            config,
+        ));
+        if let Some(field) = immutable_field_change(&class, &live)? {
+            return Err(Box::new(WeaviateError::ImmutableField { field }));
+        }
+        self.put_class(&class).await
+    }
+
+    /// Send the full configuration of a class to the update endpoint.
+    async fn put_class(&self, class: &Class) -> Result<Class, Box<dyn Error>> {
         let endpoint = self.endpoint.join(&class.class)?;
-        let payload = serde_json::to_value(&class)?;
-        let res = self.client.put(endpoint).json(&payload).send().await?;
+        let payload = serde_json::to_value(class)?;
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
+        self.invalidate_cache();
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Class = res.json().await?;
@@ -170,11 +492,7 @@ impl Schema {
         class_name: &str,
         property: &Property,
     ) -> Result<Property, Box<dyn Error>> {
-        let mut endpoint = class_name.to_string();
-        endpoint.push_str("/properties");
-        let endpoint = self.endpoint.join(&endpoint)?;
-        let payload = serde_json::to_value(&property)?;
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self.post_property(class_name, property).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let res: Property = res.json().await?;
@@ -184,6 +502,102 @@ impl Schema {
         }
     }
 
+    /// Add several properties to an existing class in the schema.
+    ///
+    /// Properties that already exist on the class are skipped. Every other property is still
+    /// attempted if one fails, and the failures are returned together once all have been tried.
+    ///
+    /// # Parameters
+    /// - class_name: the name of the class to add the properties to
+    /// - properties: the properties to add
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::Property;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let properties = vec![
+    ///         Property::builder("title", vec!["text"]).build(),
+    ///         Property::builder("wordCount", vec!["int"]).build(),
+    ///     ];
+    ///     let added = client.schema.add_properties("Article", &properties).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn add_properties(
+        &self,
+        class_name: &str,
+        properties: &[Property],
+    ) -> Result<Vec<Property>, Box<dyn Error>> {
+        let mut added = Vec::new();
+        let mut failures = Vec::new();
+        for property in properties {
+            match self.add_property_if_absent(class_name, property).await {
+                Ok(Some(property)) => added.push(property),
+                Ok(None) => {}
+                Err(err) => failures.push(format!("{}: {}", property.name, err)),
+            }
+        }
+        if !failures.is_empty() {
+            return Err(Box::new(SchemaError(format!(
+                "Failed to add {} of {} properties to {}: {}",
+                failures.len(),
+                properties.len(),
+                class_name,
+                failures.join("; "),
+            ))));
+        }
+        Ok(added)
+    }
+
+    /// Add a property to a class, returning `None` if the class already has the property.
+    async fn add_property_if_absent(
+        &self,
+        class_name: &str,
+        property: &Property,
+    ) -> Result<Option<Property>, Box<dyn Error>> {
+        let res = self.post_property(class_name, property).await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let res: Property = res.json().await?;
+                Ok(Some(res))
+            }
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let body = res.text().await?;
+                if body.contains("already exists") {
+                    return Ok(None);
+                }
+                Err(Box::new(SchemaError(format!(
+                    "Status code `{}` received when calling add property endpoint. Response: {}",
+                    reqwest::StatusCode::UNPROCESSABLE_ENTITY,
+                    body,
+                ))))
+            }
+            _ => Err(self.get_err_msg("add property", res).await),
+        }
+    }
+
+    /// Send a property to the add property endpoint of a class.
+    async fn post_property(
+        &self,
+        class_name: &str,
+        property: &Property,
+    ) -> Result<reqwest::Response, Box<dyn Error>> {
+        let mut endpoint = class_name.to_string();
+        endpoint.push_str("/properties");
+        let endpoint = self.endpoint.join(&endpoint)?;
+        let payload = serde_json::to_value(property)?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
+        self.invalidate_cache();
+        Ok(res)
+    }
+
     ///
     /// View all of the shards for a particular class.
     ///
@@ -191,7 +605,7 @@ impl Schema {
         let mut endpoint = class_name.to_string();
         endpoint.push_str("/shards");
         let endpoint = self.endpoint.join(&endpoint)?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let shards = res.json::<Vec<Shard>>().await?;
@@ -205,18 +619,30 @@ impl Schema {
     ///
     /// Update shard status
     ///
+    /// Only READONLY and READY can be set, any other status returns a
+    /// `WeaviateError::InvalidRequest` without calling the server.
+    ///
     pub async fn update_class_shard(
         &self,
         class_name: &str,
         shard_name: &str,
         status: ShardStatus,
     ) -> Result<Shard, Box<dyn Error>> {
+        if !status.is_settable() {
+            return Err(Box::new(WeaviateError::InvalidRequest(format!(
+                "shard {} cannot be set to {:?}, only READONLY and READY can be set",
+                shard_name, status
+            ))));
+        }
         let mut endpoint = class_name.to_string();
         endpoint.push_str("/shards/");
         endpoint.push_str(shard_name);
         let endpoint = self.endpoint.join(&endpoint)?;
         let payload = serde_json::json!({ "status": status });
-        let res = self.client.put(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => Ok(Shard {
                 name: shard_name.into(),
@@ -233,7 +659,7 @@ impl Schema {
         let mut endpoint = class_name.to_string();
         endpoint.push_str("/tenants");
         let endpoint = self.endpoint.join(&endpoint)?;
-        let res = self.client.get(endpoint).send().await?;
+        let res = self.client.send(self.client.get(endpoint)).await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let tenants = res.json::<Vec<Tenant>>().await?;
@@ -247,16 +673,20 @@ impl Schema {
     ///
     /// Add tenant
     ///
+    /// The consistency_level, when set, controls how many replicas must acknowledge the change.
+    ///
     pub async fn add_tenants(
         &self,
         class_name: &str,
         tenants: &Tenants,
+        consistency_level: Option<ConsistencyLevel>,
     ) -> Result<Tenants, Box<dyn Error>> {
-        let mut endpoint = class_name.to_string();
-        endpoint.push_str("/tenants");
-        let endpoint = self.endpoint.join(&endpoint)?;
+        let endpoint = self.tenants_endpoint(class_name, consistency_level)?;
         let payload = serde_json::to_value(&tenants.tenants)?;
-        let res = self.client.post(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let tenants = res.json::<Vec<Tenant>>().await?;
@@ -267,19 +697,142 @@ impl Schema {
         }
     }
 
+    /// Add tenants to a class in chunks of at most `chunk_size`, for tenant sets too large to
+    /// send in one request.
+    ///
+    /// Tenants which already exist are skipped. When a chunk is rejected because one of its
+    /// tenants already exists, the tenants in that chunk are retried one at a time. A
+    /// `SchemaError` describing every failure is returned once all chunks have been sent.
+    ///
+    /// # Parameters
+    /// - class_name: the class to add the tenants to
+    /// - tenants: the tenants to add
+    /// - chunk_size: the maximum number of tenants to send in each request
+    /// - consistency_level: how many replicas must acknowledge each change
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::{Tenant, Tenants};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let tenants = Tenants::new(
+    ///         (0..5000).map(|i| Tenant::builder(&format!("tenant_{}", i)).build()).collect()
+    ///     );
+    ///     let added = client.schema.add_tenants_chunked("Article", &tenants, 500, None).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn add_tenants_chunked(
+        &self,
+        class_name: &str,
+        tenants: &Tenants,
+        chunk_size: usize,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Tenants, Box<dyn Error>> {
+        if chunk_size == 0 {
+            return Err(Box::new(WeaviateError::InvalidRequest(
+                "chunk_size must be greater than 0".into(),
+            )));
+        }
+        let mut added = Vec::new();
+        let mut failures = Vec::new();
+        for chunk in tenants.tenants.chunks(chunk_size) {
+            match self
+                .add_tenants_if_absent(class_name, chunk, consistency_level)
+                .await
+            {
+                Ok(Some(res)) => {
+                    added.extend(res.tenants);
+                    continue;
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    failures.extend(
+                        chunk
+                            .iter()
+                            .map(|tenant| format!("{}: {}", tenant.name, err)),
+                    );
+                    continue;
+                }
+            }
+            for tenant in chunk {
+                let single = std::slice::from_ref(tenant);
+                match self
+                    .add_tenants_if_absent(class_name, single, consistency_level)
+                    .await
+                {
+                    Ok(Some(res)) => added.extend(res.tenants),
+                    Ok(None) => {}
+                    Err(err) => failures.push(format!("{}: {}", tenant.name, err)),
+                }
+            }
+        }
+        if !failures.is_empty() {
+            return Err(Box::new(SchemaError(format!(
+                "Failed to add {} of {} tenants to {}: {}",
+                failures.len(),
+                tenants.tenants.len(),
+                class_name,
+                failures.join("; "),
+            ))));
+        }
+        Ok(Tenants { tenants: added })
+    }
+
+    /// Add tenants to a class, returning `None` when Weaviate rejects the request because one of
+    /// the tenants already exists.
+    async fn add_tenants_if_absent(
+        &self,
+        class_name: &str,
+        tenants: &[Tenant],
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Option<Tenants>, Box<dyn Error>> {
+        let endpoint = self.tenants_endpoint(class_name, consistency_level)?;
+        let payload = serde_json::to_value(tenants)?;
+        let res = self
+            .client
+            .send(self.client.post(endpoint).json(&payload))
+            .await?;
+        match res.status() {
+            reqwest::StatusCode::OK => {
+                let tenants = res.json::<Vec<Tenant>>().await?;
+                Ok(Some(Tenants { tenants }))
+            }
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => {
+                let body = res.text().await?;
+                if body.contains("already exists") {
+                    return Ok(None);
+                }
+                Err(Box::new(SchemaError(format!(
+                    "Status code `{}` received when calling add tenants endpoint. Response: {}",
+                    reqwest::StatusCode::UNPROCESSABLE_ENTITY,
+                    body,
+                ))))
+            }
+            _ => Err(self.get_err_msg("add tenants", res).await),
+        }
+    }
+
     ///
     /// Remove tenants
     ///
+    /// The consistency_level, when set, controls how many replicas must acknowledge the change.
+    ///
     pub async fn remove_tenants(
         &self,
         class_name: &str,
         tenants: &Vec<&str>,
+        consistency_level: Option<ConsistencyLevel>,
     ) -> Result<bool, Box<dyn Error>> {
-        let mut endpoint = class_name.to_string();
-        endpoint.push_str("/tenants");
-        let endpoint = self.endpoint.join(&endpoint)?;
+        let endpoint = self.tenants_endpoint(class_name, consistency_level)?;
         let payload = serde_json::to_value(&tenants)?;
-        let res = self.client.delete(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.delete(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => Ok(true),
             _ => Err(self.get_err_msg("remove tenants", res).await),
@@ -289,7 +842,11 @@ impl Schema {
     ///
     /// Update tenants
     ///
-    /// For updating tenants, both `name` and `activity_status` are required.
+    /// For updating tenants, both `name` and `activity_status` are required. An
+    /// `InvalidRequest` error is returned without calling Weaviate if any tenant is missing its
+    /// `activity_status`.
+    ///
+    /// The consistency_level, when set, controls how many replicas must acknowledge the change.
     ///
     /// Note that tenant activity status setting is only available from Weaviate v1.21
     ///
@@ -297,12 +854,27 @@ impl Schema {
         &self,
         class_name: &str,
         tenants: &Tenants,
+        consistency_level: Option<ConsistencyLevel>,
     ) -> Result<Tenants, Box<dyn Error>> {
-        let mut endpoint = class_name.to_string();
-        endpoint.push_str("/tenants");
-        let endpoint = self.endpoint.join(&endpoint)?;
+        let missing: Vec<&str> = tenants
+            .tenants
+            .iter()
+            .filter(|tenant| tenant.activity_status.is_none())
+            .map(|tenant| tenant.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(Box::new(WeaviateError::InvalidRequest(format!(
+                "activity_status is required to update tenants, missing for: {}",
+                missing.join(", ")
+            ))));
+        }
+
+        let endpoint = self.tenants_endpoint(class_name, consistency_level)?;
         let payload = serde_json::to_value(&tenants.tenants)?;
-        let res = self.client.put(endpoint).json(&payload).send().await?;
+        let res = self
+            .client
+            .send(self.client.put(endpoint).json(&payload))
+            .await?;
         match res.status() {
             reqwest::StatusCode::OK => {
                 let tenants = res.json::<Vec<Tenant>>().await?;
@@ -313,6 +885,104 @@ impl Schema {
         }
     }
 
+    /// Build the tenants endpoint of a class, with the optional consistency_level query param.
+    fn tenants_endpoint(
+        &self,
+        class_name: &str,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Url, Box<dyn Error>> {
+        let mut endpoint = class_name.to_string();
+        endpoint.push_str("/tenants");
+        let mut endpoint = self.endpoint.join(&endpoint)?;
+        self.client
+            .set_consistency_level(&mut endpoint, consistency_level);
+        Ok(endpoint)
+    }
+
+    /// Compare a desired schema against the live schema.
+    ///
+    /// The returned `SchemaDiff` lists the classes to add, the classes only present in the live
+    /// schema, and the properties to add to existing classes. It can be printed to review the
+    /// changes, and passed to `apply` to make them.
+    ///
+    /// # Parameters
+    /// - desired: the schema that should exist
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::{Class, Classes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let desired = Classes::new(vec![Class::builder("Article").build()]);
+    ///     let diff = client.schema.diff(&desired).await?;
+    ///     println!("{}", diff);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn diff(&self, desired: &Classes) -> Result<SchemaDiff, Box<dyn Error>> {
+        let current = self.get().await?;
+        Ok(SchemaDiff::new(&current, desired))
+    }
+
+    /// Apply a `SchemaDiff`, creating the missing classes and adding the missing properties.
+    ///
+    /// Classes only present in the live schema are left untouched, as deleting a class also
+    /// deletes its data, as are the `changed_classes` of the diff.
+    ///
+    /// The live schema is fetched again first, so applying a diff is idempotent. A class to add
+    /// that already exists is skipped if it is `Class::semantically_equals` to the live class, and
+    /// returns a `WeaviateError::AlreadyExists` otherwise. Properties that already exist are
+    /// skipped.
+    ///
+    /// # Parameters
+    /// - diff: the changes to make
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::collections::schema::{Class, Classes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let desired = Classes::new(vec![Class::builder("Article").build()]);
+    ///     let diff = client.schema.diff(&desired).await?;
+    ///     client.schema.apply(&diff).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn apply(&self, diff: &SchemaDiff) -> Result<(), Box<dyn Error>> {
+        let current = self.get().await?;
+        for class in &diff.added_classes {
+            match current.find_class(&class.class) {
+                Some(live) if class.semantically_equals(live) => {}
+                Some(live) => {
+                    return Err(Box::new(WeaviateError::AlreadyExists {
+                        class: live.class.clone(),
+                    }))
+                }
+                None => {
+                    self.create_class(class).await?;
+                }
+            }
+        }
+        for (class_name, properties) in &diff.added_properties {
+            let live = current.find_class(class_name);
+            for property in properties {
+                let exists = live
+                    .and_then(|live| live.properties.as_ref())
+                    .is_some_and(|live| live.0.iter().any(|p| p.name == property.name));
+                if !exists {
+                    self.add_property(class_name, property).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Get the error message for the endpoint
     ///
     /// Made to reduce the boilerplate error message building
@@ -338,16 +1008,78 @@ impl Schema {
     }
 }
 
+/// The class level fields that cannot be changed once a class has been created, as JSON paths.
+const IMMUTABLE_CLASS_FIELDS: &[&[&str]] = &[
+    &["vectorizer"],
+    &["vectorIndexType"],
+    &["moduleConfig"],
+    &["shardingConfig"],
+    &["multiTenancyConfig", "enabled"],
+    &["vectorIndexConfig", "distance"],
+    &["invertedIndexConfig", "indexTimestamps"],
+    &["invertedIndexConfig", "indexNullState"],
+    &["invertedIndexConfig", "indexPropertyLength"],
+];
+
+/// Find the first immutable field that differs between the submitted and the live class.
+///
+/// Fields that are not set on the submitted class are skipped, as they are left untouched. Nested
+/// configs such as `shardingConfig` only need the keys that were submitted to match, as the live
+/// class holds the defaults filled in by Weaviate.
+fn immutable_field_change(
+    submitted: &Class,
+    live: &Class,
+) -> Result<Option<String>, serde_json::Error> {
+    let submitted = serde_json::to_value(submitted)?;
+    let live = serde_json::to_value(live)?;
+    for path in IMMUTABLE_CLASS_FIELDS {
+        let lookup = |value: &serde_json::Value| {
+            path.iter()
+                .try_fold(value, |v, key| v.get(key))
+                .filter(|v| !v.is_null())
+                .cloned()
+        };
+        if let Some(new) = lookup(&submitted) {
+            if !lookup(&live).is_some_and(|live| is_subset(&new, &live)) {
+                return Ok(Some(path.join(".")));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Merge the fields set on `update` over the `live` inverted index configuration.
+fn merge_inverted_index(
+    live: Option<InvertedIndexConfig>,
+    update: InvertedIndexConfig,
+) -> InvertedIndexConfig {
+    let live = live.unwrap_or_default();
+    InvertedIndexConfig {
+        stopwords: update.stopwords.or(live.stopwords),
+        index_timestamps: update.index_timestamps.or(live.index_timestamps),
+        index_null_state: update.index_null_state.or(live.index_null_state),
+        index_property_length: update.index_property_length.or(live.index_property_length),
+        bm25: update.bm25.or(live.bm25),
+        cleanup_interval_seconds: update
+            .cleanup_interval_seconds
+            .or(live.cleanup_interval_seconds),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Tests currently require a weaviate instance to be running on localhost, as I have not yet
     // implemented anything to mock the database. In future, actual tests will run as integration
     // tests in a container as part of the CICD process.
+    use crate::collections::error::{SchemaError, WeaviateError};
+    use crate::collections::objects::ConsistencyLevel;
     use crate::collections::schema::{
-        ActivityStatus, Class, ClassBuilder, Classes, Property, Shard, ShardStatus, Shards, Tenant,
-        Tenants,
+        ActivityStatus, Bm25, Class, ClassBuilder, Classes, DataType, DistanceMetric,
+        DynamicIndexConfig, InvertedIndexConfig, Properties, Property, SchemaDiff, Shard,
+        ShardStatus, ShardingConfig, Shards, Tenant, Tenants, VectorIndexConfig, VectorIndexType,
     };
     use crate::WeaviateClient;
+    use std::time::Duration;
 
     /// Helper function for generating a testing class
     fn test_class(class_name: &str) -> Class {
@@ -383,133 +1115,754 @@ mod tests {
         ])
     }
 
-    fn test_shards() -> Shards {
-        Shards::new(vec![Shard::new("1D3PBjtz9W7r", ShardStatus::READY)])
+    fn test_shards() -> Shards {
+        Shards::new(vec![Shard::new("1D3PBjtz9W7r", ShardStatus::READY)])
+    }
+
+    #[test]
+    fn test_property_data_types() {
+        let property = Property::builder("test", vec![])
+            .with_data_types(vec![DataType::NUMBERARRAY])
+            .unwrap()
+            .build();
+        assert_eq!(
+            serde_json::json!({"name": "test", "dataType": ["number[]"]}),
+            serde_json::to_value(&property).unwrap()
+        );
+
+        let property = Property::builder("test", vec![])
+            .with_data_types(vec![
+                DataType::CrossReference("Article".into()),
+                DataType::CrossReference("Publication".into()),
+            ])
+            .unwrap()
+            .build();
+        assert_eq!(
+            serde_json::json!({"name": "test", "dataType": ["Article", "Publication"]}),
+            serde_json::to_value(&property).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_property_data_types_multiple_scalars() {
+        let res =
+            Property::builder("test", vec![]).with_data_types(vec![DataType::TEXT, DataType::INT]);
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+
+        let res = Property::builder("test", vec![]).with_data_types(vec![
+            DataType::CrossReference("Article".into()),
+            DataType::TEXT,
+        ]);
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_property_reference_to_single_target() {
+        let property = Property::builder("inPublication", vec!["text"])
+            .with_reference_to(vec!["Publication"])
+            .unwrap()
+            .build();
+        assert_eq!(vec!["Publication"], property.data_type);
+    }
+
+    #[test]
+    fn test_property_reference_to_multiple_targets() {
+        let property = Property::builder("inPublication", vec![])
+            .with_reference_to(vec!["Publication", "Magazine_2"])
+            .unwrap()
+            .build();
+        assert_eq!(vec!["Publication", "Magazine_2"], property.data_type);
+    }
+
+    #[test]
+    fn test_property_reference_to_invalid() {
+        let res = Property::builder("inPublication", vec![]).with_reference_to(vec![]);
+        assert!(matches!(res, Err(WeaviateError::InvalidRequest(_))));
+
+        let res = Property::builder("inPublication", vec![]).with_reference_to(vec![
+            "Publication",
+            "text",
+            "my-class",
+        ]);
+        match res {
+            Err(WeaviateError::InvalidRequest(msg)) => {
+                assert!(msg.contains("text, my-class"));
+                assert!(!msg.contains("Publication,"));
+            }
+            _ => panic!("expected an InvalidRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_property_index_range_filters() {
+        let property = Property::builder("wordCount", vec!["int"])
+            .with_index_range_filters(true)
+            .build();
+        let value = serde_json::to_value(&property).unwrap();
+        assert_eq!(Some(true), value["indexRangeFilters"].as_bool());
+        let res: Property = serde_json::from_value(value).unwrap();
+        assert_eq!(property, res);
+    }
+
+    #[test]
+    fn test_property_nested_properties() {
+        let property = Property::builder("author", vec![])
+            .with_data_types(vec![DataType::OBJECT])
+            .unwrap()
+            .with_nested_properties(vec![
+                Property::builder("name", vec!["text"]).build(),
+                Property::builder("addresses", vec![])
+                    .with_data_types(vec![DataType::OBJECTARRAY])
+                    .unwrap()
+                    .with_nested_properties(vec![Property::builder("city", vec!["text"]).build()])
+                    .build(),
+            ])
+            .build();
+        let value = serde_json::to_value(&property).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "name": "author",
+                "dataType": ["object"],
+                "nestedProperties": [
+                    {"name": "name", "dataType": ["text"]},
+                    {
+                        "name": "addresses",
+                        "dataType": ["object[]"],
+                        "nestedProperties": [{"name": "city", "dataType": ["text"]}]
+                    }
+                ]
+            }),
+            value
+        );
+        let res: Property = serde_json::from_value(value).unwrap();
+        assert_eq!(property, res);
+    }
+
+    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        (mock_server, client)
+    }
+
+    async fn mock_post(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("POST", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_put(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("PUT", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_get(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        server
+            .mock("GET", endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    async fn mock_delete(
+        server: &mut mockito::ServerGuard,
+        endpoint: &str,
+        status_code: usize,
+    ) -> mockito::Mock {
+        server
+            .mock("DELETE", endpoint)
+            .with_status(status_code)
+            .create()
+    }
+
+    #[tokio::test]
+    async fn test_create_class_ok() {
+        let class = test_class("UnitClass");
+        let class_str = serde_json::to_string(&class).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/schema/", 200, &class_str).await;
+        let res = client.schema.create_class(&class).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(class.class, res.unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_create_class_already_exists() {
+        let class = test_class("UnitClass");
+        let body = serde_json::json!({"error": [
+            {"message": "class name \"UnitClass\" already exists"}
+        ]});
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/schema/", 422, &body.to_string()).await;
+        let res = client.schema.create_class(&class).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::AlreadyExists { class }) => assert_eq!("UnitClass", class),
+            _ => panic!("expected an AlreadyExists error, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_class_if_absent_created() {
+        let class = test_class("UnitClass");
+        let class_str = serde_json::to_string(&class).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let create_mock = mock_post(&mut mock_server, "/v1/schema/", 200, &class_str).await;
+        let get_mock = mock_server
+            .mock("GET", "/v1/schema/UnitClass")
+            .expect(0)
+            .create();
+        let res = client.schema.create_class_if_absent(&class).await;
+        create_mock.assert();
+        get_mock.assert();
+        assert_eq!(class.class, res.unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_create_class_if_absent_existing() {
+        let class = test_class("UnitClass");
+        let mut existing = test_class("UnitClass");
+        existing.description = Some("Existing".into());
+        let existing_str = serde_json::to_string(&existing).unwrap();
+        let body = serde_json::json!({"error": [
+            {"message": "class name \"UnitClass\" already exists"}
+        ]});
+        let (mut mock_server, client) = get_test_harness().await;
+        let create_mock = mock_post(&mut mock_server, "/v1/schema/", 422, &body.to_string()).await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/UnitClass", 200, &existing_str).await;
+        let res = client.schema.create_class_if_absent(&class).await;
+        create_mock.assert();
+        get_mock.assert();
+        assert_eq!(Some("Existing".to_string()), res.unwrap().description);
+    }
+
+    #[tokio::test]
+    async fn test_create_class_err() {
+        let class = test_class("UnitClass");
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/schema/", 401, "").await;
+        let res = client.schema.create_class(&class).await;
+        mock.assert();
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_classes_ok() {
+        let classes = test_classes();
+        let class_str = serde_json::to_string(&classes).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/", 200, &class_str).await;
+        let res = client.schema.get().await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(classes.classes[0].class, res.unwrap().classes[0].class);
+    }
+
+    #[tokio::test]
+    async fn test_diff_and_apply() {
+        let mut journal = test_class("Journal");
+        journal.properties = Some(Properties::new(vec![Property::builder(
+            "title",
+            vec!["text"],
+        )
+        .build()]));
+        let current = Classes::new(vec![journal, test_class("Magazine")]);
+        let current_str = serde_json::to_string(&current).unwrap();
+
+        let mut journal = test_class("Journal");
+        journal.properties = Some(Properties::new(vec![
+            Property::builder("title", vec!["text"]).build(),
+            Property::builder("issue", vec!["int"]).build(),
+        ]));
+        let desired = Classes::new(vec![journal, test_class("Article")]);
+
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/", 200, &current_str).await;
+        let diff = client.schema.diff(&desired).await.unwrap();
+        get_mock.assert();
+        assert_eq!(
+            "+ class Article\n+ property Journal.issue\n- class Magazine",
+            diff.to_string()
+        );
+
+        let class_str = serde_json::to_string(&test_class("Article")).unwrap();
+        let class_mock = mock_server
+            .mock("POST", "/v1/schema/")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"class": "Article"}),
+            ))
+            .with_status(200)
+            .with_body(&class_str)
+            .create();
+        let property_str =
+            serde_json::to_string(&Property::builder("issue", vec!["int"]).build()).unwrap();
+        let property_mock = mock_server
+            .mock("POST", "/v1/schema/Journal/properties")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"name": "issue"}),
+            ))
+            .with_status(200)
+            .with_body(&property_str)
+            .create();
+        let delete_mock = mock_server
+            .mock("DELETE", "/v1/schema/Magazine")
+            .expect(0)
+            .create();
+        client.schema.apply(&diff).await.unwrap();
+        class_mock.assert();
+        property_mock.assert();
+        delete_mock.assert();
+    }
+
+    #[test]
+    fn test_diff_mixed_case_class_names() {
+        let current = Classes::new(vec![test_class("Article")]);
+        let mut article = test_class("article");
+        article.properties = Some(Properties::new(vec![Property::builder(
+            "title",
+            vec!["text"],
+        )
+        .build()]));
+        let desired = Classes::new(vec![article]);
+        let diff = SchemaDiff::new(&current, &desired);
+        assert!(diff.added_classes.is_empty());
+        assert!(diff.removed_classes.is_empty());
+        assert_eq!("+ property Article.title", diff.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_apply_already_applied() {
+        let mut journal = test_class("Journal");
+        journal.properties = Some(Properties::new(vec![Property::builder(
+            "title",
+            vec!["text"],
+        )
+        .build()]));
+        let diff = SchemaDiff::new(&Classes::new(vec![]), &Classes::new(vec![journal.clone()]));
+        let mut live: serde_json::Value = serde_json::to_value(&journal).unwrap();
+        live["vectorIndexType"] = "hnsw".into();
+        live["replicationConfig"] = serde_json::json!({"factor": 1});
+        live["properties"][0]["tokenization"] = "word".into();
+        let current_str = serde_json::json!({"classes": [live]}).to_string();
+
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/", 200, &current_str).await;
+        let create_mock = mock_server.mock("POST", "/v1/schema/").expect(0).create();
+        client.schema.apply(&diff).await.unwrap();
+        get_mock.assert();
+        create_mock.assert();
+
+        journal.vectorizer = Some("text2vec-openai".into());
+        let diff = SchemaDiff::new(&Classes::new(vec![]), &Classes::new(vec![journal]));
+        let err = client.schema.apply(&diff).await.unwrap_err();
+        create_mock.assert();
+        assert!(matches!(
+            err.downcast_ref::<WeaviateError>(),
+            Some(WeaviateError::AlreadyExists { class }) if class == "Journal"
+        ));
+    }
+
+    #[test]
+    fn test_diff_changed_classes() {
+        let current = Classes::new(vec![test_class("Journal")]);
+        let mut journal = test_class("Journal");
+        journal.vectorizer = Some("none".into());
+        let diff = SchemaDiff::new(&current, &Classes::new(vec![journal.clone()]));
+        assert_eq!(vec!["Journal"], diff.changed_classes);
+        assert_eq!("~ class Journal", diff.to_string());
+
+        let mut live: Class = serde_json::from_value(serde_json::json!({
+            "class": "Journal",
+            "description": "Test",
+            "vectorizer": "none",
+            "vectorIndexType": "hnsw",
+            "replicationConfig": {"factor": 1}
+        }))
+        .unwrap();
+        let desired = Classes::new(vec![journal.clone()]);
+        assert!(SchemaDiff::new(&Classes::new(vec![live.clone()]), &desired).is_empty());
+        live.vectorizer = Some("text2vec-openai".into());
+        let diff = SchemaDiff::new(&Classes::new(vec![live]), &Classes::new(vec![journal]));
+        assert_eq!(vec!["Journal"], diff.changed_classes);
+    }
+
+    #[test]
+    fn test_diff_no_changes() {
+        let classes = test_classes();
+        let diff = SchemaDiff::new(&classes, &classes);
+        assert!(diff.is_empty());
+        assert_eq!("no changes", diff.to_string());
+    }
+
+    fn test_desired_class() -> Class {
+        ClassBuilder::new("article")
+            .with_vectorizer("text2vec-openai")
+            .with_vector_index_config(
+                VectorIndexConfig::builder()
+                    .with_distance(DistanceMetric::COSINE)
+                    .build(),
+            )
+            .with_properties(Properties::new(vec![
+                Property::builder("title", vec!["text"]).build(),
+                Property::builder("wordCount", vec!["int"]).build(),
+            ]))
+            .build()
+    }
+
+    /// The desired class as Weaviate returns it, with every default filled in.
+    fn test_live_class() -> serde_json::Value {
+        serde_json::json!({
+            "class": "Article",
+            "vectorizer": "text2vec-openai",
+            "vectorIndexType": "hnsw",
+            "vectorIndexConfig": {
+                "distance": "cosine",
+                "ef": -1,
+                "efConstruction": 128,
+                "maxConnections": 64,
+                "dynamicEfMin": 100,
+                "dynamicEfMax": 500,
+                "dynamicEfFactor": 8,
+                "vectorCacheMaxObjects": 1000000000000i64,
+                "flatSearchCutoff": 40000,
+                "cleanupIntervalSeconds": 300,
+                "skip": false
+            },
+            "moduleConfig": {
+                "text2vec-openai": {
+                    "model": "ada",
+                    "modelVersion": "002",
+                    "type": "text",
+                    "vectorizeClassName": true
+                }
+            },
+            "invertedIndexConfig": {
+                "bm25": {"b": 0.75, "k1": 1.2},
+                "cleanupIntervalSeconds": 60,
+                "stopwords": {"additions": null, "preset": "en", "removals": null}
+            },
+            "shardingConfig": {
+                "virtualPerPhysical": 128,
+                "desiredCount": 1,
+                "actualCount": 1,
+                "desiredVirtualCount": 128,
+                "actualVirtualCount": 128,
+                "key": "_id",
+                "strategy": "hash",
+                "function": "murmur3"
+            },
+            "multiTenancyConfig": {"enabled": false},
+            "replicationConfig": {"factor": 1},
+            "properties": [
+                {
+                    "name": "wordCount",
+                    "dataType": ["int"],
+                    "indexFilterable": true,
+                    "indexSearchable": false,
+                    "moduleConfig": {
+                        "text2vec-openai": {"skip": false, "vectorizePropertyName": false}
+                    }
+                },
+                {
+                    "name": "title",
+                    "dataType": ["text"],
+                    "tokenization": "word",
+                    "indexFilterable": true,
+                    "indexSearchable": true,
+                    "moduleConfig": {
+                        "text2vec-openai": {"skip": false, "vectorizePropertyName": false}
+                    }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_class_semantically_equals() {
+        let live: Class = serde_json::from_value(test_live_class()).unwrap();
+        assert!(test_desired_class().semantically_equals(&live));
+        assert!(ClassBuilder::new("Article")
+            .build()
+            .semantically_equals(&live));
+
+        let mut sharded = test_desired_class();
+        sharded.sharding_config = Some(
+            ShardingConfig::builder()
+                .with_desired_count(1)
+                .with_actual_count(3)
+                .build(),
+        );
+        assert!(sharded.semantically_equals(&live));
+    }
+
+    #[test]
+    fn test_class_semantically_equals_differences() {
+        let live: Class = serde_json::from_value(test_live_class()).unwrap();
+
+        let mut vectorizer = test_desired_class();
+        vectorizer.vectorizer = Some("text2vec-cohere".into());
+        assert!(!vectorizer.semantically_equals(&live));
+
+        let distance = ClassBuilder::new("Article")
+            .with_vector_index_config(
+                VectorIndexConfig::builder()
+                    .with_distance(DistanceMetric::DOT)
+                    .build(),
+            )
+            .build();
+        assert!(!distance.semantically_equals(&live));
+
+        let missing = ClassBuilder::new("Article")
+            .with_properties(Properties::new(vec![Property::builder(
+                "title",
+                vec!["text"],
+            )
+            .build()]))
+            .build();
+        assert!(!missing.semantically_equals(&live));
+
+        let data_type = ClassBuilder::new("Article")
+            .with_properties(Properties::new(vec![
+                Property::builder("title", vec!["text[]"]).build(),
+                Property::builder("wordCount", vec!["int"]).build(),
+            ]))
+            .build();
+        assert!(!data_type.semantically_equals(&live));
+
+        assert!(!ClassBuilder::new("Journal")
+            .build()
+            .semantically_equals(&live));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_classes_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/", 401, "").await;
+        let class = client.schema.get().await;
+        mock.assert();
+        assert!(class.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_single_class_ok() {
+        let class = test_class("Test");
+        let class_str = serde_json::to_string(&class).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
+        let res = client.schema.get_class("Test").await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!(class.class, res.unwrap().class);
+    }
+
+    #[tokio::test]
+    async fn test_get_single_class_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 401, "").await;
+        let class = client.schema.get_class("Test").await;
+        mock.assert();
+        assert!(class.is_err());
     }
 
-    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+    async fn get_cached_test_harness(ttl: Duration) -> (mockito::ServerGuard, WeaviateClient) {
         let mock_server = mockito::Server::new_async().await;
-        let mut host = "http://".to_string();
-        host.push_str(&mock_server.host_with_port());
-        let client = WeaviateClient::builder(&host).build().unwrap();
+        let host = format!("http://{}", mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_schema_cache(ttl)
+            .build()
+            .unwrap();
         (mock_server, client)
     }
 
-    async fn mock_post(
-        server: &mut mockito::ServerGuard,
+    fn mock_get_times(
+        mock_server: &mut mockito::ServerGuard,
         endpoint: &str,
-        status_code: usize,
         body: &str,
+        hits: usize,
     ) -> mockito::Mock {
-        server
-            .mock("POST", endpoint)
-            .with_status(status_code)
+        mock_server
+            .mock("GET", endpoint)
+            .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(body)
+            .expect(hits)
             .create()
     }
 
-    async fn mock_put(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("PUT", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[tokio::test]
+    async fn test_schema_cache_get_class() {
+        let class_str = serde_json::to_string(&test_class("Test")).unwrap();
+        let (mut mock_server, client) = get_cached_test_harness(Duration::from_secs(60)).await;
+        let mock = mock_get_times(&mut mock_server, "/v1/schema/Test", &class_str, 1);
+        let first = client.schema.get_class("Test").await.unwrap();
+        let second = client.schema.get_class("Test").await.unwrap();
+        mock.assert();
+        assert_eq!(first.class, second.class);
     }
 
-    async fn mock_get(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-        body: &str,
-    ) -> mockito::Mock {
-        server
-            .mock("GET", endpoint)
-            .with_status(status_code)
-            .with_header("content-type", "application/json")
-            .with_body(body)
-            .create()
+    #[tokio::test]
+    async fn test_schema_cache_get() {
+        let classes_str = serde_json::to_string(&test_classes()).unwrap();
+        let (mut mock_server, client) = get_cached_test_harness(Duration::from_secs(60)).await;
+        let mock = mock_get_times(&mut mock_server, "/v1/schema/", &classes_str, 1);
+        assert!(client.schema.get().await.is_ok());
+        assert!(client.schema.get().await.is_ok());
+        mock.assert();
     }
 
-    async fn mock_delete(
-        server: &mut mockito::ServerGuard,
-        endpoint: &str,
-        status_code: usize,
-    ) -> mockito::Mock {
-        server
-            .mock("DELETE", endpoint)
-            .with_status(status_code)
-            .create()
+    #[tokio::test]
+    async fn test_schema_cache_disabled() {
+        let class_str = serde_json::to_string(&test_class("Test")).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get_times(&mut mock_server, "/v1/schema/Test", &class_str, 2);
+        assert!(client.schema.get_class("Test").await.is_ok());
+        assert!(client.schema.get_class("Test").await.is_ok());
+        mock.assert();
     }
 
     #[tokio::test]
-    async fn test_create_class_ok() {
-        let class = test_class("UnitClass");
+    async fn test_schema_cache_expired() {
+        let class_str = serde_json::to_string(&test_class("Test")).unwrap();
+        let (mut mock_server, client) = get_cached_test_harness(Duration::ZERO).await;
+        let mock = mock_get_times(&mut mock_server, "/v1/schema/Test", &class_str, 2);
+        assert!(client.schema.get_class("Test").await.is_ok());
+        assert!(client.schema.get_class("Test").await.is_ok());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_schema_cache_invalidate() {
+        let class = test_class("Test");
+        let class_str = serde_json::to_string(&class).unwrap();
+        let (mut mock_server, client) = get_cached_test_harness(Duration::from_secs(60)).await;
+        let get_mock = mock_get_times(&mut mock_server, "/v1/schema/Test", &class_str, 3);
+        let create_mock = mock_post(&mut mock_server, "/v1/schema/", 200, &class_str).await;
+        assert!(client.schema.get_class("Test").await.is_ok());
+        client.schema.invalidate_cache();
+        assert!(client.schema.get_class("Test").await.is_ok());
+        assert!(client.schema.create_class(&class).await.is_ok());
+        assert!(client.schema.get_class("Test").await.is_ok());
+        get_mock.assert();
+        create_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_find_class_ok() {
+        let class = test_class("Test");
         let class_str = serde_json::to_string(&class).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_post(&mut mock_server, "/v1/schema/", 200, &class_str).await;
-        let res = client.schema.create_class(&class).await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
+        let res = client.schema.find_class("Test").await;
         mock.assert();
-        assert!(res.is_ok());
-        assert_eq!(class.class, res.unwrap().class);
+        assert_eq!(class.class, res.unwrap().unwrap().class);
     }
 
     #[tokio::test]
-    async fn test_create_class_err() {
-        let class = test_class("UnitClass");
+    async fn test_find_class_not_found() {
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_post(&mut mock_server, "/v1/schema/", 401, "").await;
-        let res = client.schema.create_class(&class).await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 404, "").await;
+        let res = client.schema.find_class("Test").await;
         mock.assert();
-        assert!(res.is_err());
+        assert!(res.unwrap().is_none());
     }
 
     #[tokio::test]
-    async fn test_get_all_classes_ok() {
-        let classes = test_classes();
-        let class_str = serde_json::to_string(&classes).unwrap();
+    async fn test_find_class_err() {
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/v1/schema/", 200, &class_str).await;
-        let res = client.schema.get().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 500, "").await;
+        let res = client.schema.find_class("Test").await;
         mock.assert();
-        assert!(res.is_ok());
-        assert_eq!(classes.classes[0].class, res.unwrap().classes[0].class);
+        assert!(res.is_err());
     }
 
     #[tokio::test]
-    async fn test_get_all_classes_err() {
+    async fn test_get_classes_ok() {
+        let class_a = test_class("TestA");
+        let class_b = test_class("TestB");
+        let class_a_str = serde_json::to_string(&class_a).unwrap();
+        let class_b_str = serde_json::to_string(&class_b).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/v1/schema/", 401, "").await;
-        let class = client.schema.get().await;
+        let mock_a = mock_get(&mut mock_server, "/v1/schema/TestA", 200, &class_a_str).await;
+        let mock_b = mock_get(&mut mock_server, "/v1/schema/TestB", 200, &class_b_str).await;
+        let mock_c = mock_get(&mut mock_server, "/v1/schema/TestC", 404, "").await;
+        let res = client
+            .schema
+            .get_classes(&["TestA", "TestB", "TestC"])
+            .await;
+        mock_a.assert();
+        mock_b.assert();
+        mock_c.assert();
+        let names: Vec<String> = res.unwrap().classes.into_iter().map(|c| c.class).collect();
+        assert_eq!(vec!["TestA".to_string(), "TestB".to_string()], names);
+    }
+
+    #[tokio::test]
+    async fn test_get_classes_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 500, "").await;
+        let res = client.schema.get_classes(&["Test"]).await;
         mock.assert();
-        assert!(class.is_err());
+        assert!(res.is_err());
     }
 
     #[tokio::test]
-    async fn test_get_single_class_ok() {
-        let class = test_class("Test");
+    async fn test_get_property_ok() {
+        let mut class = test_class("Test");
+        class.properties = Some(Properties::new(vec![
+            test_property("title"),
+            test_property("body"),
+        ]));
         let class_str = serde_json::to_string(&class).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
-        let res = client.schema.get_class("Test").await;
+        let res = client.schema.get_property("Test", "body").await;
         mock.assert();
-        assert!(res.is_ok());
-        assert_eq!(class.class, res.unwrap().class);
+        assert_eq!("body", res.unwrap().unwrap().name);
     }
 
     #[tokio::test]
-    async fn test_get_single_class_err() {
+    async fn test_get_property_missing() {
+        let class_str = serde_json::to_string(&test_class("Test")).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
-        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 401, "").await;
-        let class = client.schema.get_class("Test").await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
+        let res = client.schema.get_property("Test", "body").await;
         mock.assert();
-        assert!(class.is_err());
+        assert!(res.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_property_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test", 404, "").await;
+        let res = client.schema.get_property("Test", "body").await;
+        mock.assert();
+        assert!(res.is_err());
     }
 
     #[tokio::test]
@@ -536,8 +1889,10 @@ mod tests {
         let class = test_class("Test");
         let class_str = serde_json::to_string(&class).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
         let mock = mock_put(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
         let res = client.schema.update(&class).await;
+        get_mock.assert();
         mock.assert();
         assert!(res.is_ok());
         assert_eq!(class.class, res.unwrap().class);
@@ -546,13 +1901,156 @@ mod tests {
     #[tokio::test]
     async fn test_update_class_err() {
         let class = test_class("Test");
+        let class_str = serde_json::to_string(&class).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &class_str).await;
         let mock = mock_put(&mut mock_server, "/v1/schema/Test", 401, "").await;
         let res = client.schema.update(&class).await;
+        get_mock.assert();
         mock.assert();
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_update_class_immutable_field() {
+        let live = ClassBuilder::new("Test")
+            .with_vectorizer("text2vec-openai")
+            .build();
+        let live_str = serde_json::to_string(&live).unwrap();
+        let class = ClassBuilder::new("Test")
+            .with_description("Changed")
+            .with_vectorizer("none")
+            .build();
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &live_str).await;
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test")
+            .expect(0)
+            .create();
+        let res = client.schema.update(&class).await;
+        get_mock.assert();
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::ImmutableField { field }) => assert_eq!("vectorizer", field),
+            _ => panic!("expected an ImmutableField error, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_class_partial_immutable_config() {
+        let live_str = serde_json::json!({
+            "class": "Test",
+            "moduleConfig": {
+                "text2vec-openai": {"model": "ada", "vectorizeClassName": true}
+            },
+            "shardingConfig": {
+                "virtualPerPhysical": 128,
+                "desiredCount": 1,
+                "actualCount": 1,
+                "desiredVirtualCount": 128,
+                "actualVirtualCount": 128,
+                "key": "_id",
+                "strategy": "hash",
+                "function": "murmur3"
+            }
+        })
+        .to_string();
+        let class = ClassBuilder::new("Test")
+            .with_description("Changed")
+            .with_module_config(serde_json::json!({"text2vec-openai": {"model": "ada"}}))
+            .with_sharding_config(ShardingConfig::builder().with_desired_count(1).build())
+            .build();
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &live_str).await;
+        let mock = mock_put(&mut mock_server, "/v1/schema/Test", 200, &live_str).await;
+        let res = client.schema.update(&class).await;
+        get_mock.assert();
+        mock.assert();
+        assert!(res.is_ok());
+
+        let class = ClassBuilder::new("Test")
+            .with_sharding_config(ShardingConfig::builder().with_desired_count(2).build())
+            .build();
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test")
+            .expect(0)
+            .create();
+        let err = client.schema.update(&class).await.unwrap_err();
+        mock.assert();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::ImmutableField { field }) => assert_eq!("shardingConfig", field),
+            _ => panic!("expected an ImmutableField error, got {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_inverted_index_ok() {
+        let live = ClassBuilder::new("Test")
+            .with_inverted_index_config(
+                InvertedIndexConfig::builder()
+                    .with_index_timestamps(true)
+                    .with_cleanup_interval_seconds(60)
+                    .build(),
+            )
+            .build();
+        let live_str = serde_json::to_string(&live).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &live_str).await;
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "class": "Test",
+                "invertedIndexConfig": {
+                    "indexTimestamps": true,
+                    "cleanupIntervalSeconds": 60,
+                    "bm25": {"b": 0.75, "k1": 1.2}
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&live_str)
+            .create();
+        let config = InvertedIndexConfig::builder()
+            .with_bm25(Bm25::new(0.75, 1.2))
+            .build();
+        let res = client.schema.update_inverted_index("Test", config).await;
+        get_mock.assert();
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_inverted_index_immutable_field() {
+        let live = ClassBuilder::new("Test")
+            .with_inverted_index_config(
+                InvertedIndexConfig::builder()
+                    .with_index_null_state(false)
+                    .build(),
+            )
+            .build();
+        let live_str = serde_json::to_string(&live).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let get_mock = mock_get(&mut mock_server, "/v1/schema/Test", 200, &live_str).await;
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test")
+            .expect(0)
+            .create();
+        let config = InvertedIndexConfig::builder()
+            .with_index_null_state(true)
+            .build();
+        let res = client.schema.update_inverted_index("Test", config).await;
+        get_mock.assert();
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::ImmutableField { field }) => {
+                assert_eq!("invertedIndexConfig.indexNullState", field)
+            }
+            _ => panic!("expected an ImmutableField error, got {}", err),
+        }
+    }
+
     #[tokio::test]
     async fn test_add_property_ok() {
         let property = test_property("Test");
@@ -580,6 +2078,18 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_add_property_already_exists() {
+        let property = test_property("Test");
+        let body = r#"{"error":[{"message":"property 'Test' already exists"}]}"#;
+        let (mut mock_server, client) = get_test_harness().await;
+        let url = "/v1/schema/TestClass/properties";
+        let mock = mock_post(&mut mock_server, url, 422, body).await;
+        let res = client.schema.add_property("TestClass", &property).await;
+        mock.assert();
+        assert!(res.unwrap_err().downcast_ref::<SchemaError>().is_some());
+    }
+
     #[tokio::test]
     async fn test_get_shards_ok() {
         let shards = test_shards();
@@ -621,6 +2131,48 @@ mod tests {
         assert_eq!(shard.name, res.unwrap().name);
     }
 
+    #[tokio::test]
+    async fn test_update_class_shard_transitions() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_put(&mut mock_server, "/v1/schema/Test/shards/abcd", 200, "").await;
+        let res = client
+            .schema
+            .update_class_shard("Test", "abcd", ShardStatus::READY)
+            .await;
+        mock.assert();
+        assert_eq!(ShardStatus::READY, res.unwrap().status);
+
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test/shards/abcd")
+            .expect(0)
+            .create();
+        for status in [ShardStatus::INDEXING, ShardStatus::LOADING] {
+            let res = client
+                .schema
+                .update_class_shard("Test", "abcd", status)
+                .await;
+            let err = res.unwrap_err();
+            match err.downcast_ref::<WeaviateError>() {
+                Some(WeaviateError::InvalidRequest(msg)) => {
+                    assert!(msg.contains(&format!("{:?}", status)))
+                }
+                _ => panic!("expected an InvalidRequest error, got {}", err),
+            }
+        }
+        mock.assert();
+    }
+
+    #[test]
+    fn test_shard_status_deserialize() {
+        let shards: Vec<Shard> = serde_json::from_value(serde_json::json!([
+            {"name": "abcd", "status": "INDEXING"},
+            {"name": "efgh", "status": "LOADING"},
+        ]))
+        .unwrap();
+        assert_eq!(ShardStatus::INDEXING, shards[0].status);
+        assert_eq!(ShardStatus::LOADING, shards[1].status);
+    }
+
     #[tokio::test]
     async fn test_update_class_shard_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -670,7 +2222,7 @@ mod tests {
             200,
             &tenants_str,
         ).await;
-        let res = client.schema.add_tenants("Test", &tenants).await;
+        let res = client.schema.add_tenants("Test", &tenants, None).await;
         mock.assert();
         assert!(res.is_ok());
         assert_eq!(tenants.tenants[0].name, res.unwrap().tenants[0].name);
@@ -681,9 +2233,152 @@ mod tests {
         let tenants = test_tenants();
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_post(&mut mock_server, "/v1/schema/Test/tenants", 422, "").await;
-        let res = client.schema.add_tenants("Test", &tenants).await;
+        let res = client.schema.add_tenants("Test", &tenants, None).await;
+        mock.assert();
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_tenants_already_exists() {
+        let tenants = test_tenants();
+        let body = r#"{"error":[{"message":"tenant 'TENANT_A' already exists"}]}"#;
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/schema/Test/tenants", 422, body).await;
+        let res = client.schema.add_tenants("Test", &tenants, None).await;
+        mock.assert();
+        assert!(res.unwrap_err().downcast_ref::<SchemaError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_add_tenants_chunked_ok() {
+        let tenants = Tenants::new(
+            (0..5)
+                .map(|i| Tenant::builder(&format!("TENANT_{}", i)).build())
+                .collect(),
+        );
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"name": "TENANT"}]"#)
+            .expect(3)
+            .create();
+        let res = client
+            .schema
+            .add_tenants_chunked("Test", &tenants, 2, None)
+            .await;
+        mock.assert();
+        assert_eq!(3, res.unwrap().tenants.len());
+    }
+
+    #[tokio::test]
+    async fn test_add_tenants_chunked_already_exists() {
+        let tenants = Tenants::new(vec![
+            Tenant::builder("TENANT_A").build(),
+            Tenant::builder("TENANT_B").build(),
+        ]);
+        let (mut mock_server, client) = get_test_harness().await;
+        let chunk_mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .match_body(mockito::Matcher::Json(serde_json::json!(&tenants.tenants)))
+            .with_status(422)
+            .with_body(r#"{"error":[{"message":"tenant TENANT_A already exists"}]}"#)
+            .create();
+        let existing_mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .match_body(mockito::Matcher::Json(serde_json::json!(
+                &tenants.tenants[..1]
+            )))
+            .with_status(422)
+            .with_body(r#"{"error":[{"message":"tenant TENANT_A already exists"}]}"#)
+            .create();
+        let new_mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .match_body(mockito::Matcher::Json(serde_json::json!(
+                &tenants.tenants[1..]
+            )))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"name": "TENANT_B"}]"#)
+            .create();
+        let res = client
+            .schema
+            .add_tenants_chunked("Test", &tenants, 2, None)
+            .await;
+        chunk_mock.assert();
+        existing_mock.assert();
+        new_mock.assert();
+        let added = res.unwrap();
+        assert_eq!(1, added.tenants.len());
+        assert_eq!("TENANT_B", added.tenants[0].name);
+    }
+
+    #[tokio::test]
+    async fn test_add_tenants_chunked_err() {
+        let tenants = test_tenants();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .with_status(500)
+            .expect(2)
+            .create();
+        let res = client
+            .schema
+            .add_tenants_chunked("Test", &tenants, 1, None)
+            .await;
         mock.assert();
         assert!(res.is_err());
+        assert!(client
+            .schema
+            .add_tenants_chunked("Test", &tenants, 0, None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tenants_consistency_level() {
+        let mut tenants = test_tenants();
+        tenants.set_all_status(ActivityStatus::HOT);
+        let tenants_str = serde_json::to_string(&tenants.tenants).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let query = mockito::Matcher::UrlEncoded("consistency_level".into(), "QUORUM".into());
+        let add_mock = mock_server
+            .mock("POST", "/v1/schema/Test/tenants")
+            .match_query(query.clone())
+            .with_status(200)
+            .with_body(&tenants_str)
+            .create();
+        let update_mock = mock_server
+            .mock("PUT", "/v1/schema/Test/tenants")
+            .match_query(query.clone())
+            .with_status(200)
+            .with_body(&tenants_str)
+            .create();
+        let remove_mock = mock_server
+            .mock("DELETE", "/v1/schema/Test/tenants")
+            .match_query(query)
+            .with_status(200)
+            .create();
+        let cl = Some(ConsistencyLevel::QUORUM);
+        assert!(client
+            .schema
+            .add_tenants("Test", &tenants, cl)
+            .await
+            .is_ok());
+        assert!(client
+            .schema
+            .update_tenants("Test", &tenants, cl)
+            .await
+            .is_ok());
+        assert!(client
+            .schema
+            .remove_tenants("Test", &vec!["TENANT_A"], cl)
+            .await
+            .unwrap());
+        add_mock.assert();
+        update_mock.assert();
+        remove_mock.assert();
     }
 
     #[tokio::test]
@@ -692,7 +2387,7 @@ mod tests {
         let mock = mock_delete(&mut mock_server, "/v1/schema/Test/tenants", 200).await;
         let res = client
             .schema
-            .remove_tenants("Test", &vec!["TestTenant"])
+            .remove_tenants("Test", &vec!["TestTenant"], None)
             .await;
         mock.assert();
         assert!(res.is_ok());
@@ -705,7 +2400,7 @@ mod tests {
         let mock = mock_delete(&mut mock_server, "/v1/schema/Test/tenants", 422).await;
         let res = client
             .schema
-            .remove_tenants("Test", &vec!["TestTenant"])
+            .remove_tenants("Test", &vec!["TestTenant"], None)
             .await;
         mock.assert();
         assert!(res.is_err());
@@ -713,7 +2408,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_tenants_ok() {
-        let tenants = test_tenants();
+        let mut tenants = test_tenants();
+        tenants.set_all_status(ActivityStatus::HOT);
         let tenants_str = serde_json::to_string(&tenants.tenants).unwrap();
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_put(
@@ -722,7 +2418,7 @@ mod tests {
             200,
             &tenants_str,
         ).await;
-        let res = client.schema.update_tenants("Test", &tenants).await;
+        let res = client.schema.update_tenants("Test", &tenants, None).await;
         mock.assert();
         assert!(res.is_ok());
         assert_eq!(tenants.tenants[0].name, res.unwrap().tenants[0].name);
@@ -730,11 +2426,181 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_tenants_err() {
-        let tenants = test_tenants();
+        let mut tenants = test_tenants();
+        tenants.set_all_status(ActivityStatus::COLD);
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_put(&mut mock_server, "/v1/schema/Test/tenants", 422, "").await;
-        let res = client.schema.update_tenants("Test", &tenants).await;
+        let res = client.schema.update_tenants("Test", &tenants, None).await;
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_update_tenants_missing_status() {
+        let tenants = test_tenants();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("PUT", "/v1/schema/Test/tenants")
+            .expect(0)
+            .create();
+        let res = client.schema.update_tenants("Test", &tenants, None).await;
+        mock.assert();
+        let err = res.unwrap_err();
+        match err.downcast_ref::<WeaviateError>() {
+            Some(WeaviateError::InvalidRequest(msg)) => assert!(msg.contains("TENANT_A")),
+            _ => panic!("expected an InvalidRequest error, got {}", err),
+        }
+    }
+
+    #[test]
+    fn test_distance_cosine() {
+        let metric = DistanceMetric::COSINE;
+        assert!(metric.is_more_similar(0.1, 0.2));
+        assert!(!metric.is_more_similar(0.2, 0.1));
+        assert_eq!(Some(1.0), metric.certainty_from_distance(0.0));
+        assert_eq!(Some(0.5), metric.certainty_from_distance(1.0));
+        assert_eq!(Some(0.0), metric.certainty_from_distance(2.0));
+    }
+
+    #[test]
+    fn test_distance_dot() {
+        let metric = DistanceMetric::DOT;
+        assert!(metric.is_more_similar(-20.0, -5.0));
+        assert!(!metric.is_more_similar(3.0, -3.0));
+        assert_eq!(None, metric.certainty_from_distance(-20.0));
+    }
+
+    #[test]
+    fn test_distance_l2_squared() {
+        let metric = DistanceMetric::L2SQUARED;
+        assert!(metric.is_more_similar(0.5, 4.0));
+        assert!(!metric.is_more_similar(4.0, 4.0));
+        assert_eq!(None, metric.certainty_from_distance(0.5));
+    }
+
+    #[test]
+    fn test_distance_hamming() {
+        let metric = DistanceMetric::HAMMING;
+        assert!(metric.is_more_similar(1.0, 3.0));
+        assert_eq!(None, metric.certainty_from_distance(1.0));
+    }
+
+    #[test]
+    fn test_distance_manhattan() {
+        let metric = DistanceMetric::MANHATTAN;
+        assert!(metric.is_more_similar(2.5, 7.0));
+        assert_eq!(None, metric.certainty_from_distance(2.5));
+    }
+
+    #[tokio::test]
+    async fn test_add_properties_skips_existing() {
+        let existing = test_property("Existing");
+        let new = test_property("New");
+        let (mut mock_server, client) = get_test_harness().await;
+        let existing_mock = mock_server
+            .mock("POST", "/v1/schema/TestClass/properties")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"name": "Existing"}),
+            ))
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":[{"message":"property 'Existing' already exists"}]}"#)
+            .create();
+        let new_mock = mock_server
+            .mock("POST", "/v1/schema/TestClass/properties")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"name": "New"}),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&new).unwrap())
+            .create();
+        let res = client
+            .schema
+            .add_properties("TestClass", &[existing, new])
+            .await
+            .unwrap();
+        existing_mock.assert();
+        new_mock.assert();
+        assert_eq!(1, res.len());
+        assert_eq!("New", res[0].name);
+    }
+
+    #[tokio::test]
+    async fn test_add_properties_aggregates_failures() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/schema/TestClass/properties")
+            .with_status(500)
+            .expect(2)
+            .create();
+        let properties = [test_property("First"), test_property("Second")];
+        let res = client.schema.add_properties("TestClass", &properties).await;
+        mock.assert();
+        let err = res.unwrap_err().to_string();
+        assert!(err.contains("2 of 2"));
+        assert!(err.contains("First") && err.contains("Second"));
+    }
+
+    #[test]
+    fn test_dynamic_index_config_serialize() {
+        let config = VectorIndexConfig::builder()
+            .with_distance(DistanceMetric::COSINE)
+            .with_dynamic(
+                DynamicIndexConfig::builder()
+                    .with_threshold(10_000)
+                    .with_hnsw(
+                        VectorIndexConfig::builder()
+                            .with_ef_construction(128)
+                            .build(),
+                    )
+                    .with_flat(
+                        VectorIndexConfig::builder()
+                            .with_vector_cache_max_objects(100)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        let class = ClassBuilder::new("Test")
+            .with_vector_index_type(VectorIndexType::DYNAMIC)
+            .with_vector_index_config(config)
+            .build();
+        let value = serde_json::to_value(&class).unwrap();
+        assert_eq!("dynamic", value["vectorIndexType"]);
+        assert_eq!(
+            serde_json::json!({
+                "distance": "cosine",
+                "threshold": 10000,
+                "hnsw": {"efConstruction": 128},
+                "flat": {"vectorCacheMaxObjects": 100}
+            }),
+            value["vectorIndexConfig"]
+        );
+    }
+
+    #[test]
+    fn test_dynamic_index_config_round_trip() {
+        let json = serde_json::json!({
+            "distance": "cosine",
+            "threshold": 10000,
+            "hnsw": {"distance": "cosine", "maxConnections": 32},
+            "flat": {"distance": "cosine", "vectorCacheMaxObjects": 100}
+        });
+        let config: VectorIndexConfig = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(Some(10000), config.dynamic.threshold);
+        assert_eq!(
+            Some(32),
+            config.dynamic.hnsw.as_ref().unwrap().max_connections
+        );
+        assert_eq!(json, serde_json::to_value(&config).unwrap());
+    }
+
+    #[test]
+    fn test_hnsw_index_config_round_trip() {
+        let json = serde_json::json!({"distance": "dot", "ef": 64});
+        let config: VectorIndexConfig = serde_json::from_value(json.clone()).unwrap();
+        assert!(config.dynamic.threshold.is_none());
+        assert_eq!(json, serde_json::to_value(&config).unwrap());
+    }
 }
@@ -0,0 +1,418 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::collections::{
+    batch::{BatchAddObjects, BatchAddReferencesResponse, BatchDeleteRequest, BatchDeleteResponse},
+    objects::{ConsistencyLevel, CreateOutcome, MultiObjects, Object, Reference, References},
+    query::{AggregateBuilder, AggregateResponse, GetBuilder, GraphQLResponse},
+};
+use crate::{Batch, Objects, Query};
+
+/// A lightweight view of a `WeaviateClient` scoped to a single tenant.
+///
+/// Every call made through the scoped `objects`, `batch` and `query` endpoints has the tenant
+/// applied automatically. The scoped client borrows the endpoints of the `WeaviateClient`, so
+/// the underlying connection pool and configuration are shared.
+///
+/// # Example
+/// ```no_run
+/// use weaviate_community::WeaviateClient;
+/// use weaviate_community::collections::objects::Object;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+///     let tenant = client.for_tenant("TENANT_A");
+///
+///     let object = Object::builder("Article", serde_json::json!({"title": "Test"})).build();
+///     let res = tenant.objects.create(&object, None).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ScopedClient<'a> {
+    pub objects: ScopedObjects<'a>,
+    pub batch: ScopedBatch<'a>,
+    pub query: ScopedQuery<'a>,
+}
+
+impl<'a> ScopedClient<'a> {
+    /// Create a new ScopedClient. Should only be done by the parent client.
+    pub(super) fn new(
+        objects: &'a Objects,
+        batch: &'a Batch,
+        query: &'a Query,
+        tenant: &str,
+    ) -> ScopedClient<'a> {
+        ScopedClient {
+            objects: ScopedObjects {
+                inner: objects,
+                tenant: tenant.into(),
+            },
+            batch: ScopedBatch {
+                inner: batch,
+                tenant: tenant.into(),
+            },
+            query: ScopedQuery {
+                inner: query,
+                tenant: tenant.into(),
+            },
+        }
+    }
+
+    /// Get the name of the tenant the client is scoped to.
+    pub fn tenant(&self) -> &str {
+        &self.objects.tenant
+    }
+}
+
+/// The `Objects` endpoints, with the tenant of the `ScopedClient` applied.
+#[derive(Debug)]
+pub struct ScopedObjects<'a> {
+    inner: &'a Objects,
+    tenant: String,
+}
+
+impl ScopedObjects<'_> {
+    /// Create a new data object in the tenant. See `Objects::create`.
+    pub async fn create(
+        &self,
+        new_object: &Object,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Object, Box<dyn Error>> {
+        let mut new_object = new_object.clone();
+        new_object.tenant = Some(self.tenant.clone());
+        self.inner.create(&new_object, consistency_level).await
+    }
+
+    /// Create a new data object in the tenant, unless it already exists. See
+    /// `Objects::create_if_absent`.
+    pub async fn create_if_absent(
+        &self,
+        new_object: &Object,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<CreateOutcome, Box<dyn Error>> {
+        self.inner
+            .create_if_absent(new_object, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Collect an individual data object from the tenant. See `Objects::get`.
+    pub async fn get(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        include: Option<&str>,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Object, Box<dyn Error>> {
+        self.inner
+            .get(
+                class_name,
+                id,
+                include,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Collect an individual data object from the tenant, if it exists. See `Objects::find`.
+    pub async fn find(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        include: Option<&str>,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Option<Object>, Box<dyn Error>> {
+        self.inner
+            .find(
+                class_name,
+                id,
+                include,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Collect several data objects from the tenant. See `Objects::get_many`.
+    pub async fn get_many(
+        &self,
+        class_name: &str,
+        ids: &[Uuid],
+        include: Option<&str>,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<Vec<Option<Object>>, Box<dyn Error>> {
+        self.inner
+            .get_many(
+                class_name,
+                ids,
+                include,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Check a data object exists in the tenant. See `Objects::exists`.
+    pub async fn exists(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<bool, Box<dyn Error>> {
+        self.inner
+            .exists(class_name, id, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Update the properties of a data object in the tenant with a typed partial value. See
+    /// `Objects::update_typed`.
+    pub async fn update_typed<T: Serialize>(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        partial: &T,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<bool, Box<dyn Error>> {
+        self.inner
+            .update_typed(
+                class_name,
+                id,
+                partial,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Replace the vector of a data object in the tenant. See `Objects::update_vector`.
+    pub async fn update_vector(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        vector: Vec<f32>,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<bool, Box<dyn Error>> {
+        self.inner
+            .update_vector(
+                class_name,
+                id,
+                vector,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Replace the properties of a data object in the tenant with a typed value. See
+    /// `Objects::replace_typed`.
+    pub async fn replace_typed<T: Serialize + DeserializeOwned>(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        properties: &T,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<T, Box<dyn Error>> {
+        self.inner
+            .replace_typed(
+                class_name,
+                id,
+                properties,
+                consistency_level,
+                Some(&self.tenant),
+            )
+            .await
+    }
+
+    /// Delete a data object from the tenant. See `Objects::delete`.
+    pub async fn delete(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<bool, Box<dyn Error>> {
+        self.inner
+            .delete(class_name, id, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Delete several data objects from the tenant. See `Objects::delete_many`.
+    pub async fn delete_many(
+        &self,
+        class_name: &str,
+        ids: &[Uuid],
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<BatchDeleteResponse, Box<dyn Error>> {
+        self.inner
+            .delete_many(class_name, ids, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Collect the references of a data object in the tenant. See `Objects::get_references`.
+    pub async fn get_references(
+        &self,
+        class_name: &str,
+        id: &Uuid,
+        property_name: &str,
+    ) -> Result<Vec<Reference>, Box<dyn Error>> {
+        self.inner
+            .get_references(class_name, id, property_name, Some(&self.tenant))
+            .await
+    }
+
+    /// Count the data objects of a class in the tenant. See `Objects::count`.
+    pub async fn count(&self, class_name: &str) -> Result<u64, Box<dyn Error>> {
+        self.inner.count(class_name, None, Some(&self.tenant)).await
+    }
+
+    /// Add a reference from a data object in the tenant. See `Objects::reference_add`.
+    pub async fn reference_add(&self, mut reference: Reference) -> Result<bool, Box<dyn Error>> {
+        reference.tenant_name = Some(self.tenant.clone());
+        self.inner.reference_add(reference).await
+    }
+
+    /// Add a reference from a data object in the tenant, unless it already exists. See
+    /// `Objects::reference_add_if_absent`.
+    pub async fn reference_add_if_absent(
+        &self,
+        mut reference: Reference,
+    ) -> Result<bool, Box<dyn Error>> {
+        reference.tenant_name = Some(self.tenant.clone());
+        self.inner.reference_add_if_absent(reference).await
+    }
+}
+
+/// The `Batch` endpoints, with the tenant of the `ScopedClient` applied.
+#[derive(Debug)]
+pub struct ScopedBatch<'a> {
+    inner: &'a Batch,
+    tenant: String,
+}
+
+impl ScopedBatch<'_> {
+    /// Batch add objects to the tenant. See `Batch::objects_batch_add`.
+    pub async fn objects_batch_add(
+        &self,
+        objects: MultiObjects,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<BatchAddObjects, Box<dyn Error>> {
+        self.inner
+            .objects_batch_add(objects, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Batch delete objects from the tenant. See `Batch::objects_batch_delete`.
+    pub async fn objects_batch_delete(
+        &self,
+        request_body: BatchDeleteRequest,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<BatchDeleteResponse, Box<dyn Error>> {
+        self.inner
+            .objects_batch_delete(request_body, consistency_level, Some(&self.tenant))
+            .await
+    }
+
+    /// Batch add references in the tenant. See `Batch::references_batch_add`.
+    pub async fn references_batch_add(
+        &self,
+        references: References,
+        consistency_level: Option<ConsistencyLevel>,
+    ) -> Result<BatchAddReferencesResponse, Box<dyn Error>> {
+        self.inner
+            .references_batch_add(references, consistency_level, Some(&self.tenant))
+            .await
+    }
+}
+
+/// The `Query` endpoints, with the tenant of the `ScopedClient` applied.
+///
+/// As the tenant is part of the GraphQL query, these take the query builder rather than the
+/// built query.
+#[derive(Debug)]
+pub struct ScopedQuery<'a> {
+    inner: &'a Query,
+    tenant: String,
+}
+
+impl ScopedQuery<'_> {
+    /// Execute the Get{} GraphQL query against the tenant. See `Query::get`.
+    pub async fn get(&self, query: GetBuilder) -> Result<GraphQLResponse, Box<dyn Error>> {
+        let query = self
+            .inner
+            .with_query_maximum_results(query)
+            .with_tenant(&self.tenant)
+            .build()?;
+        self.inner.get(query).await
+    }
+
+    /// Execute the Aggregate{} GraphQL query against the tenant. See `Query::aggregate`.
+    pub async fn aggregate(
+        &self,
+        query: AggregateBuilder,
+    ) -> Result<AggregateResponse, Box<dyn Error>> {
+        let query = query.with_tenant(&self.tenant).build();
+        self.inner.aggregate(query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        collections::{objects::Object, query::GetBuilder},
+        WeaviateClient,
+    };
+
+    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        (mock_server, client)
+    }
+
+    #[tokio::test]
+    async fn test_scoped_create() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = Object::builder("Test", serde_json::json!({"name": "test"})).build();
+        let mock = mock_server
+            .mock("POST", "/v1/objects/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "class": "Test",
+                "tenant": "TENANT_A"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&object).unwrap())
+            .create();
+        let tenant = client.for_tenant("TENANT_A");
+        let res = tenant.objects.create(&object, None).await;
+        mock.assert();
+        assert!(res.is_ok());
+        assert_eq!("TENANT_A", tenant.tenant());
+        assert!(object.tenant.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scoped_query() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let body = serde_json::json!({"data": {"Get": {"Test": []}}});
+        let mock = mock_server
+            .mock("POST", "/v1/graphql")
+            .match_body(mockito::Matcher::Regex(
+                "tenant: \\\\\"TENANT_A\\\\\"".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create();
+        let tenant = client.for_tenant("TENANT_A");
+        let res = tenant
+            .query
+            .get(GetBuilder::new("Test", vec!["name"]))
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+}
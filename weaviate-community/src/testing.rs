@@ -0,0 +1,240 @@
+//! Helpers for unit testing code built on the `WeaviateClient`, without a running Weaviate
+//! instance.
+//!
+//! Only available with the `testing` feature enabled.
+//!
+//! ```no_run
+//! use weaviate_community::testing::MockWeaviate;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut mock = MockWeaviate::new().await;
+//!     let ready = mock.mock_ready(true);
+//!     let client = mock.client()?;
+//!     assert!(client.is_ready().await?);
+//!     ready.assert();
+//!     Ok(())
+//! }
+//! ```
+use crate::collections::objects::Object;
+use crate::collections::schema::Classes;
+use crate::WeaviateClient;
+use std::error::Error;
+
+pub use mockito;
+
+/// A mock Weaviate server, wrapping a `mockito::ServerGuard` with helpers to stub the common
+/// endpoints.
+///
+/// Each helper returns the created `mockito::Mock`, which can be used to assert the endpoint was
+/// called. Anything not covered by the helpers can be stubbed through `server`.
+pub struct MockWeaviate {
+    server: mockito::ServerGuard,
+}
+
+impl std::fmt::Debug for MockWeaviate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockWeaviate")
+            .field("url", &self.url())
+            .finish()
+    }
+}
+
+impl MockWeaviate {
+    /// Start a new mock Weaviate server.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::testing::MockWeaviate;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mock = MockWeaviate::new().await;
+    /// }
+    /// ```
+    pub async fn new() -> MockWeaviate {
+        MockWeaviate {
+            server: mockito::Server::new_async().await,
+        }
+    }
+
+    /// The base url of the mock server.
+    pub fn url(&self) -> String {
+        self.server.url()
+    }
+
+    /// Build a `WeaviateClient` pointing at the mock server.
+    pub fn client(&self) -> Result<WeaviateClient, Box<dyn Error>> {
+        WeaviateClient::builder(&self.url()).build()
+    }
+
+    /// The underlying mockito server, for stubbing endpoints not covered by the helpers.
+    pub fn server(&mut self) -> &mut mockito::ServerGuard {
+        &mut self.server
+    }
+
+    /// Stub a `GET` request to the endpoint with a JSON body.
+    ///
+    /// # Parameters
+    /// - endpoint: the path of the endpoint, for example `/v1/meta`
+    /// - status_code: the status code to respond with
+    /// - body: the body to respond with
+    pub fn mock_get(&mut self, endpoint: &str, status_code: usize, body: &str) -> mockito::Mock {
+        self.mock("GET", endpoint, status_code, body)
+    }
+
+    /// Stub a `POST` request to the endpoint with a JSON body.
+    ///
+    /// # Parameters
+    /// - endpoint: the path of the endpoint, for example `/v1/objects/`
+    /// - status_code: the status code to respond with
+    /// - body: the body to respond with
+    pub fn mock_post(&mut self, endpoint: &str, status_code: usize, body: &str) -> mockito::Mock {
+        self.mock("POST", endpoint, status_code, body)
+    }
+
+    /// Stub a `PUT` request to the endpoint with a JSON body.
+    ///
+    /// # Parameters
+    /// - endpoint: the path of the endpoint
+    /// - status_code: the status code to respond with
+    /// - body: the body to respond with
+    pub fn mock_put(&mut self, endpoint: &str, status_code: usize, body: &str) -> mockito::Mock {
+        self.mock("PUT", endpoint, status_code, body)
+    }
+
+    /// Stub a `PATCH` request to the endpoint with a JSON body.
+    ///
+    /// # Parameters
+    /// - endpoint: the path of the endpoint
+    /// - status_code: the status code to respond with
+    /// - body: the body to respond with
+    pub fn mock_patch(&mut self, endpoint: &str, status_code: usize, body: &str) -> mockito::Mock {
+        self.mock("PATCH", endpoint, status_code, body)
+    }
+
+    /// Stub a `DELETE` request to the endpoint.
+    ///
+    /// # Parameters
+    /// - endpoint: the path of the endpoint
+    /// - status_code: the status code to respond with
+    pub fn mock_delete(&mut self, endpoint: &str, status_code: usize) -> mockito::Mock {
+        self.server
+            .mock("DELETE", endpoint)
+            .with_status(status_code)
+            .create()
+    }
+
+    /// Stub the readiness endpoint used by `WeaviateClient::is_ready`.
+    ///
+    /// # Parameters
+    /// - ready: whether Weaviate should report as ready
+    pub fn mock_ready(&mut self, ready: bool) -> mockito::Mock {
+        let status_code = if ready { 200 } else { 503 };
+        self.mock_get("/v1/.well-known/ready", status_code, "")
+    }
+
+    /// Stub the schema endpoint used by `Schema::get`.
+    ///
+    /// # Parameters
+    /// - classes: the schema to respond with
+    pub fn mock_schema(&mut self, classes: &Classes) -> mockito::Mock {
+        let body = serde_json::to_string(classes).unwrap();
+        self.mock_get("/v1/schema/", 200, &body)
+    }
+
+    /// Stub the object endpoint used by `Objects::get`, responding with the object.
+    ///
+    /// # Parameters
+    /// - object: the object to respond with, which must have an `id`
+    pub fn mock_object(&mut self, object: &Object) -> mockito::Mock {
+        let id = object.id.expect("the mocked object must have an id");
+        let endpoint = format!("/v1/objects/{}/{}", object.class, id);
+        let body = serde_json::to_string(object).unwrap();
+        self.server
+            .mock("GET", endpoint.as_str())
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    /// Stub the object creation endpoint used by `Objects::create`, echoing the object back.
+    ///
+    /// # Parameters
+    /// - object: the object to respond with
+    pub fn mock_create_object(&mut self, object: &Object) -> mockito::Mock {
+        let body = serde_json::to_string(object).unwrap();
+        self.server
+            .mock("POST", "/v1/objects/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+
+    /// Stub the GraphQL endpoint used by the `Query` methods.
+    ///
+    /// # Parameters
+    /// - response: the GraphQL response to respond with, for example
+    ///   `{"data": {"Get": {"Article": []}}}`
+    pub fn mock_graphql(&mut self, response: serde_json::Value) -> mockito::Mock {
+        self.mock_post("/v1/graphql", 200, &response.to_string())
+    }
+
+    fn mock(
+        &mut self,
+        method: &str,
+        endpoint: &str,
+        status_code: usize,
+        body: &str,
+    ) -> mockito::Mock {
+        self.server
+            .mock(method, endpoint)
+            .with_status(status_code)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockWeaviate;
+    use crate::collections::objects::Object;
+    use crate::collections::query::GetQuery;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_mock_weaviate() {
+        let mut mock = MockWeaviate::new().await;
+        let client = mock.client().unwrap();
+
+        let ready = mock.mock_ready(false);
+        assert!(!client.is_ready().await.unwrap());
+        ready.assert();
+
+        let object = Object::builder("Article", serde_json::json!({"title": "test"}))
+            .with_id(Uuid::new_v4())
+            .build();
+        let get = mock.mock_object(&object);
+        let res = client
+            .objects
+            .get("Article", &object.id.unwrap(), None, None, None)
+            .await
+            .unwrap();
+        get.assert();
+        assert_eq!(object.id, res.id);
+
+        let graphql = mock.mock_graphql(serde_json::json!({"data": {"Get": {"Article": []}}}));
+        let query = GetQuery::builder("Article", vec!["title"]).build().unwrap();
+        let res = client.query.get(query).await.unwrap();
+        graphql.assert();
+        assert!(res
+            .get_hits::<serde_json::Value>("Article")
+            .unwrap()
+            .is_empty());
+    }
+}